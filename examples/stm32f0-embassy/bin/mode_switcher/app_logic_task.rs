@@ -249,7 +249,7 @@ pub async fn app_logic_task() {
     RGB_COMMAND_CHANNEL
         .send(SequencerCommand8::new(
             (), // Unit LED ID since we only have one LED
-            SequencerAction8::Load(initial_sequence),
+            SequencerAction8::LoadAndStart(initial_sequence),
         ))
         .await;
 
@@ -273,7 +273,7 @@ pub async fn app_logic_task() {
         RGB_COMMAND_CHANNEL
             .send(SequencerCommand8::new(
                 (),
-                SequencerAction8::Load(new_sequence),
+                SequencerAction8::LoadAndStart(new_sequence),
             ))
             .await;
 
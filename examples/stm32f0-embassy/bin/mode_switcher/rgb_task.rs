@@ -1,10 +1,8 @@
 use defmt::info;
-use embassy_futures::select::{Either, select};
 use embassy_stm32::peripherals::TIM3;
 use embassy_stm32::timer::simple_pwm::SimplePwm;
-use embassy_time::{Duration, Timer};
 use palette::Srgb;
-use rgb_sequencer::{RgbLed, RgbSequencer8, ServiceTiming};
+use rgb_sequencer::{RgbLed, RgbSequencer8, SequencerTask};
 
 use crate::types::{EmbassyInstant, EmbassyTimeSource, RGB_COMMAND_CHANNEL};
 
@@ -63,79 +61,15 @@ pub async fn rgb_task(pwm_tim3: SimplePwm<'static, TIM3>, max_duty_tim3: u32) {
     // Create LED wrapper (common anode = true)
     let led_1 = EmbassyPwmRgbLed::new(pwm_tim3, max_duty_tim3, true);
 
-    // Create time source
-    let time_source = EmbassyTimeSource::new();
-
-    // Create sequencer
-    let mut sequencer = RgbSequencer8::<EmbassyInstant, _, _>::new(led_1, &time_source);
+    // The library's task wrapper waits for either a command or the next service time
+    let sequencer = RgbSequencer8::<EmbassyInstant, _, _>::new(led_1, &EmbassyTimeSource);
+    let mut task = SequencerTask::new(sequencer, RGB_COMMAND_CHANNEL.receiver());
 
     info!("Sequencer created");
 
-    // Start with a short delay, will be updated after first service
-    let mut next_service_delay = Duration::from_millis(16);
-
     loop {
-        // Wait for either a command or the next service time
-        match select(
-            RGB_COMMAND_CHANNEL.receive(),
-            Timer::after(next_service_delay),
-        )
-        .await
-        {
-            Either::First(command) => {
-                info!("Handling command");
-
-                // Use the library's handle_action method!
-                // The Load action will load and start the sequence
-                match command.action {
-                    rgb_sequencer::SequencerAction8::Load(seq) => {
-                        if let Err(e) = sequencer.load_and_start(seq) {
-                            info!("Load and start error: {:?}", e);
-                        }
-                    }
-                    _ => {
-                        if let Err(e) = sequencer.handle_action(command.action) {
-                            info!("Action error: {:?}", e);
-                        }
-                    }
-                }
-
-                // Service to set color and get delay
-                next_service_delay = service_and_get_delay(&mut sequencer);
-            }
-            Either::Second(_) => {
-                // Time to service the sequencer
-                next_service_delay = service_and_get_delay(&mut sequencer);
-            }
-        }
-    }
-}
-
-/// Service the sequencer and return the appropriate delay.
-fn service_and_get_delay(
-    sequencer: &mut RgbSequencer8<EmbassyInstant, EmbassyPwmRgbLed<TIM3>, EmbassyTimeSource>,
-) -> Duration {
-    if !sequencer.is_running() {
-        return Duration::from_secs(3600);
-    }
-
-    match sequencer.service() {
-        Ok(ServiceTiming::Continuous) => {
-            // Linear transition - service at ~60fps
-            Duration::from_millis(16)
-        }
-        Ok(ServiceTiming::Delay(delay)) => {
-            // Step transition - use the delay
-            delay.0
-        }
-        Ok(ServiceTiming::Complete) => {
-            info!("Sequence completed");
-            // Sequence complete - wait indefinitely - One hour for simplicity
-            Duration::from_secs(3600)
-        }
-        Err(e) => {
+        if let Err(e) = task.run_once().await {
             info!("Sequencer error: {:?}", e);
-            Duration::from_secs(3600)
         }
     }
 }
@@ -202,7 +202,7 @@ pub async fn rgb_task(
     let led_tim1 = EmbassyPwmRgbLed::new(pwm_tim1, max_duty_tim1, true);
 
     // Create time source
-    let time_source = EmbassyTimeSource::new();
+    let time_source = EmbassyTimeSource;
 
     // Create collection that can hold up to 4 LEDs
     let mut collection: SequencerCollection<4> = SequencerCollection::new(&time_source);
@@ -53,7 +53,7 @@ pub async fn rgb_task(pwm_tim3: SimplePwm<'static, TIM3>, max_duty_tim3: u32) {
     info!("RGB task started");
 
     let led_1 = EmbassyPwmRgbLed::new(pwm_tim3, max_duty_tim3, true);
-    let time_source = EmbassyTimeSource::new();
+    let time_source = EmbassyTimeSource;
     let mut sequencer = RgbSequencer::<EmbassyInstant, _, _, 16>::new(led_1, &time_source);
 
     info!("Sequencer created");
@@ -0,0 +1,124 @@
+//! APA102/DotStar pixel output (requires the `embedded-hal-1` feature).
+//!
+//! `Apa102Led` drives a single APA102 (or SK9822) pixel, or the first pixel of a chain,
+//! over an embedded-hal 1.0 `SpiBus`. Each update sends a start frame, one LED frame and
+//! a zeroed end frame; downstream pixels see the end frame as a new start frame and keep
+//! their colors.
+
+use crate::sequencer::RgbLed;
+use embedded_hal_1::spi::SpiBus;
+use palette::Srgb;
+
+/// Maximum value of the APA102's 5-bit global brightness field.
+pub const APA102_MAX_BRIGHTNESS: u8 = 31;
+
+/// How the 5-bit global brightness field of each LED frame is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Apa102Brightness {
+    /// Sends the same level (0-31, larger values are clamped) with every frame.
+    ///
+    /// Colors use the 8-bit channels only, so a sequencer dimmed to 10% has roughly
+    /// 25 levels per channel left.
+    Fixed(u8),
+    /// Picks the lowest level that can still show the brightest channel and rescales the
+    /// 8-bit channels to fill their range.
+    ///
+    /// Dimming via `RgbSequencer::set_brightness()` then moves into the global field,
+    /// keeping full channel resolution down to about 3% brightness.
+    Adaptive,
+}
+
+/// RGB LED driven as an APA102/DotStar pixel over SPI.
+///
+/// `RgbLed::set_color()` cannot fail, so SPI errors are counted instead of returned;
+/// see `error_count()`.
+#[derive(Debug)]
+pub struct Apa102Led<S> {
+    spi: S,
+    brightness: Apa102Brightness,
+    errors: u32,
+}
+
+impl<S: SpiBus> Apa102Led<S> {
+    /// Creates a pixel on `spi` using `Apa102Brightness::Adaptive`.
+    pub fn new(spi: S) -> Self {
+        Self {
+            spi,
+            brightness: Apa102Brightness::Adaptive,
+            errors: 0,
+        }
+    }
+
+    /// Sets how the global brightness field is chosen.
+    pub fn with_brightness(mut self, brightness: Apa102Brightness) -> Self {
+        self.brightness = brightness;
+        self
+    }
+
+    /// Returns how the global brightness field is chosen.
+    #[inline]
+    pub fn brightness(&self) -> Apa102Brightness {
+        self.brightness
+    }
+
+    /// Returns how many frame writes have failed, saturating at `u32::MAX`.
+    #[inline]
+    pub fn error_count(&self) -> u32 {
+        self.errors
+    }
+
+    /// Consumes the LED and returns the SPI bus.
+    #[inline]
+    pub fn into_spi(self) -> S {
+        self.spi
+    }
+
+    /// Encodes `color` as an APA102 LED frame: `0b111` + brightness, blue, green, red.
+    pub fn led_frame(&self, color: Srgb) -> [u8; 4] {
+        let [red, green, blue] =
+            [color.red, color.green, color.blue].map(|channel| channel.clamp(0.0, 1.0));
+
+        let (global, scale) = match self.brightness {
+            Apa102Brightness::Fixed(level) => (level.min(APA102_MAX_BRIGHTNESS), 1.0),
+            Apa102Brightness::Adaptive => {
+                let max = red.max(green).max(blue);
+                if max <= 0.0 {
+                    (0, 1.0)
+                } else {
+                    // Smallest level with level / 31 >= max (ceil without libm)
+                    let levels = APA102_MAX_BRIGHTNESS as f32;
+                    let exact = max * levels;
+                    let floor = exact as u8;
+                    let global = if (floor as f32) < exact {
+                        floor + 1
+                    } else {
+                        floor
+                    };
+                    (global, levels / global as f32)
+                }
+            }
+        };
+
+        let [red, green, blue] =
+            [red, green, blue].map(|channel| ((channel * scale).min(1.0) * 255.0 + 0.5) as u8);
+        [0xE0 | global, blue, green, red]
+    }
+}
+
+impl<S: SpiBus> RgbLed for Apa102Led<S> {
+    fn set_color(&mut self, color: Srgb) {
+        let [header, blue, green, red] = self.led_frame(color);
+        // Start frame, LED frame, zeroed end frame
+        let frame = [0, 0, 0, 0, header, blue, green, red, 0, 0, 0, 0];
+
+        if self
+            .spi
+            .write(&frame)
+            .and_then(|()| self.spi.flush())
+            .is_err()
+        {
+            self.errors = self.errors.saturating_add(1);
+        }
+    }
+}
@@ -0,0 +1,670 @@
+//! Fixed-capacity collection of sequencers sharing one time source.
+//!
+//! Each sequencer occupies a slot addressed by its index (`usize` LED ID). Slots can be
+//! freed and reused, so IDs stay stable while other LEDs are added or removed.
+
+use crate::command::{SequencerAction, SequencerCommand};
+use crate::sequencer::{RgbLed, RgbSequencer, SequencerError, SequencerState, ServiceTiming};
+use crate::time::{TimeDuration, TimeInstant, TimeSource};
+use crate::wire::{self, WireError};
+use heapless::Vec;
+use palette::Srgb;
+
+/// Per-LED errors collected by operations that touch multiple sequencers.
+///
+/// Each entry holds the LED ID and the error for that LED.
+pub type ErrorReport<const CAPACITY: usize> = Vec<(usize, CollectionError), CAPACITY>;
+
+/// Errors that can occur during collection operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CollectionError {
+    /// No free slot left.
+    Full,
+    /// No sequencer at the given LED ID.
+    InvalidId(usize),
+    /// Sequencer operation failed.
+    Sequencer(SequencerError),
+    /// No room left to hold a scheduled command.
+    ScheduleFull,
+}
+
+impl From<SequencerError> for CollectionError {
+    /// Wraps a sequencer error.
+    fn from(error: SequencerError) -> Self {
+        CollectionError::Sequencer(error)
+    }
+}
+
+impl core::fmt::Display for CollectionError {
+    /// Formats the error for display.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CollectionError::Full => write!(f, "collection is full"),
+            CollectionError::InvalidId(id) => write!(f, "no sequencer at LED ID {}", id),
+            CollectionError::Sequencer(error) => write!(f, "{}", error),
+            CollectionError::ScheduleFull => write!(f, "scheduled command queue is full"),
+        }
+    }
+}
+
+/// Snapshot of a collection's sequencer states and next service deadline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CollectionStatus<D> {
+    /// Number of LEDs in `Idle` state.
+    pub idle: usize,
+    /// Number of LEDs in `Loaded` state.
+    pub loaded: usize,
+    /// Number of LEDs in `Running` state.
+    pub running: usize,
+    /// Number of LEDs in `Paused` state.
+    pub paused: usize,
+    /// Number of LEDs in `Held` state.
+    pub held: usize,
+    /// Number of LEDs in `Complete` state.
+    pub complete: usize,
+    /// Most urgent timing hint across running and held LEDs (`Complete` if there are none).
+    pub next_service: ServiceTiming<D>,
+}
+
+/// Adds `error` for LED `id` unless the report already holds an error for that LED.
+///
+/// Scheduled commands and servicing share one report, so keeping the first error per LED
+/// bounds it at one entry per slot.
+fn report_once<const CAPACITY: usize>(
+    errors: &mut ErrorReport<CAPACITY>,
+    id: usize,
+    error: CollectionError,
+) {
+    if errors.iter().all(|&(reported, _)| reported != id) {
+        // Cannot overflow: at most one entry per slot
+        let _ = errors.push((id, error));
+    }
+}
+
+/// Folds per-LED service results into `timing`, collecting failures into `errors`.
+///
+/// Shared by every multi-sequencer container so they all service each LED and report
+/// failures the same way.
+pub(crate) fn merge_service_results<D: TimeDuration, const CAPACITY: usize>(
+    results: impl Iterator<Item = (usize, Result<ServiceTiming<D>, SequencerError>)>,
+    mut timing: ServiceTiming<D>,
+    errors: &mut ErrorReport<CAPACITY>,
+) -> ServiceTiming<D> {
+    for (id, result) in results {
+        match result {
+            Ok(hint) => timing = most_urgent(timing, hint),
+            Err(error) => report_once(errors, id, error.into()),
+        }
+    }
+    timing
+}
+
+/// Returns `timing`, or the first reported error.
+pub(crate) fn first_error<D: TimeDuration, const CAPACITY: usize>(
+    (timing, errors): (ServiceTiming<D>, ErrorReport<CAPACITY>),
+) -> Result<ServiceTiming<D>, CollectionError> {
+    match errors.first() {
+        Some((_, error)) => Err(*error),
+        None => Ok(timing),
+    }
+}
+
+/// Returns the more urgent of two timing hints.
+#[inline]
+pub(crate) fn most_urgent<D: TimeDuration>(
+    a: ServiceTiming<D>,
+    b: ServiceTiming<D>,
+) -> ServiceTiming<D> {
+    match (a, b) {
+        (ServiceTiming::Continuous, _) | (_, ServiceTiming::Continuous) => {
+            ServiceTiming::Continuous
+        }
+        (ServiceTiming::Delay(d1), ServiceTiming::Delay(d2)) => {
+            if d1.as_millis() <= d2.as_millis() {
+                ServiceTiming::Delay(d1)
+            } else {
+                ServiceTiming::Delay(d2)
+            }
+        }
+        (ServiceTiming::Delay(d), _) | (_, ServiceTiming::Delay(d)) => ServiceTiming::Delay(d),
+        _ => ServiceTiming::Complete,
+    }
+}
+
+/// Controls up to `CAPACITY` LEDs, each through its own sequencer.
+///
+/// Up to `QUEUE` scheduled commands can be held until their execution instant. The default
+/// of `0` disables scheduling and adds no memory.
+pub struct SequencerCollection<
+    't,
+    I: TimeInstant,
+    L: RgbLed,
+    T: TimeSource<I>,
+    const N: usize,
+    const CAPACITY: usize,
+    const QUEUE: usize = 0,
+> {
+    slots: [Option<RgbSequencer<'t, I, L, T, N>>; CAPACITY],
+    groups: [u32; CAPACITY],
+    scheduled: Vec<(I, usize, SequencerAction<I::Duration, N>), QUEUE>,
+    time_source: &'t T,
+}
+
+impl<
+    't,
+    I: TimeInstant,
+    L: RgbLed,
+    T: TimeSource<I>,
+    const N: usize,
+    const CAPACITY: usize,
+    const QUEUE: usize,
+> SequencerCollection<'t, I, L, T, N, CAPACITY, QUEUE>
+{
+    /// Creates empty collection.
+    pub fn new(time_source: &'t T) -> Self {
+        Self {
+            slots: core::array::from_fn(|_| None),
+            groups: [0; CAPACITY],
+            scheduled: Vec::new(),
+            time_source,
+        }
+    }
+
+    /// Adds a sequencer for `led` in the first free slot and returns its LED ID.
+    ///
+    /// The LED is turned off. If no slot is free, returns `CollectionError::Full` together
+    /// with the untouched LED so the hardware handle is not lost.
+    pub fn add_sequencer(&mut self, led: L) -> Result<usize, (CollectionError, L)> {
+        let Some(id) = self.slots.iter().position(Option::is_none) else {
+            return Err((CollectionError::Full, led));
+        };
+        self.slots[id] = Some(RgbSequencer::new(led, self.time_source));
+        self.groups[id] = 0;
+        Ok(id)
+    }
+
+    /// Removes the sequencer at `id` and returns its LED.
+    ///
+    /// The LED keeps its current color. Scheduled commands for `id` are dropped. The slot
+    /// becomes free for `add_sequencer()`.
+    pub fn remove(&mut self, id: usize) -> Result<L, CollectionError> {
+        let led = self
+            .slots
+            .get_mut(id)
+            .and_then(Option::take)
+            .map(RgbSequencer::into_led)
+            .ok_or(CollectionError::InvalidId(id))?;
+        self.scheduled.retain(|(_, target, _)| *target != id);
+        Ok(led)
+    }
+
+    /// Returns the sequencer at `id`.
+    pub fn get_sequencer(
+        &self,
+        id: usize,
+    ) -> Result<&RgbSequencer<'t, I, L, T, N>, CollectionError> {
+        self.slots
+            .get(id)
+            .and_then(Option::as_ref)
+            .ok_or(CollectionError::InvalidId(id))
+    }
+
+    /// Returns the sequencer at `id` mutably.
+    ///
+    /// Gives access to operations beyond `SequencerAction` (e.g. `set_phase_offset()`).
+    pub fn get_sequencer_mut(
+        &mut self,
+        id: usize,
+    ) -> Result<&mut RgbSequencer<'t, I, L, T, N>, CollectionError> {
+        self.slots
+            .get_mut(id)
+            .and_then(Option::as_mut)
+            .ok_or(CollectionError::InvalidId(id))
+    }
+
+    /// Returns iterator over present sequencers with their LED IDs, in ID order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &RgbSequencer<'t, I, L, T, N>)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(id, slot)| slot.as_ref().map(|sequencer| (id, sequencer)))
+    }
+
+    /// Returns mutable iterator over present sequencers with their LED IDs, in ID order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut RgbSequencer<'t, I, L, T, N>)> {
+        self.slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(id, slot)| slot.as_mut().map(|sequencer| (id, sequencer)))
+    }
+
+    /// Routes command to the sequencer at `command.led_id`.
+    ///
+    /// Commands with an `execute_at` instant in the future are held and executed by the
+    /// first `service_all()` at or after that instant; errors then appear in its report.
+    /// Returns `CollectionError::ScheduleFull` if `QUEUE` commands are already waiting.
+    pub fn handle_command(
+        &mut self,
+        command: SequencerCommand<usize, I::Duration, N, I>,
+    ) -> Result<(), CollectionError> {
+        self.get_sequencer(command.led_id)?;
+
+        if let Some(at) = command.execute_at {
+            let now = self.time_source.now();
+            if at > now {
+                return self
+                    .scheduled
+                    .push((at, command.led_id, command.action))
+                    .map_err(|_| CollectionError::ScheduleFull);
+            }
+        }
+
+        self.get_sequencer_mut(command.led_id)?
+            .handle_action(command.action)?;
+        Ok(())
+    }
+
+    /// Decodes one wire frame and routes its action (see the `wire` module for the format).
+    ///
+    /// Frames addressed to `wire::BROADCAST_ID` go to every present LED; all are attempted
+    /// and the first failure is returned as `WireError::Collection`.
+    pub fn feed(&mut self, frame: &[u8]) -> Result<(), WireError> {
+        let (led_id, action) = wire::decode(frame)?;
+
+        if led_id == wire::BROADCAST_ID {
+            return self
+                .broadcast(action)
+                .map_err(|errors| WireError::Collection(errors[0].1));
+        }
+        self.handle_command(SequencerCommand::new(led_id as usize, action))?;
+        Ok(())
+    }
+
+    /// Returns number of scheduled commands waiting for their instant.
+    #[inline]
+    pub fn scheduled_len(&self) -> usize {
+        self.scheduled.len()
+    }
+
+    /// Drops all scheduled commands without executing them.
+    pub fn clear_scheduled(&mut self) {
+        self.scheduled.clear();
+    }
+
+    /// Executes scheduled commands whose instant has arrived, earliest first.
+    ///
+    /// Commands due at the same instant run in the order they were scheduled.
+    fn run_due_commands(&mut self, now: I, errors: &mut ErrorReport<CAPACITY>) {
+        loop {
+            let mut next: Option<(usize, I)> = None;
+            for (index, &(at, _, _)) in self.scheduled.iter().enumerate() {
+                if at <= now && next.is_none_or(|(_, earliest)| at < earliest) {
+                    next = Some((index, at));
+                }
+            }
+
+            let Some((index, _)) = next else {
+                return;
+            };
+            let (_, id, action) = self.scheduled.remove(index);
+
+            let result = self
+                .get_sequencer_mut(id)
+                .and_then(|sequencer| Ok(sequencer.handle_action(action)?));
+            if let Err(error) = result {
+                report_once(errors, id, error);
+            }
+        }
+    }
+
+    /// Returns the timing hint for the earliest scheduled command.
+    fn next_scheduled(&self, now: I) -> ServiceTiming<I::Duration> {
+        self.scheduled
+            .iter()
+            .map(|&(at, _, _)| {
+                ServiceTiming::Delay(if at > now {
+                    at.duration_since(now)
+                } else {
+                    I::Duration::ZERO
+                })
+            })
+            .fold(ServiceTiming::Complete, most_urgent)
+    }
+
+    /// Sets brightness multiplier (0.0-1.0, clamped) of the LED at `id`.
+    pub fn set_brightness(&mut self, id: usize, brightness: f32) -> Result<(), CollectionError> {
+        self.get_sequencer_mut(id)?.set_brightness(brightness);
+        Ok(())
+    }
+
+    /// Sets brightness multiplier (0.0-1.0, clamped) of every present LED.
+    pub fn set_brightness_all(&mut self, brightness: f32) {
+        for (_, sequencer) in self.iter_mut() {
+            sequencer.set_brightness(brightness);
+        }
+    }
+
+    /// Applies action to every present sequencer.
+    ///
+    /// All sequencers are attempted even if some fail. Returns the failing LED IDs with
+    /// their errors, e.g. `Pause` on sequencers that are not running.
+    pub fn broadcast(
+        &mut self,
+        action: SequencerAction<I::Duration, N>,
+    ) -> Result<(), ErrorReport<CAPACITY>> {
+        self.apply_where(action, |_| true)
+    }
+
+    /// Pauses every running LED and returns how many were paused.
+    ///
+    /// LEDs in other states are skipped rather than reported as errors.
+    pub fn pause_all(&mut self) -> usize {
+        self.iter_mut()
+            .filter(|(_, sequencer)| sequencer.is_running())
+            .map(|(_, sequencer)| sequencer.pause())
+            .filter(Result::is_ok)
+            .count()
+    }
+
+    /// Resumes every paused or held LED and returns how many were resumed.
+    ///
+    /// LEDs in other states are skipped rather than reported as errors.
+    pub fn resume_all(&mut self) -> usize {
+        self.iter_mut()
+            .filter(|(_, sequencer)| sequencer.is_paused() || sequencer.is_held())
+            .map(|(_, sequencer)| sequencer.resume())
+            .filter(Result::is_ok)
+            .count()
+    }
+
+    /// Stops every running, paused, held or complete LED and returns how many were stopped.
+    ///
+    /// LEDs in `Idle` or `Loaded` state are skipped rather than reported as errors.
+    pub fn stop_all(&mut self) -> usize {
+        self.iter_mut()
+            .filter(|(_, sequencer)| {
+                matches!(
+                    sequencer.state(),
+                    SequencerState::Running
+                        | SequencerState::Paused
+                        | SequencerState::Held
+                        | SequencerState::Complete
+                )
+            })
+            .map(|(_, sequencer)| sequencer.stop())
+            .filter(Result::is_ok)
+            .count()
+    }
+
+    /// Stops every LED and drives it to `color` immediately (e.g. lamp test, fault red).
+    ///
+    /// Sequences stay loaded, so LEDs can be restarted with `start()`. Brightness is not
+    /// applied, so every LED shows exactly `color`.
+    pub fn set_all(&mut self, color: Srgb) {
+        for (_, sequencer) in self.iter_mut() {
+            sequencer.halt_with_color(color);
+        }
+    }
+
+    /// Sets group membership bitmask of the LED at `id` (bit `n` = member of group `n`).
+    ///
+    /// LEDs start with no group membership (`0`).
+    pub fn set_groups(&mut self, id: usize, groups: u32) -> Result<(), CollectionError> {
+        self.get_sequencer(id)?;
+        self.groups[id] = groups;
+        Ok(())
+    }
+
+    /// Returns group membership bitmask of the LED at `id`.
+    pub fn groups(&self, id: usize) -> Result<u32, CollectionError> {
+        self.get_sequencer(id)?;
+        Ok(self.groups[id])
+    }
+
+    /// Applies action to every LED belonging to any group in the `group` bitmask.
+    ///
+    /// Behaves like `broadcast()` restricted to group members.
+    pub fn handle_group_command(
+        &mut self,
+        group: u32,
+        action: SequencerAction<I::Duration, N>,
+    ) -> Result<(), ErrorReport<CAPACITY>> {
+        self.apply_where(action, |groups| groups & group != 0)
+    }
+
+    /// Applies action to every present sequencer whose group mask matches `filter`.
+    fn apply_where(
+        &mut self,
+        action: SequencerAction<I::Duration, N>,
+        filter: impl Fn(u32) -> bool,
+    ) -> Result<(), ErrorReport<CAPACITY>> {
+        let mut errors = ErrorReport::new();
+
+        for (id, slot) in self.slots.iter_mut().enumerate() {
+            if !filter(self.groups[id]) {
+                continue;
+            }
+
+            if let Some(sequencer) = slot
+                && let Err(error) = sequencer.handle_action(action.clone())
+            {
+                // Cannot overflow: at most one entry per slot
+                let _ = errors.push((id, error.into()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Starts the sequencers at `ids` using one captured instant.
+    ///
+    /// `now()` is sampled once, so all listed LEDs stay phase-aligned. Every ID is
+    /// attempted; invalid IDs and sequencers not in `Loaded` state are reported.
+    pub fn start_all_synced(&mut self, ids: &[usize]) -> Result<(), ErrorReport<CAPACITY>> {
+        let start_time = self.time_source.now();
+        let mut errors = ErrorReport::new();
+
+        for &id in ids {
+            let result = self
+                .get_sequencer_mut(id)
+                .and_then(|sequencer| Ok(sequencer.start_at(start_time)?));
+
+            if let Err(error) = result {
+                // Report is truncated at CAPACITY entries
+                let _ = errors.push((id, error));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Starts every present sequencer now, staggered by `offset` through its phase offset.
+    ///
+    /// In ID order, each LED lags the previous one by `offset`, so one sequence produces a
+    /// chase across physically adjacent LEDs. Earlier LEDs get a larger phase offset (see
+    /// `RgbSequencer::set_phase_offset()`) instead of a start time in the past, so the chase
+    /// is in place immediately, even right after boot. Sequencers not in `Loaded` state are
+    /// reported and skipped; their phase offset is left unchanged.
+    pub fn start_all_staggered(
+        &mut self,
+        offset: I::Duration,
+    ) -> Result<(), ErrorReport<CAPACITY>> {
+        let now = self.time_source.now();
+        let count = self.len() as u64;
+        let mut errors = ErrorReport::new();
+
+        for (position, (id, sequencer)) in self.iter_mut().enumerate() {
+            let lead = I::Duration::from_millis(offset.as_millis() * (count - 1 - position as u64));
+
+            match sequencer.start_at(now) {
+                Ok(()) => sequencer.set_phase_offset(lead),
+                Err(error) => {
+                    // Cannot overflow: at most one entry per slot
+                    let _ = errors.push((id, error.into()));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Services all running and held sequencers and returns the most urgent timing hint.
+    ///
+    /// Returns `ServiceTiming::Complete` if no sequencer is running or held. Every such
+    /// sequencer is serviced even if one fails; the first error is returned afterwards.
+    /// Use `service_all_lossy()` to get the timing hint and all errors.
+    pub fn service_all(&mut self) -> Result<ServiceTiming<I::Duration>, CollectionError> {
+        first_error(self.service_all_lossy())
+    }
+
+    /// Services all running and held sequencers, collecting per-LED errors instead of
+    /// stopping.
+    ///
+    /// Returns the most urgent timing hint across successfully serviced LEDs together with
+    /// the failing LED IDs, so one broken driver does not freeze the rest of the panel.
+    /// Scheduled commands that are due run first, and the hint also covers the wait until
+    /// the next scheduled command. Each failing LED is listed once, with the first error it
+    /// hit (a failed scheduled command comes before a failed service).
+    pub fn service_all_lossy(&mut self) -> (ServiceTiming<I::Duration>, ErrorReport<CAPACITY>) {
+        let mut errors = ErrorReport::new();
+        let now = self.time_source.now();
+        self.run_due_commands(now, &mut errors);
+        let next_scheduled = self.next_scheduled(now);
+
+        let results = self
+            .iter_mut()
+            .map(|(id, sequencer)| (id, sequencer.service_if_running()));
+        let timing = merge_service_results(results, next_scheduled, &mut errors);
+        (timing, errors)
+    }
+
+    /// Services all running and held sequencers and returns each LED's own timing hint,
+    /// indexed by LED ID.
+    ///
+    /// Free slots are `None`; LEDs that are neither running nor held report
+    /// `ServiceTiming::Complete`.
+    /// A scheduled command shortens the hint of the LED it targets, so an LED waiting for a
+    /// scheduled `Start` reports the wait. Use this to drive each LED from its own timer
+    /// instead of waking all of them for the most urgent one. Due scheduled commands run
+    /// first. Failures are collected like `service_all_lossy()` and do not affect other
+    /// LEDs' hints; an LED whose service failed reports `None`.
+    pub fn service_all_detailed(
+        &mut self,
+    ) -> (
+        [Option<ServiceTiming<I::Duration>>; CAPACITY],
+        ErrorReport<CAPACITY>,
+    ) {
+        let mut errors = ErrorReport::new();
+        let now = self.time_source.now();
+        self.run_due_commands(now, &mut errors);
+
+        let mut timings: [Option<ServiceTiming<I::Duration>>; CAPACITY] =
+            core::array::from_fn(|id| self.slots[id].as_ref().map(|_| ServiceTiming::Complete));
+        for (id, sequencer) in self.iter_mut() {
+            if !sequencer.needs_service() {
+                continue;
+            }
+
+            match sequencer.service() {
+                Ok(timing) => timings[id] = Some(timing),
+                Err(error) => {
+                    timings[id] = None;
+                    report_once(&mut errors, id, error.into());
+                }
+            }
+        }
+
+        for &(at, id, _) in self.scheduled.iter() {
+            if let Some(timing) = timings.get_mut(id).and_then(Option::as_mut) {
+                let wait = if at > now {
+                    at.duration_since(now)
+                } else {
+                    I::Duration::ZERO
+                };
+                *timing = most_urgent(*timing, ServiceTiming::Delay(wait));
+            }
+        }
+
+        (timings, errors)
+    }
+
+    /// Returns state counts and the most urgent timing hint without servicing.
+    ///
+    /// A running LED whose sequence has finished but not yet been serviced reports
+    /// `Delay(ZERO)`, since one more `service_all()` is needed to show its final color.
+    /// Scheduled commands count towards the hint as well.
+    pub fn status(&self) -> CollectionStatus<I::Duration> {
+        let mut status = CollectionStatus {
+            idle: 0,
+            loaded: 0,
+            running: 0,
+            paused: 0,
+            held: 0,
+            complete: 0,
+            next_service: self.next_scheduled(self.time_source.now()),
+        };
+
+        for (_, sequencer) in self.iter() {
+            match sequencer.state() {
+                SequencerState::Idle => status.idle += 1,
+                SequencerState::Loaded => status.loaded += 1,
+                SequencerState::Paused => status.paused += 1,
+                SequencerState::Complete => status.complete += 1,
+                state @ (SequencerState::Running | SequencerState::Held) => {
+                    if state == SequencerState::Held {
+                        status.held += 1;
+                    } else {
+                        status.running += 1;
+                    }
+                    let timing = match sequencer.peek_next_timing() {
+                        Ok(ServiceTiming::Complete) | Err(_) => {
+                            ServiceTiming::Delay(I::Duration::ZERO)
+                        }
+                        Ok(timing) => timing,
+                    };
+                    status.next_service = most_urgent(status.next_service, timing);
+                }
+            }
+        }
+
+        status
+    }
+
+    /// Returns number of occupied slots.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    /// Returns total number of slots.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns number of free slots.
+    #[inline]
+    pub fn free_slots(&self) -> usize {
+        CAPACITY - self.len()
+    }
+
+    /// Returns true if no slot is occupied.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slots.iter().all(Option::is_none)
+    }
+}
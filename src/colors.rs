@@ -1,12 +1,17 @@
 //! Color space conversion helpers.
 //!
 //! Provides convenience functions for working with different color spaces,
-//! particularly HSV (Hue, Saturation, Value) which is more intuitive for
-//! many LED animations like color wheels and hue rotations.
+//! particularly HSV (Hue, Saturation, Value) and HSL (Hue, Saturation, Lightness),
+//! which are more intuitive for many LED animations like color wheels and hue rotations.
 //!
-//! All functions return `palette::Srgb` for direct use with RGB sequences.
+//! All functions return `palette::Srgb` for direct use with RGB sequences. Named colors
+//! beyond the crate-root primaries live in the [`palette`] submodule, and channel blending
+//! helpers in [`blend`].
 
-use palette::{FromColor, Hsv, Srgb};
+pub mod blend;
+pub mod palette;
+
+use ::palette::{FromColor, Hsl, Hsv, ShiftHue, Srgb};
 
 /// Creates an RGB color from HSV (Hue, Saturation, Value) components.
 #[inline]
@@ -15,8 +20,183 @@ pub fn hsv(hue: f32, saturation: f32, value: f32) -> Srgb {
     Srgb::from_color(hsv)
 }
 
+/// Creates an RGB color from HSL (Hue, Saturation, Lightness) components.
+#[inline]
+pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Srgb {
+    let hsl = Hsl::new(hue, saturation, lightness);
+    Srgb::from_color(hsl)
+}
+
 /// Creates an RGB color from hue only (full saturation and value).
 #[inline]
 pub fn hue(hue: f32) -> Srgb {
     hsv(hue, 1.0, 1.0)
 }
+
+/// Rotates the hue of `color` by `degrees`, keeping saturation and value.
+#[inline]
+fn rotate_hue(color: Srgb, degrees: f32) -> Srgb {
+    Srgb::from_color(Hsv::from_color(color).shift_hue(degrees))
+}
+
+/// Returns the color opposite `color` on the color wheel (hue + 180°).
+#[inline]
+pub fn complementary(color: Srgb) -> Srgb {
+    rotate_hue(color, 180.0)
+}
+
+/// Returns `color` and the two colors 120° away on the color wheel.
+#[inline]
+pub fn triadic(color: Srgb) -> [Srgb; 3] {
+    [color, rotate_hue(color, 120.0), rotate_hue(color, 240.0)]
+}
+
+/// Returns `color` flanked by its neighbors `spread` degrees either side on the color wheel.
+///
+/// Order is `[hue - spread, hue, hue + spread]`.
+#[inline]
+pub fn analogous(color: Srgb, spread: f32) -> [Srgb; 3] {
+    [rotate_hue(color, -spread), color, rotate_hue(color, spread)]
+}
+
+/// Raises the HSV value of `color` by `amount`, clamped to 1.0.
+#[inline]
+pub fn lighten(color: Srgb, amount: f32) -> Srgb {
+    let mut hsv = Hsv::from_color(color);
+    hsv.value = (hsv.value + amount).clamp(0.0, 1.0);
+    Srgb::from_color(hsv)
+}
+
+/// Lowers the HSV value of `color` by `amount`, clamped to 0.0.
+#[inline]
+pub fn darken(color: Srgb, amount: f32) -> Srgb {
+    lighten(color, -amount)
+}
+
+/// Raises the HSV saturation of `color` by `amount`, clamped to 1.0.
+#[inline]
+pub fn saturate(color: Srgb, amount: f32) -> Srgb {
+    let mut hsv = Hsv::from_color(color);
+    hsv.saturation = (hsv.saturation + amount).clamp(0.0, 1.0);
+    Srgb::from_color(hsv)
+}
+
+/// Lowers the HSV saturation of `color` by `amount`, clamped to 0.0 (gray).
+#[inline]
+pub fn desaturate(color: Srgb, amount: f32) -> Srgb {
+    saturate(color, -amount)
+}
+
+/// Returns an iterator over `steps` evenly spaced, fully saturated hues starting at red.
+///
+/// Handy for rainbow sequences: `builder.steps_from(wheel(N), duration, Linear)`.
+#[inline]
+pub fn wheel(steps: usize) -> Wheel {
+    Wheel { index: 0, steps }
+}
+
+/// Iterator over evenly spaced hues, created by [`wheel()`].
+#[derive(Debug, Clone)]
+pub struct Wheel {
+    index: usize,
+    steps: usize,
+}
+
+impl Iterator for Wheel {
+    type Item = Srgb;
+
+    fn next(&mut self) -> Option<Srgb> {
+        if self.index >= self.steps {
+            return None;
+        }
+
+        let color = hue(self.index as f32 * 360.0 / self.steps as f32);
+        self.index += 1;
+        Some(color)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.steps - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Wheel {}
+
+/// Creates an RGB color from 8-bit channel values, e.g. for brand colors.
+///
+/// Usable in `const` items: `const BRAND: Srgb = rgb8(0, 120, 215);`
+#[inline]
+pub const fn rgb8(red: u8, green: u8, blue: u8) -> Srgb {
+    Srgb::new(
+        red as f32 / 255.0,
+        green as f32 / 255.0,
+        blue as f32 / 255.0,
+    )
+}
+
+/// Creates a color from a `0xRRGGBB`-packed value. The top byte is ignored.
+#[inline]
+pub const fn from_u32(packed: u32) -> Srgb {
+    rgb8((packed >> 16) as u8, (packed >> 8) as u8, packed as u8)
+}
+
+/// Packs a color into `0xRRGGBB`, rounding each channel (clamped to 0.0-1.0) to 8 bits.
+///
+/// Round-trips exactly with [`from_u32`] and [`rgb8`].
+#[inline]
+pub fn to_u32(color: Srgb) -> u32 {
+    let [red, green, blue] = [color.red, color.green, color.blue]
+        .map(|channel| (channel.clamp(0.0, 1.0) * 255.0 + 0.5) as u32);
+    red << 16 | green << 8 | blue
+}
+
+/// Error parsing a color from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ColorParseError {
+    /// Input is not six hex digits (with optional leading `#`).
+    InvalidLength,
+    /// Input contains a character that is not a hex digit.
+    InvalidDigit,
+}
+
+impl core::fmt::Display for ColorParseError {
+    /// Formats the error for display.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ColorParseError::InvalidLength => {
+                write!(f, "hex color must be six digits, e.g. \"#FF8000\"")
+            }
+            ColorParseError::InvalidDigit => write!(f, "hex color contains a non-hex character"),
+        }
+    }
+}
+
+/// Parses a `#RRGGBB` (or `RRGGBB`) hex string into a color. Case-insensitive.
+pub fn from_hex(hex: &str) -> Result<Srgb, ColorParseError> {
+    let digits = hex.strip_prefix('#').unwrap_or(hex).as_bytes();
+    if digits.len() != 6 {
+        return Err(ColorParseError::InvalidLength);
+    }
+
+    let mut channels = [0u8; 3];
+    for (channel, pair) in channels.iter_mut().zip(digits.chunks(2)) {
+        let high = hex_digit(pair[0]).ok_or(ColorParseError::InvalidDigit)?;
+        let low = hex_digit(pair[1]).ok_or(ColorParseError::InvalidDigit)?;
+        *channel = high << 4 | low;
+    }
+
+    Ok(rgb8(channels[0], channels[1], channels[2]))
+}
+
+/// Returns the value of an ASCII hex digit.
+#[inline]
+fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
@@ -0,0 +1,41 @@
+//! Channel-wise color blending with clamping.
+//!
+//! Shared channel math for custom color functions and compositing. Every result is clamped
+//! to 0.0-1.0 per channel.
+
+use crate::Srgb;
+
+/// Applies `op` to each channel pair and clamps the result.
+#[inline]
+fn per_channel(a: Srgb, b: Srgb, op: impl Fn(f32, f32) -> f32) -> Srgb {
+    Srgb::new(
+        op(a.red, b.red).clamp(0.0, 1.0),
+        op(a.green, b.green).clamp(0.0, 1.0),
+        op(a.blue, b.blue).clamp(0.0, 1.0),
+    )
+}
+
+/// Adds channels, saturating at full intensity (light from two sources).
+#[inline]
+pub fn add(a: Srgb, b: Srgb) -> Srgb {
+    per_channel(a, b, |a, b| a + b)
+}
+
+/// Multiplies channels; always darker or equal (filtering one color by another).
+#[inline]
+pub fn multiply(a: Srgb, b: Srgb) -> Srgb {
+    per_channel(a, b, |a, b| a * b)
+}
+
+/// Inverse multiply; always lighter or equal, softer than `add`.
+#[inline]
+pub fn screen(a: Srgb, b: Srgb) -> Srgb {
+    per_channel(a, b, |a, b| 1.0 - (1.0 - a) * (1.0 - b))
+}
+
+/// Interpolates linearly from `a` (t = 0.0) to `b` (t = 1.0); `t` is clamped to 0.0-1.0.
+#[inline]
+pub fn lerp(a: Srgb, b: Srgb, t: f32) -> Srgb {
+    let t = t.clamp(0.0, 1.0);
+    per_channel(a, b, |a, b| a + (b - a) * t)
+}
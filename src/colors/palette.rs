@@ -0,0 +1,42 @@
+//! Named color constants beyond the primaries exported at the crate root.
+//!
+//! Values are tuned for RGB LEDs rather than screens, so they read well on bare diodes.
+
+use super::rgb8;
+use crate::Srgb;
+
+/// Orange (red with half green).
+pub const ORANGE: Srgb = rgb8(255, 128, 0);
+
+/// Amber (warm yellow-orange, typical indicator color).
+pub const AMBER: Srgb = rgb8(255, 191, 0);
+
+/// Gold (yellow with a touch less green).
+pub const GOLD: Srgb = rgb8(255, 215, 0);
+
+/// Lime (green with half red).
+pub const LIME: Srgb = rgb8(128, 255, 0);
+
+/// Teal (dim green + blue).
+pub const TEAL: Srgb = rgb8(0, 128, 128);
+
+/// Sky blue (light blue).
+pub const SKY_BLUE: Srgb = rgb8(64, 160, 255);
+
+/// Indigo (deep blue-violet).
+pub const INDIGO: Srgb = rgb8(75, 0, 130);
+
+/// Purple (blue with half red).
+pub const PURPLE: Srgb = rgb8(128, 0, 255);
+
+/// Violet (lighter purple).
+pub const VIOLET: Srgb = rgb8(180, 80, 255);
+
+/// Pink (red with some green and blue).
+pub const PINK: Srgb = rgb8(255, 96, 160);
+
+/// Warm white (incandescent-like, around 2700K).
+pub const WARM_WHITE: Srgb = rgb8(255, 190, 120);
+
+/// Cool white (daylight-like, around 6500K).
+pub const COOL_WHITE: Srgb = rgb8(220, 235, 255);
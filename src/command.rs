@@ -11,34 +11,62 @@ use crate::time::TimeDuration;
 pub enum SequencerAction<D: TimeDuration, const N: usize> {
     /// Load sequence (transitions to `Loaded` state).
     Load(RgbSequence<D, N>),
+    /// Load sequence and start it immediately, as one step.
+    LoadAndStart(RgbSequence<D, N>),
     /// Start loaded sequence (requires `Loaded` state).
     Start,
     /// Stop and turn off LED (keeps sequence loaded).
     Stop,
     /// Pause at current color (requires `Running` state).
     Pause,
-    /// Resume from pause with timing compensation.
+    /// Resume from pause with timing compensation, or from hold.
     Resume,
+    /// Hold LED at current color while the timeline keeps running (requires `Running` state).
+    Hold,
     /// Restart from beginning (from `Running`, `Paused`, or `Complete`).
     Restart,
+    /// Jump to a position in the sequence timeline (from `Running`, `Paused`, or `Held`).
+    Seek(D),
     /// Clear sequence and turn off LED.
     Clear,
     /// Set brightness multiplier (0.0-1.0, clamped).
     SetBrightness(f32),
+    /// Set playback speed multiplier (`MIN_SPEED`-`MAX_SPEED`, clamped).
+    SetSpeed(f32),
 }
 
 /// Command targeting a specific LED.
+///
+/// `I` is the instant type used for scheduling. It defaults to `()` for commands that are
+/// always executed immediately, such as those routed to a single sequencer.
 #[derive(Debug, Clone)]
-pub struct SequencerCommand<Id, D: TimeDuration, const N: usize> {
+pub struct SequencerCommand<Id, D: TimeDuration, const N: usize, I = ()> {
     /// LED identifier.
     pub led_id: Id,
     /// Action to execute.
     pub action: SequencerAction<D, N>,
+    /// Instant at which to execute the action (`None` = immediately).
+    pub execute_at: Option<I>,
 }
 
-impl<Id, D: TimeDuration, const N: usize> SequencerCommand<Id, D, N> {
-    /// Creates command.
+impl<Id, D: TimeDuration, const N: usize, I> SequencerCommand<Id, D, N, I> {
+    /// Creates command executed immediately.
     pub fn new(led_id: Id, action: SequencerAction<D, N>) -> Self {
-        Self { led_id, action }
+        Self {
+            led_id,
+            action,
+            execute_at: None,
+        }
+    }
+
+    /// Creates command executed once `instant` is reached.
+    ///
+    /// `SequencerCollection` holds it until `service_all()` sees the instant arrive.
+    pub fn at(led_id: Id, action: SequencerAction<D, N>, instant: I) -> Self {
+        Self {
+            led_id,
+            action,
+            execute_at: Some(instant),
+        }
     }
 }
@@ -0,0 +1,250 @@
+//! Memory-saving storage for sequences that are kept around but played rarely.
+//!
+//! A `CompactSequence` stores step durations as `u16` milliseconds instead of the platform
+//! duration type, so libraries of presets take less RAM. A `PaletteSequence` additionally
+//! replaces each step color with an index into a palette supplied on expansion. Both are
+//! expanded into a regular `RgbSequence` when they are about to be played.
+//!
+//! The savings apply to stored presets only. A sequencer always holds the expanded
+//! `RgbSequence<D, N>`, so playing a compact sequence takes as much sequencer RAM as
+//! playing the full-size one.
+
+use crate::sequence::{RgbSequence, SequenceBuilder};
+use crate::time::TimeDuration;
+use crate::types::{LoopCount, SequenceError, TransitionStyle};
+use heapless::Vec;
+use palette::Srgb;
+
+/// Sequence step with its duration stored as `u16` milliseconds (up to 65.535 s).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompactStep {
+    /// Target color (0.0-1.0 range).
+    pub color: Srgb,
+
+    /// Step duration in milliseconds.
+    pub millis: u16,
+
+    /// Transition style (how to animate to this color).
+    pub transition: TransitionStyle,
+}
+
+impl CompactStep {
+    /// Creates a compact step. Usable in `const` step tables.
+    #[inline]
+    pub const fn new(color: Srgb, millis: u16, transition: TransitionStyle) -> Self {
+        Self {
+            color,
+            millis,
+            transition,
+        }
+    }
+}
+
+/// Step list with compact `u16` millisecond durations.
+///
+/// Converts to and from the `TimeDuration` type at the API boundary. Steps are validated
+/// when expanded with `to_sequence()` or `to_builder()`. Storage format only; the sequencer
+/// plays the expanded `RgbSequence`.
+#[derive(Debug, Clone)]
+pub struct CompactSequence<const N: usize> {
+    steps: Vec<CompactStep, N>,
+    loop_count: LoopCount,
+}
+
+impl<const N: usize> CompactSequence<N> {
+    /// Creates an empty sequence with the given loop count.
+    pub fn new(loop_count: LoopCount) -> Self {
+        Self {
+            steps: Vec::new(),
+            loop_count,
+        }
+    }
+
+    /// Creates a sequence from a step table (e.g. a `const` array).
+    ///
+    /// Returns `SequenceError::CapacityExceeded` if the table is longer than `N`.
+    pub fn from_steps(steps: &[CompactStep], loop_count: LoopCount) -> Result<Self, SequenceError> {
+        Ok(Self {
+            steps: Vec::from_slice(steps).map_err(|_| SequenceError::CapacityExceeded)?,
+            loop_count,
+        })
+    }
+
+    /// Adds a step, converting `duration` to milliseconds.
+    ///
+    /// Returns `SequenceError::DurationOutOfRange` for durations over 65535 ms.
+    pub fn step<D: TimeDuration>(
+        mut self,
+        color: Srgb,
+        duration: D,
+        transition: TransitionStyle,
+    ) -> Result<Self, SequenceError> {
+        let millis =
+            u16::try_from(duration.as_millis()).map_err(|_| SequenceError::DurationOutOfRange)?;
+        self.steps
+            .push(CompactStep::new(color, millis, transition))
+            .map_err(|_| SequenceError::CapacityExceeded)?;
+        Ok(self)
+    }
+
+    /// Compresses the steps and loop count of a step-based sequence.
+    ///
+    /// Other settings (start and landing colors, delays, brightness) are not kept.
+    /// Returns `SequenceError::DurationOutOfRange` if a step is longer than 65535 ms, and
+    /// `SequenceError::FunctionBased` for function-based sequences.
+    pub fn from_sequence<D: TimeDuration>(
+        sequence: &RgbSequence<D, N>,
+    ) -> Result<Self, SequenceError> {
+        if sequence.is_function_based() {
+            return Err(SequenceError::FunctionBased);
+        }
+
+        (0..sequence.step_count())
+            .filter_map(|index| sequence.get_step(index))
+            .try_fold(Self::new(sequence.loop_count()), |compact, step| {
+                compact.step(step.color, step.duration, step.transition)
+            })
+    }
+
+    /// Returns the stored steps.
+    #[inline]
+    pub fn steps(&self) -> &[CompactStep] {
+        &self.steps
+    }
+
+    /// Returns the loop count.
+    #[inline]
+    pub fn loop_count(&self) -> LoopCount {
+        self.loop_count
+    }
+
+    /// Expands into a builder with the steps and loop count, for adding further settings.
+    pub fn to_builder<D: TimeDuration>(&self) -> Result<SequenceBuilder<D, N>, SequenceError> {
+        self.steps
+            .iter()
+            .try_fold(SequenceBuilder::new(), |builder, step| {
+                builder.step(
+                    step.color,
+                    D::from_millis(step.millis as u64),
+                    step.transition,
+                )
+            })
+            .map(|builder| builder.loop_count(self.loop_count))
+    }
+
+    /// Expands into a playable sequence.
+    pub fn to_sequence<D: TimeDuration>(&self) -> Result<RgbSequence<D, N>, SequenceError> {
+        self.to_builder()?.build()
+    }
+}
+
+/// Sequence step referring to a palette color by index, with `u16` millisecond duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaletteStep {
+    /// Index of the target color in the palette.
+    pub color: u8,
+
+    /// Step duration in milliseconds.
+    pub millis: u16,
+
+    /// Transition style (how to animate to this color).
+    pub transition: TransitionStyle,
+}
+
+impl PaletteStep {
+    /// Creates a palette-indexed step. Usable in `const` step tables.
+    #[inline]
+    pub const fn new(color: u8, millis: u16, transition: TransitionStyle) -> Self {
+        Self {
+            color,
+            millis,
+            transition,
+        }
+    }
+}
+
+/// Step list whose colors are indices into a palette supplied on expansion.
+///
+/// Suits patterns that reuse a few colors (police lights, blink codes). The same pattern
+/// can be expanded with different palettes, e.g. for per-product color themes. Storage
+/// format only; the sequencer plays the expanded `RgbSequence`.
+#[derive(Debug, Clone)]
+pub struct PaletteSequence<const N: usize> {
+    steps: Vec<PaletteStep, N>,
+    loop_count: LoopCount,
+}
+
+impl<const N: usize> PaletteSequence<N> {
+    /// Creates an empty sequence with the given loop count.
+    pub fn new(loop_count: LoopCount) -> Self {
+        Self {
+            steps: Vec::new(),
+            loop_count,
+        }
+    }
+
+    /// Creates a sequence from a step table (e.g. a `const` array).
+    ///
+    /// Returns `SequenceError::CapacityExceeded` if the table is longer than `N`.
+    pub fn from_steps(steps: &[PaletteStep], loop_count: LoopCount) -> Result<Self, SequenceError> {
+        Ok(Self {
+            steps: Vec::from_slice(steps).map_err(|_| SequenceError::CapacityExceeded)?,
+            loop_count,
+        })
+    }
+
+    /// Adds a step showing palette color `color`, converting `duration` to milliseconds.
+    ///
+    /// Returns `SequenceError::DurationOutOfRange` for durations over 65535 ms.
+    pub fn step<D: TimeDuration>(
+        mut self,
+        color: u8,
+        duration: D,
+        transition: TransitionStyle,
+    ) -> Result<Self, SequenceError> {
+        let millis =
+            u16::try_from(duration.as_millis()).map_err(|_| SequenceError::DurationOutOfRange)?;
+        self.steps
+            .push(PaletteStep::new(color, millis, transition))
+            .map_err(|_| SequenceError::CapacityExceeded)?;
+        Ok(self)
+    }
+
+    /// Returns the stored steps.
+    #[inline]
+    pub fn steps(&self) -> &[PaletteStep] {
+        &self.steps
+    }
+
+    /// Returns the loop count.
+    #[inline]
+    pub fn loop_count(&self) -> LoopCount {
+        self.loop_count
+    }
+
+    /// Expands into a builder, looking up step colors in `palette`.
+    ///
+    /// Returns `SequenceError::PaletteIndexOutOfRange` if a step refers past the palette.
+    pub fn to_builder<D: TimeDuration>(
+        &self,
+        palette: &[Srgb],
+    ) -> Result<SequenceBuilder<D, N>, SequenceError> {
+        self.steps
+            .iter()
+            .try_fold(SequenceBuilder::new(), |builder, step| {
+                let color = palette
+                    .get(step.color as usize)
+                    .ok_or(SequenceError::PaletteIndexOutOfRange)?;
+                builder.step(*color, D::from_millis(step.millis as u64), step.transition)
+            })
+            .map(|builder| builder.loop_count(self.loop_count))
+    }
+
+    /// Expands into a playable sequence, looking up step colors in `palette`.
+    pub fn to_sequence<D: TimeDuration>(
+        &self,
+        palette: &[Srgb],
+    ) -> Result<RgbSequence<D, N>, SequenceError> {
+        self.to_builder(palette)?.build()
+    }
+}
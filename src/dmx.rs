@@ -0,0 +1,158 @@
+//! DMX512 fixture output.
+//!
+//! A `DmxFixture` writes the sequencer output to `C` consecutive slots of a shared
+//! `DmxUniverse`, so several sequencers can drive fixtures in one universe. The universe
+//! is told to `commit()` after every fixture update, which ties frame timing to
+//! `service()`: a transmitter can start a new frame there, or `DmxBuffer` simply marks the
+//! frame as changed for the next break.
+
+use crate::output::MultiChannelLed;
+use crate::sequencer::RgbLed;
+use core::cell::Cell;
+use palette::Srgb;
+
+/// Number of slots in a DMX512 universe.
+pub const DMX_SLOTS: usize = 512;
+
+/// Errors that can occur when placing a fixture in a universe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DmxError {
+    /// The fixture's channels do not fit between slot 1 and slot 512.
+    AddressOutOfRange,
+}
+
+impl core::fmt::Display for DmxError {
+    /// Formats the error for display.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DmxError::AddressOutOfRange => {
+                write!(f, "fixture channels do not fit in the DMX universe")
+            }
+        }
+    }
+}
+
+/// A DMX512 universe shared by fixtures.
+///
+/// Methods take `&self` so many fixtures can hold the same universe; implementations use
+/// interior mutability (a `Cell` buffer, or a critical-section mutex when an interrupt
+/// transmits the frame).
+pub trait DmxUniverse {
+    /// Writes `values` starting at 1-based slot `address`.
+    ///
+    /// Fixtures validate their range on construction, so `address + values.len() - 1`
+    /// never exceeds 512.
+    fn write_slots(&self, address: u16, values: &[u8]);
+
+    /// Called once after each fixture update.
+    ///
+    /// Start transmitting here, or mark the frame as changed. Does nothing by default.
+    fn commit(&self) {}
+}
+
+/// Ready-made universe buffer with change tracking.
+///
+/// Fixtures write into the buffer during `service()`; the transmit loop calls
+/// `take_frame()` and only sends when something changed (DMX receivers hold their last
+/// frame, though many fixtures expect a refresh at least once a second).
+#[derive(Debug)]
+pub struct DmxBuffer {
+    slots: [Cell<u8>; DMX_SLOTS],
+    changed: Cell<bool>,
+}
+
+impl DmxBuffer {
+    /// Creates a universe with all slots at zero.
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { Cell::new(0) }; DMX_SLOTS],
+            changed: Cell::new(false),
+        }
+    }
+
+    /// Returns the value of 1-based slot `address`, or `None` outside 1-512.
+    pub fn slot(&self, address: u16) -> Option<u8> {
+        let index = (address as usize).checked_sub(1)?;
+        self.slots.get(index).map(Cell::get)
+    }
+
+    /// Returns the current slot values (slot 1 at index 0).
+    pub fn frame(&self) -> [u8; DMX_SLOTS] {
+        core::array::from_fn(|index| self.slots[index].get())
+    }
+
+    /// Returns the slot values if a fixture committed since the last call.
+    pub fn take_frame(&self) -> Option<[u8; DMX_SLOTS]> {
+        self.changed.replace(false).then(|| self.frame())
+    }
+}
+
+impl Default for DmxBuffer {
+    /// Returns a universe with all slots at zero.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DmxUniverse for DmxBuffer {
+    fn write_slots(&self, address: u16, values: &[u8]) {
+        let start = address as usize - 1;
+        for (slot, value) in self.slots[start..].iter().zip(values) {
+            slot.set(*value);
+        }
+    }
+
+    fn commit(&self) {
+        self.changed.set(true);
+    }
+}
+
+/// Fixture occupying `C` consecutive slots of a DMX universe.
+///
+/// Implements `RgbLed` for three-channel fixtures. For other layouts (RGBW, RGB plus a
+/// dimmer channel, ...) wrap it in a `MappedLed` with a `ColorMapper<C>`.
+#[derive(Debug)]
+pub struct DmxFixture<'u, U, const C: usize> {
+    universe: &'u U,
+    address: u16,
+}
+
+impl<'u, U: DmxUniverse, const C: usize> DmxFixture<'u, U, C> {
+    /// Creates a fixture at 1-based start `address`.
+    ///
+    /// Returns `DmxError::AddressOutOfRange` if slots `address..address + C` fall outside
+    /// 1-512.
+    pub fn new(universe: &'u U, address: u16) -> Result<Self, DmxError> {
+        if address == 0 || address as usize + C - 1 > DMX_SLOTS {
+            return Err(DmxError::AddressOutOfRange);
+        }
+        Ok(Self { universe, address })
+    }
+
+    /// Returns the fixture's 1-based start address.
+    #[inline]
+    pub fn address(&self) -> u16 {
+        self.address
+    }
+
+    /// Returns the universe the fixture writes to.
+    #[inline]
+    pub fn universe(&self) -> &'u U {
+        self.universe
+    }
+}
+
+impl<U: DmxUniverse, const C: usize> MultiChannelLed<C> for DmxFixture<'_, U, C> {
+    fn set_channels(&mut self, channels: [f32; C]) {
+        let values = channels.map(|value| (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8);
+        self.universe.write_slots(self.address, &values);
+        self.universe.commit();
+    }
+}
+
+impl<U: DmxUniverse> RgbLed for DmxFixture<'_, U, 3> {
+    fn set_color(&mut self, color: Srgb) {
+        self.set_channels([color.red, color.green, color.blue]);
+    }
+}
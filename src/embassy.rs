@@ -0,0 +1,195 @@
+//! Embassy integration (requires the `embassy` feature).
+//!
+//! Provides `TimeInstant`/`TimeSource` implementations backed by `embassy_time` and
+//! `SequencerTask`, which owns a sequencer inside an async task and processes
+//! `SequencerCommand`s from an `embassy_sync` channel between service deadlines.
+
+use crate::command::SequencerCommand;
+use crate::sequencer::{RgbLed, RgbSequencer, SequencerError, ServiceDeadline};
+use crate::time::{TimeDuration, TimeInstant, TimeSource};
+use embassy_futures::select::{Either, select};
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::channel::Receiver;
+use embassy_time::{Duration, Instant, Timer};
+
+/// Default service interval for `ServiceTiming::Continuous` (about 60 FPS).
+pub const DEFAULT_FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// `embassy_time::Duration` implementing `TimeDuration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EmbassyDuration(pub Duration);
+
+impl TimeDuration for EmbassyDuration {
+    const ZERO: Self = EmbassyDuration(Duration::from_ticks(0));
+
+    fn as_millis(&self) -> u64 {
+        self.0.as_millis()
+    }
+
+    fn from_millis(millis: u64) -> Self {
+        EmbassyDuration(Duration::from_millis(millis))
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        EmbassyDuration(Duration::from_ticks(
+            self.0.as_ticks().saturating_sub(other.0.as_ticks()),
+        ))
+    }
+}
+
+/// `embassy_time::Instant` implementing `TimeInstant`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EmbassyInstant(pub Instant);
+
+impl TimeInstant for EmbassyInstant {
+    type Duration = EmbassyDuration;
+
+    fn duration_since(&self, earlier: Self) -> Self::Duration {
+        EmbassyDuration(self.0.saturating_duration_since(earlier.0))
+    }
+
+    fn checked_add(self, duration: Self::Duration) -> Option<Self> {
+        self.0.checked_add(duration.0).map(EmbassyInstant)
+    }
+
+    fn checked_sub(self, duration: Self::Duration) -> Option<Self> {
+        self.0.checked_sub(duration.0).map(EmbassyInstant)
+    }
+}
+
+/// Time source backed by `embassy_time::Instant::now()`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmbassyTimeSource;
+
+impl TimeSource<EmbassyInstant> for EmbassyTimeSource {
+    fn now(&self) -> EmbassyInstant {
+        EmbassyInstant(Instant::now())
+    }
+}
+
+/// Command type carried by a `SequencerTask` channel.
+pub type EmbassyCommand<Id, const N: usize> = SequencerCommand<Id, EmbassyDuration, N>;
+
+/// Async task body that owns a sequencer and feeds it commands from a channel.
+///
+/// Each `run_once()` waits for whichever comes first: a command, or the next service
+/// deadline. Commands are applied with `handle_action()` and followed by an immediate
+/// service, so changes show without waiting for the old deadline. The `led_id` of
+/// incoming commands is ignored; give each task its own channel.
+///
+/// While the sequencer is neither running nor held the task sleeps until the next command.
+pub struct SequencerTask<'a, 't, M, Id, L, T, const N: usize, const DEPTH: usize>
+where
+    M: RawMutex,
+    L: RgbLed,
+    T: TimeSource<EmbassyInstant>,
+{
+    sequencer: RgbSequencer<'t, EmbassyInstant, L, T, N>,
+    commands: Receiver<'a, M, EmbassyCommand<Id, N>, DEPTH>,
+    frame_interval: Duration,
+    next_deadline: Option<Instant>,
+}
+
+impl<'a, 't, M, Id, L, T, const N: usize, const DEPTH: usize>
+    SequencerTask<'a, 't, M, Id, L, T, N, DEPTH>
+where
+    M: RawMutex,
+    L: RgbLed,
+    T: TimeSource<EmbassyInstant>,
+{
+    /// Creates a task from a sequencer and the receiving end of a command channel.
+    ///
+    /// If the sequencer is already running, it is serviced on the first `run_once()`.
+    pub fn new(
+        sequencer: RgbSequencer<'t, EmbassyInstant, L, T, N>,
+        commands: Receiver<'a, M, EmbassyCommand<Id, N>, DEPTH>,
+    ) -> Self {
+        Self {
+            sequencer,
+            commands,
+            frame_interval: DEFAULT_FRAME_INTERVAL,
+            next_deadline: Some(Instant::MIN),
+        }
+    }
+
+    /// Sets the service interval used for `ServiceTiming::Continuous`.
+    pub fn with_frame_interval(mut self, frame_interval: Duration) -> Self {
+        self.frame_interval = frame_interval;
+        self
+    }
+
+    /// Returns the service interval used for `ServiceTiming::Continuous`.
+    #[inline]
+    pub fn frame_interval(&self) -> Duration {
+        self.frame_interval
+    }
+
+    /// Returns the sequencer.
+    #[inline]
+    pub fn sequencer(&self) -> &RgbSequencer<'t, EmbassyInstant, L, T, N> {
+        &self.sequencer
+    }
+
+    /// Returns the sequencer mutably.
+    ///
+    /// The next `run_once()` services it immediately, so direct changes (including
+    /// starting a sequence) take effect without waiting for a command.
+    #[inline]
+    pub fn sequencer_mut(&mut self) -> &mut RgbSequencer<'t, EmbassyInstant, L, T, N> {
+        self.next_deadline = Some(Instant::MIN);
+        &mut self.sequencer
+    }
+
+    /// Consumes the task and returns the sequencer.
+    #[inline]
+    pub fn into_sequencer(self) -> RgbSequencer<'t, EmbassyInstant, L, T, N> {
+        self.sequencer
+    }
+
+    /// Waits for the next command or service deadline and handles it.
+    ///
+    /// Returns the error from the command or the service call, if any. The task keeps
+    /// working after an error.
+    pub async fn run_once(&mut self) -> Result<(), SequencerError> {
+        let command = match self.next_deadline {
+            Some(deadline) => match select(self.commands.receive(), Timer::at(deadline)).await {
+                Either::First(command) => Some(command),
+                Either::Second(()) => None,
+            },
+            None => Some(self.commands.receive().await),
+        };
+
+        let result = match command {
+            Some(command) => self.sequencer.handle_action(command.action),
+            None => Ok(()),
+        };
+        let serviced = self.service();
+        result.and(serviced)
+    }
+
+    /// Runs the task forever, discarding errors.
+    ///
+    /// Use `run_once()` in a loop to log or react to errors instead.
+    pub async fn run(&mut self) -> ! {
+        loop {
+            let _ = self.run_once().await;
+        }
+    }
+
+    /// Services a running or held sequencer and records when the next service is due.
+    fn service(&mut self) -> Result<(), SequencerError> {
+        self.next_deadline = None;
+        if !self.sequencer.needs_service() {
+            return Ok(());
+        }
+
+        self.next_deadline = match self.sequencer.service_deadline()? {
+            ServiceDeadline::Continuous => Some(Instant::now() + self.frame_interval),
+            ServiceDeadline::At(deadline) => Some(deadline.0),
+            ServiceDeadline::Complete => None,
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+//! Capacity-erased sequencers for collections with mixed step capacities.
+//!
+//! `SequencerCollection` stores its sequencers by value, so every LED shares one step
+//! capacity `N`. An `ErasedCollection` instead borrows sequencers through the object-safe
+//! `ErasedSequencer` trait, so a status LED that only blinks can use a 2-step sequencer
+//! next to a 32-step ambient light without sizing both for 32 steps. Sequences are passed
+//! in as `RgbSequenceRef` views and copied into each sequencer's own capacity.
+
+use crate::collection::{CollectionError, ErrorReport, first_error, merge_service_results};
+use crate::command::{SequencerAction, SequencerCommand};
+use crate::sequence::{RgbSequence, RgbSequenceRef};
+use crate::sequencer::{RgbLed, RgbSequencer, SequencerError, SequencerState, ServiceTiming};
+use crate::time::{TimeDuration, TimeInstant, TimeSource};
+use heapless::Vec;
+use palette::Srgb;
+
+/// Object-safe control of a sequencer whose LED type and step capacity are erased.
+///
+/// Implemented by every `RgbSequencer`. Methods mirror the `RgbSequencer` methods of the
+/// same name, except that sequences are taken as views.
+pub trait ErasedSequencer<I: TimeInstant> {
+    /// Copies `sequence` into the sequencer and loads it (see `RgbSequencer::try_load()`).
+    ///
+    /// Returns `SequencerError::CapacityExceeded` if it has more steps than `capacity()`.
+    fn load(&mut self, sequence: RgbSequenceRef<'_, I::Duration>) -> Result<(), SequencerError>;
+
+    /// Copies, loads and starts `sequence` (see `RgbSequencer::load_and_start()`).
+    fn load_and_start(
+        &mut self,
+        sequence: RgbSequenceRef<'_, I::Duration>,
+    ) -> Result<(), SequencerError>;
+
+    /// Starts the loaded sequence (see `RgbSequencer::start()`).
+    fn start(&mut self) -> Result<(), SequencerError>;
+
+    /// Stops and turns off the LED (see `RgbSequencer::stop()`).
+    fn stop(&mut self) -> Result<(), SequencerError>;
+
+    /// Pauses at the current color (see `RgbSequencer::pause()`).
+    fn pause(&mut self) -> Result<(), SequencerError>;
+
+    /// Resumes from pause or hold (see `RgbSequencer::resume()`).
+    fn resume(&mut self) -> Result<(), SequencerError>;
+
+    /// Holds the current color while the timeline runs on (see `RgbSequencer::hold()`).
+    fn hold(&mut self) -> Result<(), SequencerError>;
+
+    /// Restarts from the beginning (see `RgbSequencer::restart()`).
+    fn restart(&mut self) -> Result<(), SequencerError>;
+
+    /// Jumps to `position` in the timeline (see `RgbSequencer::seek()`).
+    fn seek(&mut self, position: I::Duration) -> Result<(), SequencerError>;
+
+    /// Clears the sequence and turns off the LED (see `RgbSequencer::clear()`).
+    fn clear(&mut self);
+
+    /// Sets the brightness multiplier (see `RgbSequencer::set_brightness()`).
+    fn set_brightness(&mut self, brightness: f32);
+
+    /// Sets the playback speed multiplier (see `RgbSequencer::set_speed()`).
+    fn set_speed(&mut self, speed: f32);
+
+    /// Services the sequencer (see `RgbSequencer::service()`).
+    fn service(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError>;
+
+    /// Services the sequencer if it is running or held (see
+    /// `RgbSequencer::service_if_running()`).
+    fn service_if_running(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError>;
+
+    /// Returns the current state.
+    fn state(&self) -> SequencerState;
+
+    /// Returns the current color (see `RgbSequencer::current_color()`).
+    fn current_color(&self) -> Srgb;
+
+    /// Returns the maximum number of steps a loaded sequence may have.
+    fn capacity(&self) -> usize;
+}
+
+impl<I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> ErasedSequencer<I>
+    for RgbSequencer<'_, I, L, T, N>
+{
+    fn load(&mut self, sequence: RgbSequenceRef<'_, I::Duration>) -> Result<(), SequencerError> {
+        RgbSequencer::try_load(self, copy_sequence(sequence)?)
+    }
+
+    fn load_and_start(
+        &mut self,
+        sequence: RgbSequenceRef<'_, I::Duration>,
+    ) -> Result<(), SequencerError> {
+        RgbSequencer::load_and_start(self, copy_sequence(sequence)?)
+    }
+
+    fn start(&mut self) -> Result<(), SequencerError> {
+        RgbSequencer::start(self)
+    }
+
+    fn stop(&mut self) -> Result<(), SequencerError> {
+        RgbSequencer::stop(self)
+    }
+
+    fn pause(&mut self) -> Result<(), SequencerError> {
+        RgbSequencer::pause(self)
+    }
+
+    fn resume(&mut self) -> Result<(), SequencerError> {
+        RgbSequencer::resume(self)
+    }
+
+    fn hold(&mut self) -> Result<(), SequencerError> {
+        RgbSequencer::hold(self)
+    }
+
+    fn restart(&mut self) -> Result<(), SequencerError> {
+        RgbSequencer::restart(self)
+    }
+
+    fn seek(&mut self, position: I::Duration) -> Result<(), SequencerError> {
+        RgbSequencer::seek(self, position)
+    }
+
+    fn clear(&mut self) {
+        RgbSequencer::clear(self)
+    }
+
+    fn set_brightness(&mut self, brightness: f32) {
+        RgbSequencer::set_brightness(self, brightness)
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        RgbSequencer::set_speed(self, speed)
+    }
+
+    fn service(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        RgbSequencer::service(self)
+    }
+
+    fn service_if_running(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        RgbSequencer::service_if_running(self)
+    }
+
+    fn state(&self) -> SequencerState {
+        RgbSequencer::state(self)
+    }
+
+    fn current_color(&self) -> Srgb {
+        RgbSequencer::current_color(self)
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+/// Copies a view into an owned sequence of capacity `N`.
+fn copy_sequence<D: TimeDuration, const N: usize>(
+    sequence: RgbSequenceRef<'_, D>,
+) -> Result<RgbSequence<D, N>, SequencerError> {
+    RgbSequence::try_from(sequence).map_err(|_| SequencerError::CapacityExceeded)
+}
+
+/// Up to `CAPACITY` borrowed sequencers of any LED type and step capacity.
+///
+/// LEDs are addressed by the index `add()` returned. Commands may carry a sequence of any
+/// capacity; it is copied into the target sequencer when executed.
+pub struct ErasedCollection<'m, I: TimeInstant, const CAPACITY: usize> {
+    sequencers: Vec<&'m mut dyn ErasedSequencer<I>, CAPACITY>,
+}
+
+impl<'m, I: TimeInstant, const CAPACITY: usize> ErasedCollection<'m, I, CAPACITY> {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self {
+            sequencers: Vec::new(),
+        }
+    }
+
+    /// Registers `sequencer` and returns its LED ID.
+    ///
+    /// Returns `CollectionError::Full` if `CAPACITY` sequencers are already registered.
+    pub fn add(
+        &mut self,
+        sequencer: &'m mut dyn ErasedSequencer<I>,
+    ) -> Result<usize, CollectionError> {
+        self.sequencers
+            .push(sequencer)
+            .map_err(|_| CollectionError::Full)?;
+        Ok(self.sequencers.len() - 1)
+    }
+
+    /// Returns the sequencer at `id`.
+    pub fn get_mut(
+        &mut self,
+        id: usize,
+    ) -> Result<&mut (dyn ErasedSequencer<I> + 'm), CollectionError> {
+        self.sequencers
+            .get_mut(id)
+            .map(|sequencer| &mut **sequencer)
+            .ok_or(CollectionError::InvalidId(id))
+    }
+
+    /// Routes command to the sequencer at `command.led_id`.
+    ///
+    /// `Load` and `LoadAndStart` fail with `SequencerError::CapacityExceeded` if the
+    /// sequence has more steps than the target sequencer holds. Scheduling is not
+    /// supported: a command created with `SequencerCommand::at()` is rejected with
+    /// `CollectionError::ScheduleFull`, like a `SequencerCollection` without a queue.
+    pub fn handle_command<const N: usize>(
+        &mut self,
+        command: SequencerCommand<usize, I::Duration, N>,
+    ) -> Result<(), CollectionError> {
+        let sequencer = self.get_mut(command.led_id)?;
+        if command.execute_at.is_some() {
+            return Err(CollectionError::ScheduleFull);
+        }
+        match command.action {
+            SequencerAction::Load(sequence) => sequencer.load(sequence.as_view())?,
+            SequencerAction::LoadAndStart(sequence) => {
+                sequencer.load_and_start(sequence.as_view())?
+            }
+            SequencerAction::Start => sequencer.start()?,
+            SequencerAction::Stop => sequencer.stop()?,
+            SequencerAction::Pause => sequencer.pause()?,
+            SequencerAction::Resume => sequencer.resume()?,
+            SequencerAction::Hold => sequencer.hold()?,
+            SequencerAction::Restart => sequencer.restart()?,
+            SequencerAction::Seek(position) => sequencer.seek(position)?,
+            SequencerAction::Clear => sequencer.clear(),
+            SequencerAction::SetBrightness(brightness) => sequencer.set_brightness(brightness),
+            SequencerAction::SetSpeed(speed) => sequencer.set_speed(speed),
+        }
+        Ok(())
+    }
+
+    /// Services every running and held sequencer and returns the most urgent timing hint.
+    ///
+    /// Returns `ServiceTiming::Complete` if none is running or held. Every sequencer is
+    /// serviced even if one fails; the first error is returned afterwards. Use
+    /// `service_all_lossy()` to get the timing hint and all errors.
+    pub fn service_all(&mut self) -> Result<ServiceTiming<I::Duration>, CollectionError> {
+        first_error(self.service_all_lossy())
+    }
+
+    /// Services every running and held sequencer, collecting per-LED errors instead of
+    /// stopping (see `SequencerCollection::service_all_lossy()`).
+    pub fn service_all_lossy(&mut self) -> (ServiceTiming<I::Duration>, ErrorReport<CAPACITY>) {
+        let mut errors = ErrorReport::new();
+        let results = self
+            .sequencers
+            .iter_mut()
+            .enumerate()
+            .map(|(id, sequencer)| (id, sequencer.service_if_running()));
+        let timing = merge_service_results(results, ServiceTiming::Complete, &mut errors);
+        (timing, errors)
+    }
+
+    /// Returns the number of registered sequencers.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.sequencers.len()
+    }
+
+    /// Returns true if no sequencer is registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.sequencers.is_empty()
+    }
+
+    /// Returns the maximum number of sequencers.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+}
+
+impl<I: TimeInstant, const CAPACITY: usize> Default for ErasedCollection<'_, I, CAPACITY> {
+    /// Returns an empty collection.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,133 @@
+//! On/off RGB LEDs on plain GPIO pins (requires the `embedded-hal-1` feature).
+//!
+//! `BinaryRgbLed` approximates intermediate colors with temporal dithering: each channel
+//! is switched fully on or off per `tick()`, with a first-order sigma-delta accumulator
+//! choosing the pattern so the on-time averages out to the requested level.
+
+use crate::sequencer::RgbLed;
+use embedded_hal_1::digital::OutputPin;
+use palette::Srgb;
+
+/// RGB LED on three digital output pins, dithered in software.
+///
+/// The sequencer only calls `set_color()` when the color changes, so the application
+/// must call `tick()` at a steady rate (every pass of the service loop, or from a timer
+/// interrupt) to keep the dithering running, including during `ServiceTiming::Delay`
+/// holds. Faster ticks mean less visible flicker; at 1 kHz a 1% level blinks at 10 Hz,
+/// so very dim levels flicker unless the tick rate is high. Full-on and full-off levels
+/// never toggle.
+#[derive(Debug)]
+pub struct BinaryRgbLed<R, G, B> {
+    red: R,
+    green: G,
+    blue: B,
+    levels: [f32; 3],
+    accumulators: [f32; 3],
+    states: [bool; 3],
+    common_anode: bool,
+    errors: u32,
+}
+
+impl<R: OutputPin, G: OutputPin, B: OutputPin> BinaryRgbLed<R, G, B> {
+    /// Creates an LED from three output pins and switches all channels off.
+    ///
+    /// Pass `true` for `common_anode` when the LED's common pin is tied to VCC (a channel
+    /// is lit by driving its pin low), `false` for common cathode.
+    pub fn new(red: R, green: G, blue: B, common_anode: bool) -> Self {
+        let mut led = Self {
+            red,
+            green,
+            blue,
+            levels: [0.0; 3],
+            accumulators: [0.0; 3],
+            states: [false; 3],
+            common_anode,
+            errors: 0,
+        };
+        for channel in 0..3 {
+            led.write(channel, false);
+        }
+        led
+    }
+
+    /// Advances the dither pattern by one step and updates the pins.
+    ///
+    /// Pins are only written when their state changes.
+    pub fn tick(&mut self) {
+        for channel in 0..3 {
+            let accumulator = self.accumulators[channel] + self.levels[channel];
+            let on = accumulator >= 1.0;
+            self.accumulators[channel] = if on { accumulator - 1.0 } else { accumulator };
+
+            if on != self.states[channel] {
+                self.write(channel, on);
+            }
+        }
+    }
+
+    /// Returns the current dither target of the red, green and blue channels.
+    #[inline]
+    pub fn levels(&self) -> [f32; 3] {
+        self.levels
+    }
+
+    /// Returns whether the red, green and blue channels are currently lit.
+    #[inline]
+    pub fn lit(&self) -> [bool; 3] {
+        self.states
+    }
+
+    /// Returns true if pins are driven low to light a common-anode LED.
+    #[inline]
+    pub fn is_common_anode(&self) -> bool {
+        self.common_anode
+    }
+
+    /// Returns how many pin writes have failed, saturating at `u32::MAX`.
+    #[inline]
+    pub fn error_count(&self) -> u32 {
+        self.errors
+    }
+
+    /// Consumes the LED and returns the red, green and blue pins.
+    #[inline]
+    pub fn into_pins(self) -> (R, G, B) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// Drives one channel on or off, honoring common-anode inversion.
+    fn write(&mut self, channel: usize, on: bool) {
+        let high = on != self.common_anode;
+        let result = match (channel, high) {
+            (0, true) => self.red.set_high().is_ok(),
+            (0, false) => self.red.set_low().is_ok(),
+            (1, true) => self.green.set_high().is_ok(),
+            (1, false) => self.green.set_low().is_ok(),
+            (_, true) => self.blue.set_high().is_ok(),
+            (_, false) => self.blue.set_low().is_ok(),
+        };
+
+        if result {
+            self.states[channel] = on;
+        } else {
+            self.errors = self.errors.saturating_add(1);
+        }
+    }
+}
+
+impl<R: OutputPin, G: OutputPin, B: OutputPin> RgbLed for BinaryRgbLed<R, G, B> {
+    /// Sets the dither target and applies it with an immediate tick.
+    ///
+    /// Accumulated error is kept across color changes, so fades stay smooth.
+    fn set_color(&mut self, color: Srgb) {
+        // A NaN would poison the accumulators for good, so treat it as off
+        self.levels = [color.red, color.green, color.blue].map(|level| {
+            if level.is_nan() {
+                0.0
+            } else {
+                level.clamp(0.0, 1.0)
+            }
+        });
+        self.tick();
+    }
+}
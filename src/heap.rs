@@ -0,0 +1,282 @@
+//! Heap-backed sequences (requires the `alloc` feature).
+//!
+//! `HeapSequence` stores its steps in an `alloc::vec::Vec`, so host tools and large MCUs
+//! can build sequences of any length without choosing a capacity at compile time. It
+//! evaluates through `RgbSequenceRef`, sharing every line of evaluation code with
+//! `RgbSequence`, and converts to an `RgbSequence<D, N>` for loading into a sequencer.
+
+extern crate alloc;
+
+use crate::BLACK;
+use crate::sequence::{RgbSequence, RgbSequenceRef, sanitize_color, validate_steps};
+use crate::time::TimeDuration;
+use crate::types::{LoopCount, SequenceError, SequenceStep, TransitionStyle};
+use alloc::vec::Vec;
+use palette::Srgb;
+
+/// A step-based RGB sequence with heap-allocated steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeapSequence<D: TimeDuration> {
+    steps: Vec<SequenceStep<D>>,
+    loop_count: LoopCount,
+    start_color: Option<Srgb>,
+    landing_color: Option<Srgb>,
+    loop_duration: D,
+    loop_start: usize,
+    intro_duration: D,
+    start_delay: D,
+    end_hold: D,
+    brightness: f32,
+}
+
+impl<D: TimeDuration> HeapSequence<D> {
+    /// Creates a new builder.
+    pub fn builder() -> HeapSequenceBuilder<D> {
+        HeapSequenceBuilder::new()
+    }
+
+    /// Copies a step-based sequence of any capacity.
+    ///
+    /// Returns `SequenceError::EmptySequence` for function-based sequences, which have no
+    /// steps to store.
+    pub fn from_view(view: RgbSequenceRef<'_, D>) -> Result<Self, SequenceError> {
+        if view.is_function_based() {
+            return Err(SequenceError::EmptySequence);
+        }
+
+        Ok(Self {
+            steps: view.steps().to_vec(),
+            loop_count: view.loop_count,
+            start_color: view.start_color,
+            landing_color: view.landing_color,
+            loop_duration: view.loop_duration,
+            loop_start: view.loop_start,
+            intro_duration: view.intro_duration,
+            start_delay: view.start_delay,
+            end_hold: view.end_hold,
+            brightness: view.brightness,
+        })
+    }
+
+    /// Returns a view for evaluation and queries (`evaluate()`, `find_step_position()`,
+    /// `total_duration()`, ...).
+    #[inline]
+    pub fn as_view(&self) -> RgbSequenceRef<'_, D> {
+        RgbSequenceRef {
+            steps: &self.steps,
+            loop_count: self.loop_count,
+            start_color: self.start_color,
+            landing_color: self.landing_color,
+            loop_duration: self.loop_duration,
+            loop_start: self.loop_start,
+            intro_duration: self.intro_duration,
+            start_delay: self.start_delay,
+            end_hold: self.end_hold,
+            brightness: self.brightness,
+            color_fn: None,
+            timing_fn: None,
+        }
+    }
+
+    /// Evaluates color and next service time at elapsed time.
+    ///
+    /// See `RgbSequence::evaluate()`.
+    #[inline]
+    pub fn evaluate(&self, elapsed: D) -> (Srgb, Option<D>) {
+        self.as_view().evaluate(elapsed)
+    }
+
+    /// Returns the steps.
+    #[inline]
+    pub fn steps(&self) -> &[SequenceStep<D>] {
+        &self.steps
+    }
+
+    /// Returns step count.
+    #[inline]
+    pub fn step_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns time from start until completion, or `None` for infinite sequences.
+    #[inline]
+    pub fn total_duration(&self) -> Option<D> {
+        self.as_view().total_duration()
+    }
+
+    /// Copies the sequence into a fixed-capacity `RgbSequence` for loading into a sequencer.
+    ///
+    /// Returns `SequenceError::CapacityExceeded` if the steps don't fit in `N`.
+    pub fn to_sequence<const N: usize>(&self) -> Result<RgbSequence<D, N>, SequenceError> {
+        RgbSequence::try_from(self.as_view())
+    }
+}
+
+impl<'a, D: TimeDuration> From<&'a HeapSequence<D>> for RgbSequenceRef<'a, D> {
+    #[inline]
+    fn from(sequence: &'a HeapSequence<D>) -> Self {
+        sequence.as_view()
+    }
+}
+
+/// Builder for heap-backed sequences.
+///
+/// Mirrors `SequenceBuilder`, except that adding steps cannot fail.
+#[derive(Debug)]
+pub struct HeapSequenceBuilder<D: TimeDuration> {
+    steps: Vec<SequenceStep<D>>,
+    loop_count: LoopCount,
+    landing_color: Option<Srgb>,
+    start_color: Option<Srgb>,
+    start_delay: D,
+    end_hold: D,
+    brightness: f32,
+    default_transition: TransitionStyle,
+    loop_start: usize,
+}
+
+impl<D: TimeDuration> HeapSequenceBuilder<D> {
+    /// Creates a new builder.
+    pub fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            loop_count: LoopCount::default(),
+            landing_color: None,
+            start_color: None,
+            start_delay: D::ZERO,
+            end_hold: D::ZERO,
+            brightness: 1.0,
+            default_transition: TransitionStyle::Step,
+            loop_start: 0,
+        }
+    }
+
+    /// Adds a step to the sequence.
+    ///
+    /// NaN color channels become 0.0 and out-of-range channels are clamped to 0.0-1.0.
+    pub fn step(mut self, color: Srgb, duration: D, transition: TransitionStyle) -> Self {
+        self.steps.push(SequenceStep::new(
+            sanitize_color(color),
+            duration,
+            transition,
+        ));
+        self
+    }
+
+    /// Adds one step per color, all sharing `duration` and `transition`.
+    pub fn steps_from<C: IntoIterator<Item = Srgb>>(
+        self,
+        colors: C,
+        duration: D,
+        transition: TransitionStyle,
+    ) -> Self {
+        colors.into_iter().fold(self, |builder, color| {
+            builder.step(color, duration, transition)
+        })
+    }
+
+    /// Adds a step using the builder's default transition style.
+    pub fn step_color(self, color: Srgb, duration: D) -> Self {
+        let transition = self.default_transition;
+        self.step(color, duration, transition)
+    }
+
+    /// Adds a step with the same transition as the previous step (see
+    /// `SequenceBuilder::then()`).
+    pub fn then(self, color: Srgb, duration: D) -> Self {
+        let transition = self
+            .steps
+            .last()
+            .map_or(self.default_transition, |step| step.transition);
+        self.step(color, duration, transition)
+    }
+
+    /// Adds a `Step` that keeps the previous step's color for `duration` (see
+    /// `SequenceBuilder::hold()`).
+    pub fn hold(self, duration: D) -> Self {
+        let color = self.steps.last().map_or(BLACK, |step| step.color);
+        self.step(color, duration, TransitionStyle::Step)
+    }
+
+    /// Sets the transition style used by subsequent `step_color()` calls (default: `Step`).
+    pub fn default_transition(mut self, transition: TransitionStyle) -> Self {
+        self.default_transition = transition;
+        self
+    }
+
+    /// Sets loop count (default: `Finite(1)`).
+    pub fn loop_count(mut self, count: LoopCount) -> Self {
+        self.loop_count = count;
+        self
+    }
+
+    /// Marks steps before `index` as an intro played once; the loop count repeats the rest.
+    pub fn loop_from(mut self, index: usize) -> Self {
+        self.loop_start = index;
+        self
+    }
+
+    /// Sets landing color shown after sequence completes (finite sequences only).
+    pub fn landing_color(mut self, color: Srgb) -> Self {
+        self.landing_color = Some(sanitize_color(color));
+        self
+    }
+
+    /// Sets start color for smooth entry into first step (first loop only).
+    pub fn start_color(mut self, color: Srgb) -> Self {
+        self.start_color = Some(sanitize_color(color));
+        self
+    }
+
+    /// Sets a delay before the first step, shown once at the start.
+    pub fn start_delay(mut self, delay: D) -> Self {
+        self.start_delay = delay;
+        self
+    }
+
+    /// Sets how long the final color is held before completion (finite sequences only).
+    pub fn end_hold(mut self, duration: D) -> Self {
+        self.end_hold = duration;
+        self
+    }
+
+    /// Sets an intensity scale (0.0-1.0, clamped) applied to every color of this sequence.
+    pub fn brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Builds and validates the sequence.
+    ///
+    /// Returns the same errors as `SequenceBuilder::build()`.
+    pub fn build(self) -> Result<HeapSequence<D>, SequenceError> {
+        let (intro_duration, loop_duration) = validate_steps(
+            &self.steps,
+            self.loop_start,
+            self.loop_count,
+            self.start_color,
+            self.landing_color,
+            self.end_hold,
+        )?;
+
+        Ok(HeapSequence {
+            steps: self.steps,
+            loop_count: self.loop_count,
+            start_color: self.start_color,
+            landing_color: self.landing_color,
+            loop_duration,
+            loop_start: self.loop_start,
+            intro_duration,
+            start_delay: self.start_delay,
+            end_hold: self.end_hold,
+            brightness: self.brightness,
+        })
+    }
+}
+
+impl<D: TimeDuration> Default for HeapSequenceBuilder<D> {
+    /// Returns a new default builder.
+    fn default() -> Self {
+        Self::new()
+    }
+}
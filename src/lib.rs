@@ -5,14 +5,32 @@
 //! `no_std` RGB LED animation library for embedded systems.
 //!
 //! Provides step-based and function-based color sequences with trait abstractions for LED hardware and timing.
-//! Zero heap allocation, platform-independent, type-safe colors via `palette::Srgb<f32>`.
+//! Zero heap allocation (optional `alloc` feature for `Vec`-backed sequences), platform-independent, type-safe colors via `palette::Srgb<f32>`.
 //!
 //! # Core Types
 //!
 //! - **`RgbSequence`** - Defines an animation (steps, loops, transitions, colors)
+//! - **`RgbSequenceRef`** - Borrowed view of an `RgbSequence` of any capacity, for APIs not generic over `N`
+//! - **`HeapSequence`** - Sequence with `Vec`-backed steps and no fixed capacity (`alloc` feature)
+//! - **`CompactSequence`** - Stores steps with `u16` millisecond durations for large libraries
+//! - **`PaletteSequence`** - Stores steps as indices into a shared color palette
 //! - **`RgbSequencer`** - Controls a single LED through sequences with state management
+//! - **`SequencerCollection`** - Controls multiple LEDs by ID with a shared time source
+//! - **`ErasedCollection`** - Controls borrowed sequencers with different step capacities by ID
+//! - **`StripSequencer`** - Controls an addressable LED strip, rendering a frame per service call
+//! - **`SyncGroup`** - Starts, pauses and resumes sequencers of different types against one instant
 //! - **`RgbLed`** - Trait for LED hardware abstraction
 //! - **`TimeSource`** - Trait for timing system abstraction
+//! - **`MappedLed`** - Adapts multi-channel LEDs (RGBW, RGBA, ...) via a `ColorMapper`
+//! - **`PwmRgbLed`** - Drives an LED from three PWM channels (`embedded-hal` feature)
+//! - **`DutyCycleRgbLed`** - Same for embedded-hal 1.0 `SetDutyCycle` channels (`embedded-hal-1` feature)
+//! - **`BinaryRgbLed`** - Dithers an LED on three plain GPIO pins (`embedded-hal-1` feature)
+//! - **`Apa102Led`** - Drives an APA102/DotStar pixel over SPI (`embedded-hal-1` feature)
+//! - **`DmxFixture`** - Writes to consecutive slots of a shared DMX512 universe
+//! - **`Sk6812Led`** - Drives SK6812 RGBW pixels with configurable white extraction
+//! - **`SequencerTask`** - Runs a sequencer in an Embassy task fed by a command channel (`embassy` feature)
+//! - **`Frames`** - Async stream of `(instant, color)` frames at the sequencer's cadence (`async` feature)
+//! - **`SimulatorLed`** - Previews sequences as truecolor blocks in a terminal (`std` feature)
 //! - **`TransitionStyle`** - How to animate between colors (Step, Linear, EaseIn/Out)
 //!
 //! # Color Helpers
@@ -24,21 +42,75 @@
 // Re-export Srgb from palette for user convenience
 pub use palette::Srgb;
 
+#[cfg(feature = "embedded-hal-1")]
+pub mod apa102;
+pub mod collection;
 pub mod colors;
 pub mod command;
+pub mod compact;
+pub mod dmx;
+#[cfg(feature = "embassy")]
+pub mod embassy;
+pub mod erased;
+#[cfg(feature = "embedded-hal-1")]
+pub mod gpio;
+#[cfg(feature = "alloc")]
+pub mod heap;
+pub mod output;
+#[cfg(any(feature = "embedded-hal", feature = "embedded-hal-1"))]
+pub mod pwm;
 pub mod sequence;
 pub mod sequencer;
+#[cfg(feature = "std")]
+pub mod simulator;
+pub mod sk6812;
+#[cfg(feature = "async")]
+pub mod stream;
+pub mod strip;
+pub mod sync;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 pub mod time;
 pub mod types;
+pub mod wire;
 
+#[cfg(feature = "embedded-hal-1")]
+pub use apa102::{APA102_MAX_BRIGHTNESS, Apa102Brightness, Apa102Led};
+pub use collection::{CollectionError, CollectionStatus, ErrorReport, SequencerCollection};
 pub use command::{SequencerAction, SequencerCommand};
-pub use sequence::{RgbSequence, SequenceBuilder, StepPosition};
+pub use compact::{CompactSequence, CompactStep, PaletteSequence, PaletteStep};
+pub use dmx::{DMX_SLOTS, DmxBuffer, DmxError, DmxFixture, DmxUniverse};
+#[cfg(feature = "embassy")]
+pub use embassy::{
+    EmbassyCommand, EmbassyDuration, EmbassyInstant, EmbassyTimeSource, SequencerTask,
+};
+pub use erased::{ErasedCollection, ErasedSequencer};
+#[cfg(feature = "embedded-hal-1")]
+pub use gpio::BinaryRgbLed;
+#[cfg(feature = "alloc")]
+pub use heap::{HeapSequence, HeapSequenceBuilder};
+pub use output::{ColorMapper, MappedLed, MultiChannelLed};
+#[cfg(feature = "embedded-hal-1")]
+pub use pwm::DutyCycleRgbLed;
+#[cfg(feature = "embedded-hal")]
+pub use pwm::PwmRgbLed;
+pub use sequence::{ChannelLevels, RgbSequence, RgbSequenceRef, SequenceBuilder, StepPosition};
 pub use sequencer::{
-    DEFAULT_COLOR_EPSILON, Position, RgbLed, RgbSequencer, SequencerError, SequencerState,
-    ServiceTiming,
+    BrightnessCurve, DEFAULT_COLOR_EPSILON, DEFAULT_PHASE_LOCK_GAIN, LoadPolicy, MAX_SPEED,
+    MIN_SPEED, OnComplete, Position, RgbLed, RgbSequencer, SNAPSHOT_SIZE, SequencerConfig,
+    SequencerError, SequencerSnapshot, SequencerState, ServiceDeadline, ServiceTiming,
+    SnapshotError,
 };
+#[cfg(feature = "std")]
+pub use simulator::{SimulatorLed, StdTimeSource};
+pub use sk6812::{RgbwWrite, Sk6812Led, WhiteExtraction, WhiteStrategy};
+#[cfg(feature = "async")]
+pub use stream::Frames;
+pub use strip::{Chase, FrameRenderer, RgbStrip, Scanner, StripSequencer, Uniform, Wipe};
+pub use sync::{SyncGroup, SyncMember};
 pub use time::{TimeDuration, TimeInstant, TimeSource};
 pub use types::{LoopCount, SequenceError, SequenceStep, TransitionStyle};
+pub use wire::{BROADCAST_ID, WireError};
 
 /// Black color (all channels off).
 pub const BLACK: Srgb = Srgb::new(0.0, 0.0, 0.0);
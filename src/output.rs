@@ -0,0 +1,71 @@
+//! Pluggable output stage for LEDs with more than three channels.
+//!
+//! Sequences always evaluate to `Srgb`. A `ColorMapper` converts that color into an
+//! N-channel frame (e.g. RGBW, RGBA, RGB + UV) which `MappedLed` writes to a
+//! `MultiChannelLed`. Since `MappedLed` implements `RgbLed`, it plugs into
+//! `RgbSequencer` unchanged.
+
+use crate::sequencer::RgbLed;
+use palette::Srgb;
+
+/// Converts an evaluated sRGB color into a `C`-channel output frame.
+pub trait ColorMapper<const C: usize> {
+    /// Maps color (0.0-1.0 components) to channel values (0.0-1.0 range).
+    fn map(&self, color: Srgb) -> [f32; C];
+}
+
+impl<const C: usize> ColorMapper<C> for fn(Srgb) -> [f32; C] {
+    #[inline]
+    fn map(&self, color: Srgb) -> [f32; C] {
+        self(color)
+    }
+}
+
+/// Trait for abstracting LED hardware with `C` channels.
+pub trait MultiChannelLed<const C: usize> {
+    /// Sets all channels at once.
+    ///
+    /// Channel values are in 0.0-1.0 range. Convert to your hardware's native format
+    /// in your implementation.
+    fn set_channels(&mut self, channels: [f32; C]);
+}
+
+/// Adapts a `MultiChannelLed` to `RgbLed` through a `ColorMapper`.
+#[derive(Debug)]
+pub struct MappedLed<L, M, const C: usize> {
+    led: L,
+    mapper: M,
+}
+
+impl<L: MultiChannelLed<C>, M: ColorMapper<C>, const C: usize> MappedLed<L, M, C> {
+    /// Creates adapter from LED and mapper.
+    pub fn new(led: L, mapper: M) -> Self {
+        Self { led, mapper }
+    }
+
+    /// Returns the underlying LED.
+    #[inline]
+    pub fn led(&self) -> &L {
+        &self.led
+    }
+
+    /// Returns the color mapper.
+    #[inline]
+    pub fn mapper(&self) -> &M {
+        &self.mapper
+    }
+
+    /// Consumes the adapter and returns the LED and mapper.
+    #[inline]
+    pub fn into_parts(self) -> (L, M) {
+        (self.led, self.mapper)
+    }
+}
+
+impl<L: MultiChannelLed<C>, M: ColorMapper<C>, const C: usize> RgbLed for MappedLed<L, M, C> {
+    #[inline]
+    fn set_color(&mut self, color: Srgb) {
+        let channels = self.mapper.map(color);
+        self.led.set_channels(channels);
+    }
+}
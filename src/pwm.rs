@@ -0,0 +1,236 @@
+//! PWM-driven RGB LEDs (requires the `embedded-hal` or `embedded-hal-1` feature).
+//!
+//! `PwmRgbLed` implements `RgbLed` over three embedded-hal 0.2 `PwmPin` channels and
+//! `DutyCycleRgbLed` over three embedded-hal 1.0 `SetDutyCycle` channels, so an LED
+//! wired to timer outputs works without writing an adapter by hand.
+
+use crate::sequencer::RgbLed;
+#[cfg(feature = "embedded-hal-1")]
+use embedded_hal_1::pwm::SetDutyCycle;
+#[cfg(feature = "embedded-hal")]
+use embedded_hal_02::PwmPin;
+use palette::Srgb;
+
+/// Decodes sRGB to linear light when `gamma` is set.
+#[inline]
+fn decode(color: Srgb, gamma: bool) -> Srgb {
+    if gamma {
+        let linear = color.into_linear::<f32>();
+        Srgb::new(linear.red, linear.green, linear.blue)
+    } else {
+        color
+    }
+}
+
+/// Converts a 0.0-1.0 channel value to a duty cycle, rounding to nearest.
+#[inline]
+fn duty(value: f32, max_duty: u16, common_anode: bool) -> u16 {
+    let duty = (value.clamp(0.0, 1.0) * max_duty as f32 + 0.5) as u16;
+    if common_anode { max_duty - duty } else { duty }
+}
+
+/// RGB LED driven by three `PwmPin` channels with `u16` duty cycles.
+///
+/// Each channel's maximum duty is read once at construction, so channels on timers with
+/// different periods are scaled independently. Common-anode LEDs (common pin to VCC)
+/// are handled by inverting the duty cycle.
+#[cfg(feature = "embedded-hal")]
+#[derive(Debug)]
+pub struct PwmRgbLed<R, G, B> {
+    red: R,
+    green: G,
+    blue: B,
+    max_duty: [u16; 3],
+    common_anode: bool,
+    gamma: bool,
+}
+
+#[cfg(feature = "embedded-hal")]
+impl<R, G, B> PwmRgbLed<R, G, B>
+where
+    R: PwmPin<Duty = u16>,
+    G: PwmPin<Duty = u16>,
+    B: PwmPin<Duty = u16>,
+{
+    /// Creates an LED from three PWM channels and enables them.
+    ///
+    /// Pass `true` for `common_anode` when the LED's common pin is tied to VCC (inverted
+    /// logic), `false` for common cathode.
+    pub fn new(mut red: R, mut green: G, mut blue: B, common_anode: bool) -> Self {
+        red.enable();
+        green.enable();
+        blue.enable();
+
+        Self {
+            max_duty: [
+                red.get_max_duty(),
+                green.get_max_duty(),
+                blue.get_max_duty(),
+            ],
+            red,
+            green,
+            blue,
+            common_anode,
+            gamma: false,
+        }
+    }
+
+    /// Applies sRGB gamma decoding before scaling to duty cycles.
+    ///
+    /// PWM output is linear in light, so decoding makes fades look perceptually even.
+    /// Off by default; leave it off when the sequencer already has `set_linear_output(true)`.
+    pub fn with_gamma(mut self, enabled: bool) -> Self {
+        self.gamma = enabled;
+        self
+    }
+
+    /// Returns true if duty cycles are inverted for a common-anode LED.
+    #[inline]
+    pub fn is_common_anode(&self) -> bool {
+        self.common_anode
+    }
+
+    /// Returns true if gamma decoding is applied.
+    #[inline]
+    pub fn gamma(&self) -> bool {
+        self.gamma
+    }
+
+    /// Consumes the LED and returns the red, green and blue channels.
+    #[inline]
+    pub fn into_channels(self) -> (R, G, B) {
+        (self.red, self.green, self.blue)
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl<R, G, B> RgbLed for PwmRgbLed<R, G, B>
+where
+    R: PwmPin<Duty = u16>,
+    G: PwmPin<Duty = u16>,
+    B: PwmPin<Duty = u16>,
+{
+    fn set_color(&mut self, color: Srgb) {
+        let color = decode(color, self.gamma);
+        let [red_max, green_max, blue_max] = self.max_duty;
+
+        self.red
+            .set_duty(duty(color.red, red_max, self.common_anode));
+        self.green
+            .set_duty(duty(color.green, green_max, self.common_anode));
+        self.blue
+            .set_duty(duty(color.blue, blue_max, self.common_anode));
+    }
+}
+
+/// RGB LED driven by three embedded-hal 1.0 `SetDutyCycle` channels.
+///
+/// Each channel's maximum duty is read once at construction and cached, so channels on
+/// timers with different periods are scaled independently. Common-anode LEDs (common pin
+/// to VCC) are handled by inverting the duty cycle. `RgbLed::set_color()` cannot fail, so
+/// channel errors are counted instead of returned; see `error_count()`.
+#[cfg(feature = "embedded-hal-1")]
+#[derive(Debug)]
+pub struct DutyCycleRgbLed<R, G, B> {
+    red: R,
+    green: G,
+    blue: B,
+    max_duty: [u16; 3],
+    common_anode: bool,
+    gamma: bool,
+    errors: u32,
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl<R, G, B> DutyCycleRgbLed<R, G, B>
+where
+    R: SetDutyCycle,
+    G: SetDutyCycle,
+    B: SetDutyCycle,
+{
+    /// Creates an LED from three duty-cycle channels.
+    ///
+    /// Pass `true` for `common_anode` when the LED's common pin is tied to VCC (inverted
+    /// logic), `false` for common cathode.
+    pub fn new(red: R, green: G, blue: B, common_anode: bool) -> Self {
+        Self {
+            max_duty: [
+                red.max_duty_cycle(),
+                green.max_duty_cycle(),
+                blue.max_duty_cycle(),
+            ],
+            red,
+            green,
+            blue,
+            common_anode,
+            gamma: false,
+            errors: 0,
+        }
+    }
+
+    /// Applies sRGB gamma decoding before scaling to duty cycles.
+    ///
+    /// Off by default; leave it off when the sequencer already has `set_linear_output(true)`.
+    pub fn with_gamma(mut self, enabled: bool) -> Self {
+        self.gamma = enabled;
+        self
+    }
+
+    /// Returns true if duty cycles are inverted for a common-anode LED.
+    #[inline]
+    pub fn is_common_anode(&self) -> bool {
+        self.common_anode
+    }
+
+    /// Returns true if gamma decoding is applied.
+    #[inline]
+    pub fn gamma(&self) -> bool {
+        self.gamma
+    }
+
+    /// Returns the cached maximum duty of the red, green and blue channels.
+    #[inline]
+    pub fn max_duty(&self) -> [u16; 3] {
+        self.max_duty
+    }
+
+    /// Returns how many channel writes have failed, saturating at `u32::MAX`.
+    #[inline]
+    pub fn error_count(&self) -> u32 {
+        self.errors
+    }
+
+    /// Consumes the LED and returns the red, green and blue channels.
+    #[inline]
+    pub fn into_channels(self) -> (R, G, B) {
+        (self.red, self.green, self.blue)
+    }
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl<R, G, B> RgbLed for DutyCycleRgbLed<R, G, B>
+where
+    R: SetDutyCycle,
+    G: SetDutyCycle,
+    B: SetDutyCycle,
+{
+    fn set_color(&mut self, color: Srgb) {
+        let color = decode(color, self.gamma);
+        let [red_max, green_max, blue_max] = self.max_duty;
+
+        // Write every channel even if an earlier one failed
+        let results = [
+            self.red
+                .set_duty_cycle(duty(color.red, red_max, self.common_anode))
+                .is_ok(),
+            self.green
+                .set_duty_cycle(duty(color.green, green_max, self.common_anode))
+                .is_ok(),
+            self.blue
+                .set_duty_cycle(duty(color.blue, blue_max, self.common_anode))
+                .is_ok(),
+        ];
+        let failed = results.iter().filter(|ok| !**ok).count() as u32;
+        self.errors = self.errors.saturating_add(failed);
+    }
+}
@@ -16,6 +16,7 @@ fn apply_easing(t: f32, transition: TransitionStyle) -> f32 {
     match transition {
         TransitionStyle::Step => t,
         TransitionStyle::Linear => t,
+        TransitionStyle::CatmullRom => t,
         TransitionStyle::EaseIn => t * t,
         TransitionStyle::EaseOut => t * (2.0 - t),
         TransitionStyle::EaseInOut => {
@@ -39,6 +40,62 @@ fn apply_easing(t: f32, transition: TransitionStyle) -> f32 {
     }
 }
 
+/// Replaces NaN channels with 0.0 and clamps all channels to 0.0-1.0.
+///
+/// A single NaN would otherwise poison interpolation and the sequencer's change detection.
+#[inline]
+pub(crate) fn sanitize_color(color: Srgb) -> Srgb {
+    let channel = |value: f32| {
+        if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        }
+    };
+
+    Srgb::new(
+        channel(color.red),
+        channel(color.green),
+        channel(color.blue),
+    )
+}
+
+/// Evaluates a uniform Catmull-Rom spline between `p1` and `p2` per channel.
+///
+/// The curve passes through every step color with a continuous slope at step boundaries.
+/// Channels are clamped to 0.0-1.0 since the spline can overshoot.
+#[inline]
+fn catmull_rom(p0: Srgb, p1: Srgb, p2: Srgb, p3: Srgb, t: f32) -> Srgb {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let channel = |a: f32, b: f32, c: f32, d: f32| {
+        let value = 0.5
+            * (2.0 * b
+                + (c - a) * t
+                + (2.0 * a - 5.0 * b + 4.0 * c - d) * t2
+                + (3.0 * b - a - 3.0 * c + d) * t3);
+        value.clamp(0.0, 1.0)
+    };
+
+    Srgb::new(
+        channel(p0.red, p1.red, p2.red, p3.red),
+        channel(p0.green, p1.green, p2.green, p3.green),
+        channel(p0.blue, p1.blue, p2.blue, p3.blue),
+    )
+}
+
+/// Samples taken per interpolating step when analyzing channel levels.
+const LEVEL_SAMPLES_PER_STEP: u64 = 16;
+
+/// Per-channel output levels of a sequence over one loop.
+#[derive(Debug, Clone, Copy)]
+pub struct ChannelLevels {
+    /// Time-weighted average level of each channel.
+    pub average: Srgb,
+    /// Highest level each channel reaches.
+    pub peak: Srgb,
+}
+
 /// Position within a sequence.
 #[derive(Debug, Clone, Copy)]
 pub struct StepPosition<D: TimeDuration> {
@@ -54,6 +111,17 @@ pub struct StepPosition<D: TimeDuration> {
     pub current_loop: u32,
 }
 
+/// Cached step lookup state carried between evaluations.
+///
+/// Records the last found step and its start offset within the loop so the next lookup
+/// can verify and advance from there instead of scanning from step 0. The default cursor
+/// points at step 0, which is equivalent to a full scan.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct StepCursor {
+    step_index: usize,
+    step_start_millis: u64,
+}
+
 /// An RGB color sequence.
 #[derive(Debug, Clone)]
 pub struct RgbSequence<D: TimeDuration, const N: usize> {
@@ -62,11 +130,23 @@ pub struct RgbSequence<D: TimeDuration, const N: usize> {
     start_color: Option<Srgb>,
     landing_color: Option<Srgb>,
     loop_duration: D,
+    loop_start: usize,
+    intro_duration: D,
+    start_delay: D,
+    end_hold: D,
+    brightness: f32,
 
     color_fn: Option<fn(Srgb, D) -> Srgb>,
     timing_fn: Option<fn(D) -> Option<D>>,
 }
 
+impl<D: TimeDuration, const N: usize> PartialEq for RgbSequence<D, N> {
+    /// Compares like `RgbSequenceRef`: same steps, settings and functions.
+    fn eq(&self, other: &Self) -> bool {
+        self.as_view() == other.as_view()
+    }
+}
+
 impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
     /// Creates a new sequence builder for step-based sequences.
     pub fn builder() -> SequenceBuilder<D, N> {
@@ -76,6 +156,8 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
     /// Creates a function-based sequence for algorithmic animations.
     ///
     /// The `color_fn` receives base color and elapsed time, returning the current color.
+    /// Returned colors are clamped to 0.0-1.0 with NaN channels replaced by 0.0; a NaN also
+    /// trips a debug assertion.
     /// The `timing_fn` returns next service delay (`Some(D::ZERO)` for continuous updates,
     /// `Some(delay)` to wait, `None` when complete).
     pub fn from_function(
@@ -88,12 +170,31 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
             loop_count: LoopCount::Finite(1),
             landing_color: None,
             loop_duration: D::ZERO,
+            loop_start: 0,
+            intro_duration: D::ZERO,
+            start_delay: D::ZERO,
+            end_hold: D::ZERO,
+            brightness: 1.0,
             start_color: Some(base_color),
             color_fn: Some(color_fn),
             timing_fn: Some(timing_fn),
         }
     }
 
+    /// Sets the logical period of a function-based sequence.
+    ///
+    /// The period is what one loop of the animation lasts, e.g. one breath of a breathing
+    /// effect. It lets the sequencer report a loop number (`current_position()`) and a
+    /// phase (`current_phase()`) for the sequence, so app logic can sync to it. It does not
+    /// affect the color or timing functions. Has no effect on step-based sequences, whose
+    /// period is their loop duration.
+    pub fn with_period(mut self, period: D) -> Self {
+        if self.is_function_based() {
+            self.loop_duration = period;
+        }
+        self
+    }
+
     /// Creates a simple solid color sequence with zero duration.
     ///
     /// Returns `SequenceError::CapacityExceeded` if `N < 1`.
@@ -103,20 +204,371 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
             .build()
     }
 
+    /// Creates a single-pass step sequence from a table of `(color, duration, transition)`.
+    ///
+    /// Suited to step data stored in arrays or flash. Use the builder for loop counts and
+    /// other options. Returns the same errors as `SequenceBuilder::build()`.
+    pub fn from_steps(steps: &[(Srgb, D, TransitionStyle)]) -> Result<Self, SequenceError> {
+        steps
+            .iter()
+            .try_fold(
+                Self::builder(),
+                |builder, &(color, duration, transition)| builder.step(color, duration, transition),
+            )?
+            .build()
+    }
+
+    /// Returns a capacity-erased view of this sequence.
+    ///
+    /// Functions taking `RgbSequenceRef` accept sequences of any capacity without being
+    /// generic over `N`.
+    #[inline]
+    pub fn as_view(&self) -> RgbSequenceRef<'_, D> {
+        RgbSequenceRef {
+            steps: &self.steps,
+            loop_count: self.loop_count,
+            start_color: self.start_color,
+            landing_color: self.landing_color,
+            loop_duration: self.loop_duration,
+            loop_start: self.loop_start,
+            intro_duration: self.intro_duration,
+            start_delay: self.start_delay,
+            end_hold: self.end_hold,
+            brightness: self.brightness,
+            color_fn: self.color_fn,
+            timing_fn: self.timing_fn,
+        }
+    }
+
+    /// Returns the logical period of a function-based sequence, if one was set.
+    #[inline]
+    pub fn period(&self) -> Option<D> {
+        self.as_view().period()
+    }
+
+    /// Returns how far through its current loop the sequence is at `elapsed`, from 0.0 to 1.0.
+    ///
+    /// Step-based sequences report 0.0 during the start delay and intro. Returns `None` once
+    /// the sequence has completed, for zero-duration loops, and for function-based
+    /// sequences without a period.
+    #[inline]
+    pub fn phase_at(&self, elapsed: D) -> Option<f32> {
+        self.as_view().phase_at(elapsed)
+    }
+
+    /// Evaluates color and next service time at elapsed time.
+    ///
+    /// Returns `(color, timing)` where timing is `Some(D::ZERO)` for continuous animation,
+    /// `Some(delay)` for static hold, or `None` when sequence completes.
+    #[inline]
+    pub fn evaluate(&self, elapsed: D) -> (Srgb, Option<D>) {
+        self.as_view().evaluate(elapsed)
+    }
+
+    /// Evaluates color and timing, using and updating a cached step cursor.
+    ///
+    /// Falls back to a full scan when elapsed time lies before the cached step (e.g. after a
+    /// loop wrap or restart).
+    #[inline]
+    pub(crate) fn evaluate_with_cursor(
+        &self,
+        elapsed: D,
+        cursor: &mut StepCursor,
+    ) -> (Srgb, Option<D>) {
+        self.as_view().evaluate_with_cursor(elapsed, cursor)
+    }
+
+    /// Returns the current position within the sequence at the given elapsed time.
+    ///
+    /// Includes step index, loop number, and timing information within the current step.
+    /// Returns `None` if the sequence is empty or function-based.
+    #[inline]
+    pub fn find_step_position(&self, elapsed: D) -> Option<StepPosition<D>> {
+        self.as_view().find_step_position(elapsed)
+    }
+
+    /// Returns true if sequence has completed.
+    #[inline]
+    pub fn has_completed(&self, elapsed: D) -> bool {
+        self.as_view().has_completed(elapsed)
+    }
+
+    /// Returns loop duration (the looping section only, excluding any intro).
+    ///
+    /// For function-based sequences this is the period set with `with_period()`, or zero.
+    #[inline]
+    pub fn loop_duration(&self) -> D {
+        self.loop_duration
+    }
+
+    /// Returns the index of the first step of the looping section.
+    #[inline]
+    pub fn loop_start(&self) -> usize {
+        self.loop_start
+    }
+
+    /// Returns the duration of the intro steps played once before the looping section.
+    #[inline]
+    pub fn intro_duration(&self) -> D {
+        self.intro_duration
+    }
+
+    /// Returns the delay before the first step begins.
+    #[inline]
+    pub fn start_delay(&self) -> D {
+        self.start_delay
+    }
+
+    /// Returns how long the final color is held before completion.
+    #[inline]
+    pub fn end_hold(&self) -> D {
+        self.end_hold
+    }
+
+    /// Returns time from start until completion, including start delay and end hold.
+    ///
+    /// Returns `None` for infinite and function-based sequences.
+    #[inline]
+    pub fn total_duration(&self) -> Option<D> {
+        self.as_view().total_duration()
+    }
+
+    /// Returns the intensity scale applied to every evaluated color.
+    #[inline]
+    pub fn brightness(&self) -> f32 {
+        self.brightness
+    }
+
+    /// Returns step count.
+    #[inline]
+    pub fn step_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns loop count.
+    #[inline]
+    pub fn loop_count(&self) -> LoopCount {
+        self.loop_count
+    }
+
+    /// Replaces the loop count of a built sequence.
+    #[inline]
+    pub(crate) fn set_loop_count(&mut self, count: LoopCount) {
+        self.loop_count = count;
+    }
+
+    /// Returns landing color.
+    #[inline]
+    pub fn landing_color(&self) -> Option<Srgb> {
+        self.landing_color
+    }
+
+    /// Returns start color.
+    #[inline]
+    pub fn start_color(&self) -> Option<Srgb> {
+        self.start_color
+    }
+
+    /// Returns step at index.
+    #[inline]
+    pub fn get_step(&self, index: usize) -> Option<&SequenceStep<D>> {
+        self.steps.get(index)
+    }
+
+    /// Returns true if function-based.
+    #[inline]
+    pub fn is_function_based(&self) -> bool {
+        self.color_fn.is_some()
+    }
+
+    /// Returns average and peak channel levels over one loop of the looping section.
+    ///
+    /// Step transitions are weighted exactly; interpolating steps are sampled at 16
+    /// points each. The sequence brightness is applied. Intro steps,
+    /// start color and landing color are not included. Returns `None` for function-based
+    /// sequences.
+    #[inline]
+    pub fn channel_levels(&self) -> Option<ChannelLevels> {
+        self.as_view().channel_levels()
+    }
+}
+
+/// A borrowed, capacity-erased view of an `RgbSequence`: its steps as a slice plus the
+/// sequence settings.
+///
+/// Obtained with `RgbSequence::as_view()` or `From<&RgbSequence>`. Evaluates exactly like
+/// the sequence it was taken from.
+#[derive(Debug, Clone, Copy)]
+pub struct RgbSequenceRef<'a, D: TimeDuration> {
+    pub(crate) steps: &'a [SequenceStep<D>],
+    pub(crate) loop_count: LoopCount,
+    pub(crate) start_color: Option<Srgb>,
+    pub(crate) landing_color: Option<Srgb>,
+    pub(crate) loop_duration: D,
+    pub(crate) loop_start: usize,
+    pub(crate) intro_duration: D,
+    pub(crate) start_delay: D,
+    pub(crate) end_hold: D,
+    pub(crate) brightness: f32,
+
+    pub(crate) color_fn: Option<fn(Srgb, D) -> Srgb>,
+    pub(crate) timing_fn: Option<fn(D) -> Option<D>>,
+}
+
+impl<'a, D: TimeDuration, const N: usize> From<&'a RgbSequence<D, N>> for RgbSequenceRef<'a, D> {
+    #[inline]
+    fn from(sequence: &'a RgbSequence<D, N>) -> Self {
+        sequence.as_view()
+    }
+}
+
+/// Copies a view into a sequence of capacity `N`, e.g. to change the capacity of a sequence.
+///
+/// Returns `SequenceError::CapacityExceeded` if the steps don't fit in `N`.
+impl<D: TimeDuration, const N: usize> TryFrom<RgbSequenceRef<'_, D>> for RgbSequence<D, N> {
+    type Error = SequenceError;
+
+    fn try_from(view: RgbSequenceRef<'_, D>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            steps: Vec::from_slice(view.steps).map_err(|_| SequenceError::CapacityExceeded)?,
+            loop_count: view.loop_count,
+            start_color: view.start_color,
+            landing_color: view.landing_color,
+            loop_duration: view.loop_duration,
+            loop_start: view.loop_start,
+            intro_duration: view.intro_duration,
+            start_delay: view.start_delay,
+            end_hold: view.end_hold,
+            brightness: view.brightness,
+            color_fn: view.color_fn,
+            timing_fn: view.timing_fn,
+        })
+    }
+}
+
+/// Sequences are equal when they would play identically: same steps, loop settings,
+/// colors and timing, regardless of capacity. Function-based sequences compare their
+/// functions by address, so the same `fn` items compare equal.
+impl<D: TimeDuration> PartialEq for RgbSequenceRef<'_, D> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_fn = |a: Option<fn(Srgb, D) -> Srgb>, b: Option<fn(Srgb, D) -> Srgb>| match (a, b)
+        {
+            (Some(a), Some(b)) => core::ptr::fn_addr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        let same_timing =
+            |a: Option<fn(D) -> Option<D>>, b: Option<fn(D) -> Option<D>>| match (a, b) {
+                (Some(a), Some(b)) => core::ptr::fn_addr_eq(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            };
+
+        self.steps == other.steps
+            && self.loop_count == other.loop_count
+            && self.start_color == other.start_color
+            && self.landing_color == other.landing_color
+            && self.loop_duration == other.loop_duration
+            && self.loop_start == other.loop_start
+            && self.start_delay == other.start_delay
+            && self.end_hold == other.end_hold
+            && self.brightness == other.brightness
+            && same_fn(self.color_fn, other.color_fn)
+            && same_timing(self.timing_fn, other.timing_fn)
+    }
+}
+
+impl<'a, D: TimeDuration> RgbSequenceRef<'a, D> {
+    /// Returns the logical period of a function-based sequence, if one was set.
+    #[inline]
+    pub fn period(&self) -> Option<D> {
+        (self.is_function_based() && self.loop_duration != D::ZERO).then_some(self.loop_duration)
+    }
+
+    /// Returns how far through its current loop the sequence is at `elapsed`, from 0.0 to 1.0.
+    ///
+    /// Step-based sequences report 0.0 during the start delay and intro. Returns `None` once
+    /// the sequence has completed, for zero-duration loops, and for function-based
+    /// sequences without a period.
+    pub fn phase_at(&self, elapsed: D) -> Option<f32> {
+        let loop_millis = self.loop_duration.as_millis();
+        if loop_millis == 0 || self.has_completed(elapsed) {
+            return None;
+        }
+
+        let into_loop = if self.is_function_based() {
+            elapsed.as_millis()
+        } else {
+            self.body_elapsed(elapsed)
+                .as_millis()
+                .saturating_sub(self.intro_duration.as_millis())
+        };
+        Some((into_loop % loop_millis) as f32 / loop_millis as f32)
+    }
+
     /// Evaluates color and next service time at elapsed time.
     ///
     /// Returns `(color, timing)` where timing is `Some(D::ZERO)` for continuous animation,
     /// `Some(delay)` for static hold, or `None` when sequence completes.
     #[inline]
     pub fn evaluate(&self, elapsed: D) -> (Srgb, Option<D>) {
+        self.evaluate_with_cursor(elapsed, &mut StepCursor::default())
+    }
+
+    /// Evaluates color and timing, using and updating a cached step cursor.
+    ///
+    /// Falls back to a full scan when elapsed time lies before the cached step (e.g. after a
+    /// loop wrap or restart).
+    #[inline]
+    pub(crate) fn evaluate_with_cursor(
+        &self,
+        elapsed: D,
+        cursor: &mut StepCursor,
+    ) -> (Srgb, Option<D>) {
+        let (color, timing) = self.evaluate_unscaled(elapsed, cursor);
+        if self.brightness == 1.0 {
+            return (color, timing);
+        }
+
+        let scaled = Srgb::new(
+            color.red * self.brightness,
+            color.green * self.brightness,
+            color.blue * self.brightness,
+        );
+        (scaled, timing)
+    }
+
+    /// Evaluates color and timing before the sequence brightness is applied.
+    #[inline]
+    fn evaluate_unscaled(&self, elapsed: D, cursor: &mut StepCursor) -> (Srgb, Option<D>) {
         // Use custom functions if present
         if let (Some(color_fn), Some(timing_fn)) = (self.color_fn, self.timing_fn) {
             let base = self.start_color.unwrap_or(BLACK);
-            return (color_fn(base, elapsed), timing_fn(elapsed));
+            let color = color_fn(base, elapsed);
+            debug_assert!(
+                !(color.red.is_nan() || color.green.is_nan() || color.blue.is_nan()),
+                "color function returned NaN"
+            );
+            return (sanitize_color(color), timing_fn(elapsed));
+        }
+
+        // Hold the start color (or off) until the start delay has passed
+        let delay_millis = self.start_delay.as_millis();
+        if elapsed.as_millis() < delay_millis {
+            let remaining = D::from_millis(delay_millis - elapsed.as_millis());
+            return (self.start_color.unwrap_or(BLACK), Some(remaining));
+        }
+
+        // Hold the final color after the last loop until the end hold has passed
+        let body_elapsed = self.body_elapsed(elapsed);
+        if self.loops_finished(body_elapsed) && !self.is_complete_step_based(body_elapsed) {
+            let remaining = D::from_millis(self.completion_millis() - body_elapsed.as_millis());
+            let final_color = self
+                .landing_color
+                .unwrap_or(self.steps.last().map_or(BLACK, |step| step.color));
+            return (final_color, Some(remaining));
         }
 
         // Step-based evaluation - calculate position once
-        if let Some(position) = self.find_step_position(elapsed) {
+        if let Some(position) = self.find_step_position_with_cursor(elapsed, cursor) {
             let color = self.color_at_position(&position);
             let timing = self.next_service_time_from_position(&position);
             (color, timing)
@@ -126,16 +578,51 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
         }
     }
 
-    /// Returns true if step-based finite sequence has completed all loops.
+    /// Returns elapsed time measured from the end of the start delay.
+    #[inline]
+    fn body_elapsed(&self, elapsed: D) -> D {
+        elapsed.saturating_sub(self.start_delay)
+    }
+
+    /// Returns time from the end of the start delay until completion, including end hold.
+    ///
+    /// Only meaningful for finite sequences.
+    #[inline]
+    fn completion_millis(&self) -> u64 {
+        let count = match self.loop_count {
+            LoopCount::Finite(count) => count as u64,
+            LoopCount::Infinite => 0,
+        };
+        self.intro_duration.as_millis()
+            + self.loop_duration.as_millis() * count
+            + self.end_hold.as_millis()
+    }
+
+    /// Returns true if step-based finite sequence has completed all loops and its end hold.
+    ///
+    /// Expects elapsed time measured from the end of the start delay.
     #[inline]
     fn is_complete_step_based(&self, elapsed: D) -> bool {
+        if self.end_hold == D::ZERO {
+            return self.loops_finished(elapsed);
+        }
+        matches!(self.loop_count, LoopCount::Finite(_))
+            && elapsed.as_millis() >= self.completion_millis()
+    }
+
+    /// Returns true if step-based finite sequence has played all loops.
+    ///
+    /// Expects elapsed time measured from the end of the start delay.
+    #[inline]
+    fn loops_finished(&self, elapsed: D) -> bool {
         match self.loop_count {
             LoopCount::Finite(count) => {
+                let intro_millis = self.intro_duration.as_millis();
                 let loop_millis = self.loop_duration.as_millis();
                 if loop_millis == 0 {
-                    elapsed.as_millis() > 0
+                    elapsed.as_millis() > intro_millis
                 } else {
-                    let total_duration = loop_millis * (count as u64);
+                    let total_duration = intro_millis + loop_millis * (count as u64);
                     elapsed.as_millis() >= total_duration
                 }
             }
@@ -176,12 +663,29 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
         }
     }
 
-    /// Finds the step position at a specific time within a loop.
+    /// Finds the step position at a specific time measured from the start of step 0.
+    ///
+    /// Times within the looping section are offset by the intro duration. Scanning starts at
+    /// the cursor when its step begins at or before `time_in_loop`, otherwise from the first
+    /// step of the section containing `time_in_loop`. The cursor is updated to the found step.
     #[inline]
-    fn find_step_at_time(&self, time_in_loop: D, current_loop: u32) -> StepPosition<D> {
-        let mut accumulated_time = D::ZERO;
+    fn find_step_at_time(
+        &self,
+        time_in_loop: D,
+        current_loop: u32,
+        cursor: &mut StepCursor,
+    ) -> StepPosition<D> {
+        let (start_index, mut accumulated_time) = if cursor.step_index < self.steps.len()
+            && cursor.step_start_millis <= time_in_loop.as_millis()
+        {
+            (cursor.step_index, D::from_millis(cursor.step_start_millis))
+        } else if time_in_loop.as_millis() >= self.intro_duration.as_millis() {
+            (self.loop_start, self.intro_duration)
+        } else {
+            (0, D::ZERO)
+        };
 
-        for (step_idx, step) in self.steps.iter().enumerate() {
+        for (step_idx, step) in self.steps.iter().enumerate().skip(start_index) {
             let step_end_time =
                 D::from_millis(accumulated_time.as_millis() + step.duration.as_millis());
 
@@ -190,6 +694,9 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
                     D::from_millis(time_in_loop.as_millis() - accumulated_time.as_millis());
                 let time_until_end = step_end_time.saturating_sub(time_in_loop);
 
+                cursor.step_index = step_idx;
+                cursor.step_start_millis = accumulated_time.as_millis();
+
                 return StepPosition {
                     step_index: step_idx,
                     time_in_step,
@@ -231,14 +738,14 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
                     | TransitionStyle::EaseOut
                     | TransitionStyle::EaseInOut
                     | TransitionStyle::EaseOutIn
+                    | TransitionStyle::CatmullRom
             );
 
+        let previous_index = self.previous_index(position.step_index, position.current_loop);
         let previous_color = if use_start_color {
             self.start_color.unwrap()
-        } else if position.step_index == 0 {
-            self.steps.last().unwrap().color
         } else {
-            self.steps[position.step_index - 1].color
+            self.steps[previous_index].color
         };
 
         let duration_millis = step.duration.as_millis();
@@ -250,37 +757,99 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
         let mut progress = (time_millis as f32) / (duration_millis as f32);
         progress = progress.clamp(0.0, 1.0);
 
+        if step.transition == TransitionStyle::CatmullRom {
+            // Outer control points: the color before the previous step, and the next step
+            let is_first_step = previous_index == 0 && position.current_loop == 0;
+            let before_previous = if use_start_color || is_first_step {
+                previous_color
+            } else {
+                let previous_loop = if previous_index > position.step_index {
+                    position.current_loop.saturating_sub(1)
+                } else {
+                    position.current_loop
+                };
+                self.steps[self.previous_index(previous_index, previous_loop)].color
+            };
+            let next = self.next_color(position.step_index, position.current_loop);
+            return catmull_rom(before_previous, previous_color, step.color, next, progress);
+        }
+
         // Apply easing function
         progress = apply_easing(progress, step.transition);
 
         previous_color.mix(step.color, progress)
     }
 
+    /// Returns the index of the step played before `step_index`, wrapping to the last step.
+    #[inline]
+    fn previous_index(&self, step_index: usize, current_loop: u32) -> usize {
+        if step_index == 0 || (step_index == self.loop_start && current_loop > 0) {
+            self.steps.len() - 1
+        } else {
+            step_index - 1
+        }
+    }
+
+    /// Returns the color played after `step_index`.
+    ///
+    /// After the last step this is the first looping step, or on the final loop of a finite
+    /// sequence the landing color (falling back to the last step's color).
+    #[inline]
+    fn next_color(&self, step_index: usize, current_loop: u32) -> Srgb {
+        if let Some(next) = self.steps.get(step_index + 1) {
+            return next.color;
+        }
+
+        let last = &self.steps[step_index];
+        match self.loop_count {
+            LoopCount::Finite(count) if current_loop + 1 >= count => {
+                self.landing_color.unwrap_or(last.color)
+            }
+            _ => self.steps[self.loop_start].color,
+        }
+    }
+
     /// Returns the current position within the sequence at the given elapsed time.
     ///
     /// Includes step index, loop number, and timing information within the current step.
     /// Returns `None` if the sequence is empty or function-based.
     pub fn find_step_position(&self, elapsed: D) -> Option<StepPosition<D>> {
+        self.find_step_position_with_cursor(elapsed, &mut StepCursor::default())
+    }
+
+    /// Returns the position at the given elapsed time, resuming the search from `cursor`.
+    pub(crate) fn find_step_position_with_cursor(
+        &self,
+        elapsed: D,
+        cursor: &mut StepCursor,
+    ) -> Option<StepPosition<D>> {
         if self.steps.is_empty() {
             return None;
         }
 
+        let elapsed = self.body_elapsed(elapsed);
+        let intro_millis = self.intro_duration.as_millis();
         let loop_millis = self.loop_duration.as_millis();
 
-        if loop_millis == 0 {
+        if loop_millis == 0 && intro_millis == 0 {
             return Some(self.handle_zero_duration_sequence(elapsed));
         }
 
-        if self.is_complete_step_based(elapsed) {
+        if self.loops_finished(elapsed) {
             return Some(self.create_complete_position());
         }
 
-        let elapsed_millis = elapsed.as_millis();
+        // The intro plays once as part of the first loop
+        if elapsed.as_millis() < intro_millis || loop_millis == 0 {
+            return Some(self.find_step_at_time(elapsed, 0, cursor));
+        }
+
+        let elapsed_millis = elapsed.as_millis() - intro_millis;
         // Use modulo for O(1) loop position calculation without tracking iteration state
         let current_loop = (elapsed_millis / loop_millis) as u32;
-        let time_in_loop = D::from_millis(elapsed_millis % loop_millis);
+        let time_in_loop = D::from_millis(intro_millis + elapsed_millis % loop_millis);
 
-        Some(self.find_step_at_time(time_in_loop, current_loop))
+        Some(self.find_step_at_time(time_in_loop, current_loop, cursor))
     }
 
     /// Returns the color at the given position.
@@ -300,7 +869,8 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
             | TransitionStyle::EaseIn
             | TransitionStyle::EaseOut
             | TransitionStyle::EaseInOut
-            | TransitionStyle::EaseOutIn => self.interpolate_color(position, step),
+            | TransitionStyle::EaseOutIn
+            | TransitionStyle::CatmullRom => self.interpolate_color(position, step),
         }
     }
 
@@ -318,7 +888,8 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
             | TransitionStyle::EaseIn
             | TransitionStyle::EaseOut
             | TransitionStyle::EaseInOut
-            | TransitionStyle::EaseOutIn => Some(D::ZERO),
+            | TransitionStyle::EaseOutIn
+            | TransitionStyle::CatmullRom => Some(D::ZERO),
             // Step transition can wait until the end
             TransitionStyle::Step => Some(position.time_until_step_end),
         }
@@ -330,16 +901,66 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
         if let Some(timing_fn) = self.timing_fn {
             timing_fn(elapsed).is_none()
         } else {
-            self.is_complete_step_based(elapsed)
+            self.is_complete_step_based(self.body_elapsed(elapsed))
         }
     }
 
-    /// Returns loop duration.
+    /// Returns loop duration (the looping section only, excluding any intro).
+    ///
+    /// For function-based sequences this is the period set with `with_period()`, or zero.
     #[inline]
     pub fn loop_duration(&self) -> D {
         self.loop_duration
     }
 
+    /// Returns the index of the first step of the looping section.
+    #[inline]
+    pub fn loop_start(&self) -> usize {
+        self.loop_start
+    }
+
+    /// Returns the duration of the intro steps played once before the looping section.
+    #[inline]
+    pub fn intro_duration(&self) -> D {
+        self.intro_duration
+    }
+
+    /// Returns the delay before the first step begins.
+    #[inline]
+    pub fn start_delay(&self) -> D {
+        self.start_delay
+    }
+
+    /// Returns how long the final color is held before completion.
+    #[inline]
+    pub fn end_hold(&self) -> D {
+        self.end_hold
+    }
+
+    /// Returns time from start until completion, including start delay and end hold.
+    ///
+    /// Returns `None` for infinite and function-based sequences.
+    pub fn total_duration(&self) -> Option<D> {
+        if self.is_function_based() || self.loop_count == LoopCount::Infinite {
+            return None;
+        }
+        Some(D::from_millis(
+            self.start_delay.as_millis() + self.completion_millis(),
+        ))
+    }
+
+    /// Returns the intensity scale applied to every evaluated color.
+    #[inline]
+    pub fn brightness(&self) -> f32 {
+        self.brightness
+    }
+
+    /// Returns the steps.
+    #[inline]
+    pub fn steps(&self) -> &'a [SequenceStep<D>] {
+        self.steps
+    }
+
     /// Returns step count.
     #[inline]
     pub fn step_count(&self) -> usize {
@@ -366,7 +987,7 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
 
     /// Returns step at index.
     #[inline]
-    pub fn get_step(&self, index: usize) -> Option<&SequenceStep<D>> {
+    pub fn get_step(&self, index: usize) -> Option<&'a SequenceStep<D>> {
         self.steps.get(index)
     }
 
@@ -375,6 +996,150 @@ impl<D: TimeDuration, const N: usize> RgbSequence<D, N> {
     pub fn is_function_based(&self) -> bool {
         self.color_fn.is_some()
     }
+
+    /// Returns average and peak channel levels over one loop of the looping section.
+    ///
+    /// Step transitions are weighted exactly; interpolating steps are sampled at 16
+    /// points each. The sequence brightness is applied. Intro steps,
+    /// start color and landing color are not included. Returns `None` for function-based
+    /// sequences.
+    pub fn channel_levels(&self) -> Option<ChannelLevels> {
+        if self.is_function_based() {
+            return None;
+        }
+
+        let scale = |color: Srgb| {
+            Srgb::new(
+                color.red * self.brightness,
+                color.green * self.brightness,
+                color.blue * self.brightness,
+            )
+        };
+
+        // A zero-duration loop just shows its last color
+        let loop_millis = self.loop_duration.as_millis();
+        if loop_millis == 0 {
+            let color = scale(self.steps.last()?.color);
+            return Some(ChannelLevels {
+                average: color,
+                peak: color,
+            });
+        }
+
+        let mut sum = BLACK;
+        let mut peak = BLACK;
+        let mut track = |color: Srgb, weight: f32| {
+            sum.red += color.red * weight;
+            sum.green += color.green * weight;
+            sum.blue += color.blue * weight;
+            peak.red = peak.red.max(color.red);
+            peak.green = peak.green.max(color.green);
+            peak.blue = peak.blue.max(color.blue);
+        };
+
+        for (step_index, step) in self.steps.iter().enumerate().skip(self.loop_start) {
+            let step_millis = step.duration.as_millis();
+            if step_millis == 0 {
+                continue;
+            }
+            if step.transition == TransitionStyle::Step {
+                track(step.color, step_millis as f32);
+                continue;
+            }
+
+            // Midpoint samples, evaluated as a later loop so the step wraps from the last color
+            let weight = step_millis as f32 / LEVEL_SAMPLES_PER_STEP as f32;
+            for sample in 0..LEVEL_SAMPLES_PER_STEP {
+                let time_millis = step_millis * (2 * sample + 1) / (2 * LEVEL_SAMPLES_PER_STEP);
+                let position = StepPosition {
+                    step_index,
+                    time_in_step: D::from_millis(time_millis),
+                    time_until_step_end: D::from_millis(step_millis - time_millis),
+                    is_complete: false,
+                    current_loop: 1,
+                };
+                track(self.color_at_position(&position), weight);
+            }
+            track(step.color, 0.0);
+        }
+
+        let loop_millis = loop_millis as f32;
+        Some(ChannelLevels {
+            average: scale(Srgb::new(
+                sum.red / loop_millis,
+                sum.green / loop_millis,
+                sum.blue / loop_millis,
+            )),
+            peak: scale(peak),
+        })
+    }
+}
+
+/// Validates the steps and settings of a step-based sequence.
+///
+/// Returns the intro and loop durations, or the first rule violated (see
+/// `SequenceBuilder::build()`).
+pub(crate) fn validate_steps<D: TimeDuration>(
+    steps: &[SequenceStep<D>],
+    loop_start: usize,
+    loop_count: LoopCount,
+    start_color: Option<Srgb>,
+    landing_color: Option<Srgb>,
+    end_hold: D,
+) -> Result<(D, D), SequenceError> {
+    if steps.is_empty() {
+        return Err(SequenceError::EmptySequence);
+    }
+
+    for step in steps {
+        if step.duration.as_millis() == 0
+            && matches!(
+                step.transition,
+                TransitionStyle::Linear
+                    | TransitionStyle::EaseIn
+                    | TransitionStyle::EaseOut
+                    | TransitionStyle::EaseInOut
+                    | TransitionStyle::EaseOutIn
+                    | TransitionStyle::CatmullRom
+            )
+        {
+            return Err(SequenceError::ZeroDurationWithInterpolation);
+        }
+    }
+
+    // Validate start_color is only set when first sequence step has TransitionStyle != Step
+    if start_color.is_some()
+        && let Some(first_step) = steps.first()
+        && matches!(first_step.transition, TransitionStyle::Step)
+    {
+        return Err(SequenceError::StartColorWithStepTransition);
+    }
+
+    // Validate landing_color is only set with finite loop count
+    if landing_color.is_some() && matches!(loop_count, LoopCount::Infinite) {
+        return Err(SequenceError::LandingColorWithInfiniteLoop);
+    }
+
+    // Validate end_hold is only set with finite loop count
+    if end_hold != D::ZERO && matches!(loop_count, LoopCount::Infinite) {
+        return Err(SequenceError::EndHoldWithInfiniteLoop);
+    }
+
+    // Validate the looping section contains at least one step
+    if loop_start >= steps.len() {
+        return Err(SequenceError::LoopStartOutOfRange);
+    }
+
+    // Section durations, cached by the sequence to avoid repeated calculation during operation
+    let (intro, body) = steps.split_at(loop_start);
+    let intro_millis: u64 = intro.iter().map(|s| s.duration.as_millis()).sum();
+    let loop_millis: u64 = body.iter().map(|s| s.duration.as_millis()).sum();
+
+    // An endless zero-duration loop after the intro would need servicing continuously
+    if loop_start > 0 && loop_millis == 0 && matches!(loop_count, LoopCount::Infinite) {
+        return Err(SequenceError::ZeroDurationLoop);
+    }
+    Ok((D::from_millis(intro_millis), D::from_millis(loop_millis)))
 }
 
 /// Builder for RGB sequences.
@@ -384,6 +1149,11 @@ pub struct SequenceBuilder<D: TimeDuration, const N: usize> {
     loop_count: LoopCount,
     landing_color: Option<Srgb>,
     start_color: Option<Srgb>,
+    start_delay: D,
+    end_hold: D,
+    brightness: f32,
+    default_transition: TransitionStyle,
+    loop_start: usize,
 }
 
 impl<D: TimeDuration, const N: usize> SequenceBuilder<D, N> {
@@ -394,12 +1164,18 @@ impl<D: TimeDuration, const N: usize> SequenceBuilder<D, N> {
             loop_count: LoopCount::default(),
             landing_color: None,
             start_color: None,
+            start_delay: D::ZERO,
+            end_hold: D::ZERO,
+            brightness: 1.0,
+            default_transition: TransitionStyle::Step,
+            loop_start: 0,
         }
     }
 
     /// Adds a step to the sequence.
     ///
-    /// Panics if capacity `N` is exceeded.
+    /// Panics if capacity `N` is exceeded. NaN color channels become 0.0 and out-of-range
+    /// channels are clamped to 0.0-1.0.
     pub fn step(
         mut self,
         color: Srgb,
@@ -407,26 +1183,111 @@ impl<D: TimeDuration, const N: usize> SequenceBuilder<D, N> {
         transition: TransitionStyle,
     ) -> Result<Self, SequenceError> {
         self.steps
-            .push(SequenceStep::new(color, duration, transition))
+            .push(SequenceStep::new(
+                sanitize_color(color),
+                duration,
+                transition,
+            ))
             .map_err(|_| SequenceError::CapacityExceeded)?;
         Ok(self)
     }
 
+    /// Adds one step per color, all sharing `duration` and `transition`.
+    ///
+    /// Returns `SequenceError::CapacityExceeded` if the colors don't fit in `N`.
+    pub fn steps_from<C: IntoIterator<Item = Srgb>>(
+        self,
+        colors: C,
+        duration: D,
+        transition: TransitionStyle,
+    ) -> Result<Self, SequenceError> {
+        colors.into_iter().try_fold(self, |builder, color| {
+            builder.step(color, duration, transition)
+        })
+    }
+
+    /// Adds a step using the builder's default transition style.
+    ///
+    /// Zero-duration steps are still rejected at build if the default interpolates.
+    pub fn step_color(self, color: Srgb, duration: D) -> Result<Self, SequenceError> {
+        let transition = self.default_transition;
+        self.step(color, duration, transition)
+    }
+
+    /// Adds a step with the same transition as the previous step.
+    ///
+    /// The first step uses the builder's default transition (see `default_transition()`).
+    pub fn then(self, color: Srgb, duration: D) -> Result<Self, SequenceError> {
+        let transition = self
+            .steps
+            .last()
+            .map_or(self.default_transition, |step| step.transition);
+        self.step(color, duration, transition)
+    }
+
+    /// Adds a `Step` that keeps the previous step's color for `duration`.
+    ///
+    /// On an empty builder the held color is black (LED off).
+    pub fn hold(self, duration: D) -> Result<Self, SequenceError> {
+        let color = self.steps.last().map_or(BLACK, |step| step.color);
+        self.step(color, duration, TransitionStyle::Step)
+    }
+
+    /// Sets the transition style used by subsequent `step_color()` calls (default: `Step`).
+    pub fn default_transition(mut self, transition: TransitionStyle) -> Self {
+        self.default_transition = transition;
+        self
+    }
+
     /// Sets loop count (default: `Finite(1)`).
     pub fn loop_count(mut self, count: LoopCount) -> Self {
         self.loop_count = count;
         self
     }
 
+    /// Marks steps before `index` as an intro played once; the loop count repeats the rest.
+    ///
+    /// Default is `0` (all steps loop). Must be less than the step count.
+    pub fn loop_from(mut self, index: usize) -> Self {
+        self.loop_start = index;
+        self
+    }
+
     /// Sets landing color shown after sequence completes (finite sequences only).
     pub fn landing_color(mut self, color: Srgb) -> Self {
-        self.landing_color = Some(color);
+        self.landing_color = Some(sanitize_color(color));
         self
     }
 
     /// Sets start color for smooth entry into first step (first loop only, Linear transitions only).
     pub fn start_color(mut self, color: Srgb) -> Self {
-        self.start_color = Some(color);
+        self.start_color = Some(sanitize_color(color));
+        self
+    }
+
+    /// Sets a delay before the first step, shown once at the start (not repeated per loop).
+    ///
+    /// The start color is held during the delay, or the LED is off if none is set.
+    pub fn start_delay(mut self, delay: D) -> Self {
+        self.start_delay = delay;
+        self
+    }
+
+    /// Sets how long the final (landing or last step) color is held before the sequence
+    /// reports completion (finite sequences only).
+    ///
+    /// The hold happens once after the last loop and is not part of the looped body.
+    pub fn end_hold(mut self, duration: D) -> Self {
+        self.end_hold = duration;
+        self
+    }
+
+    /// Sets an intensity scale (0.0-1.0, clamped) applied to every color of this sequence.
+    ///
+    /// Independent of the sequencer's brightness; both multiply. Lets preset libraries ship
+    /// subtle variants without duplicating colors.
+    pub fn brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness.clamp(0.0, 1.0);
         self
     }
 
@@ -437,48 +1298,29 @@ impl<D: TimeDuration, const N: usize> SequenceBuilder<D, N> {
     /// - Has zero-duration steps with TransitionStyle != Step
     /// - Has start_color and first step is Step transition
     /// - Has landing_color with infinite loop
+    /// - Has end_hold with infinite loop
+    /// - Has `loop_from()` leaving no steps to loop
+    /// - Has an infinite zero-duration loop after an intro
     pub fn build(self) -> Result<RgbSequence<D, N>, SequenceError> {
-        if self.steps.is_empty() {
-            return Err(SequenceError::EmptySequence);
-        }
-
-        for step in &self.steps {
-            if step.duration.as_millis() == 0
-                && matches!(
-                    step.transition,
-                    TransitionStyle::Linear
-                        | TransitionStyle::EaseIn
-                        | TransitionStyle::EaseOut
-                        | TransitionStyle::EaseInOut
-                        | TransitionStyle::EaseOutIn
-                )
-            {
-                return Err(SequenceError::ZeroDurationWithInterpolation);
-            }
-        }
-
-        // Validate start_color is only set when first sequence step has TransitionStyle != Step
-        if self.start_color.is_some()
-            && let Some(first_step) = self.steps.first()
-            && matches!(first_step.transition, TransitionStyle::Step)
-        {
-            return Err(SequenceError::StartColorWithStepTransition);
-        }
-
-        // Validate landing_color is only set with finite loop count
-        if self.landing_color.is_some() && matches!(self.loop_count, LoopCount::Infinite) {
-            return Err(SequenceError::LandingColorWithInfiniteLoop);
-        }
-
-        // Calculate and cache loop duration here to avoid repeated calculation during operation
-        let total_millis: u64 = self.steps.iter().map(|s| s.duration.as_millis()).sum();
-        let loop_duration = D::from_millis(total_millis);
+        let (intro_duration, loop_duration) = validate_steps(
+            &self.steps,
+            self.loop_start,
+            self.loop_count,
+            self.start_color,
+            self.landing_color,
+            self.end_hold,
+        )?;
 
         Ok(RgbSequence {
             steps: self.steps,
             loop_count: self.loop_count,
             landing_color: self.landing_color,
             loop_duration,
+            loop_start: self.loop_start,
+            intro_duration,
+            start_delay: self.start_delay,
+            end_hold: self.end_hold,
+            brightness: self.brightness,
             start_color: self.start_color,
             color_fn: None,
             timing_fn: None,
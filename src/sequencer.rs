@@ -2,9 +2,11 @@
 
 use crate::BLACK;
 use crate::command::SequencerAction;
-use crate::sequence::RgbSequence;
+use crate::sequence::{RgbSequence, StepCursor, StepPosition, sanitize_color};
 use crate::time::{TimeDuration, TimeInstant, TimeSource};
-use palette::Srgb;
+use crate::types::{LoopCount, SequenceStep, TransitionStyle};
+use core::ops::Deref;
+use palette::{Mix, Srgb};
 
 /// Trait for abstracting RGB LED hardware.
 pub trait RgbLed {
@@ -15,6 +17,17 @@ pub trait RgbLed {
     fn set_color(&mut self, color: Srgb);
 }
 
+/// Forwards to the referenced LED.
+///
+/// Allows `&mut dyn RgbLed` as the LED type, so LEDs on different hardware can share one
+/// `SequencerCollection` without an enum wrapper (at the cost of dynamic dispatch).
+impl<L: RgbLed + ?Sized> RgbLed for &mut L {
+    #[inline]
+    fn set_color(&mut self, color: Srgb) {
+        (**self).set_color(color);
+    }
+}
+
 /// RGB sequencer state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -27,10 +40,88 @@ pub enum SequencerState {
     Running,
     /// Sequence paused.
     Paused,
+    /// LED frozen at its current color while the sequence timeline keeps running.
+    Held,
     /// Sequence complete.
     Complete,
 }
 
+/// What the sequencer does when a finite sequence completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OnComplete {
+    /// Keep showing the landing color or last step color (`Complete` state).
+    HoldColor,
+    /// Turn the LED off (`Complete` state).
+    TurnOff,
+    /// Turn the LED off and return to `Loaded`, ready for `start()`.
+    ReturnToLoaded,
+}
+
+impl Default for OnComplete {
+    /// Returns the default completion behavior (hold color).
+    fn default() -> Self {
+        OnComplete::HoldColor
+    }
+}
+
+/// What loading a sequence does while the sequencer is `Running`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LoadPolicy {
+    /// Replace the sequence and stop in `Loaded`; the LED keeps its last color until the
+    /// next `start()`.
+    Replace,
+    /// Replace the sequence and start it right away (`Running`).
+    StartImmediately,
+    /// Keep the playing sequence and return `SequencerError::InvalidState`.
+    Reject,
+}
+
+impl Default for LoadPolicy {
+    /// Returns the default load policy (replace).
+    fn default() -> Self {
+        LoadPolicy::Replace
+    }
+}
+
+/// How the brightness level maps to the multiplier applied to colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BrightnessCurve {
+    /// Multiply colors by the brightness level directly (cheapest).
+    Linear,
+    /// Treat the brightness level as perceived lightness (CIE 1931), so `0.5` looks like
+    /// half brightness to the eye.
+    Cie1931,
+}
+
+impl Default for BrightnessCurve {
+    /// Returns the default brightness curve (linear).
+    fn default() -> Self {
+        BrightnessCurve::Linear
+    }
+}
+
+impl BrightnessCurve {
+    /// Maps a brightness level (0.0-1.0) to a color multiplier.
+    #[inline]
+    pub fn apply(self, level: f32) -> f32 {
+        match self {
+            BrightnessCurve::Linear => level,
+            BrightnessCurve::Cie1931 => {
+                let lightness = level * 100.0;
+                if lightness <= 8.0 {
+                    lightness / 903.3
+                } else {
+                    let scaled = (lightness + 16.0) / 116.0;
+                    scaled * scaled * scaled
+                }
+            }
+        }
+    }
+}
+
 /// Timing information returned by service operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -43,6 +134,22 @@ pub enum ServiceTiming<D> {
     Complete,
 }
 
+/// Timing information with the absolute instant of the next required service.
+///
+/// Returned by `service_deadline()`. Suits timers that wake at an instant (e.g.
+/// `Timer::at()`), so the wait does not drift by the time spent between servicing and
+/// going to sleep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ServiceDeadline<I> {
+    /// Continuous animation - service again at your target frame rate.
+    Continuous,
+    /// Static hold - service again at this instant.
+    At(I),
+    /// Sequence complete - no further servicing needed.
+    Complete,
+}
+
 /// Current playback position within a sequence.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -66,6 +173,10 @@ pub enum SequencerError {
     },
     /// No sequence loaded.
     NoSequenceLoaded,
+    /// Operation requires a step-based sequence.
+    FunctionBasedSequence,
+    /// Sequence has more steps than the sequencer's capacity.
+    CapacityExceeded,
 }
 
 impl core::fmt::Display for SequencerError {
@@ -82,6 +193,248 @@ impl core::fmt::Display for SequencerError {
             SequencerError::NoSequenceLoaded => {
                 write!(f, "no sequence loaded")
             }
+            SequencerError::FunctionBasedSequence => {
+                write!(f, "operation requires a step-based sequence")
+            }
+            SequencerError::CapacityExceeded => {
+                write!(f, "sequence has more steps than the sequencer capacity")
+            }
+        }
+    }
+}
+
+/// Size in bytes of a serialized `SequencerSnapshot`.
+pub const SNAPSHOT_SIZE: usize = 84;
+
+/// Serialization format version written as the first snapshot byte.
+const SNAPSHOT_VERSION: u8 = 1;
+
+/// Errors that can occur when decoding a serialized snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SnapshotError {
+    /// Snapshot was written by an incompatible format version.
+    UnsupportedVersion(u8),
+    /// Snapshot bytes are corrupt.
+    InvalidData,
+    /// Snapshot state requires a sequence but none was provided.
+    MissingSequence,
+}
+
+impl core::fmt::Display for SnapshotError {
+    /// Formats the error for display.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+            SnapshotError::InvalidData => write!(f, "invalid snapshot data"),
+            SnapshotError::MissingSequence => write!(f, "snapshot requires a sequence"),
+        }
+    }
+}
+
+/// Saved sequencer state for tearing down and resuming playback.
+///
+/// Holds the sequence, state, playback progress and settings, but not the LED or time source.
+/// Progress is stored as time elapsed rather than as a `TimeInstant`, so a snapshot stays
+/// valid after the time source restarts (e.g. on wake from deep sleep).
+#[derive(Debug, Clone)]
+pub struct SequencerSnapshot<D: TimeDuration, const N: usize> {
+    sequence: Option<RgbSequence<D, N>>,
+    state: SequencerState,
+    elapsed: Option<D>,
+    off_period_elapsed: Option<D>,
+    current_color: Srgb,
+    color_epsilon: f32,
+    brightness: f32,
+    phase_offset: D,
+    color_override: Option<Srgb>,
+    output_enabled: bool,
+    fade_in: D,
+    on_complete: OnComplete,
+    auto_restart: Option<D>,
+    max_catch_up: Option<D>,
+}
+
+impl<D: TimeDuration, const N: usize> SequencerSnapshot<D, N> {
+    /// Returns the saved state.
+    #[inline]
+    pub fn state(&self) -> SequencerState {
+        self.state
+    }
+
+    /// Returns the saved playback time, or `None` if playback had not started.
+    #[inline]
+    pub fn elapsed(&self) -> Option<D> {
+        self.elapsed
+    }
+
+    /// Returns the saved sequence.
+    #[inline]
+    pub fn sequence(&self) -> Option<&RgbSequence<D, N>> {
+        self.sequence.as_ref()
+    }
+
+    /// Returns the saved brightness.
+    #[inline]
+    pub fn brightness(&self) -> f32 {
+        self.brightness
+    }
+
+    /// Serializes everything except the sequence into a fixed-size byte array.
+    ///
+    /// Suitable for backup RAM or EEPROM. Durations are stored as milliseconds and colors as
+    /// `f32`, all little-endian. The sequence itself is not stored; pass it back in
+    /// `from_bytes()`.
+    pub fn to_bytes(&self) -> [u8; SNAPSHOT_SIZE] {
+        let mut bytes = [0u8; SNAPSHOT_SIZE];
+        let flags = (self.elapsed.is_some() as u8)
+            | (self.off_period_elapsed.is_some() as u8) << 1
+            | (self.color_override.is_some() as u8) << 2
+            | (self.output_enabled as u8) << 3
+            | (self.auto_restart.is_some() as u8) << 4
+            | (self.max_catch_up.is_some() as u8) << 5;
+
+        bytes[0] = SNAPSHOT_VERSION;
+        bytes[1] = match self.state {
+            SequencerState::Idle => 0,
+            SequencerState::Loaded => 1,
+            SequencerState::Running => 2,
+            SequencerState::Paused => 3,
+            SequencerState::Held => 4,
+            SequencerState::Complete => 5,
+        };
+        bytes[2] = flags;
+        bytes[3] = match self.on_complete {
+            OnComplete::HoldColor => 0,
+            OnComplete::TurnOff => 1,
+            OnComplete::ReturnToLoaded => 2,
+        };
+
+        let millis = |duration: Option<D>| duration.map_or(0, |d| d.as_millis()).to_le_bytes();
+        let color = |c: Srgb| {
+            let mut out = [0u8; 12];
+            out[0..4].copy_from_slice(&c.red.to_le_bytes());
+            out[4..8].copy_from_slice(&c.green.to_le_bytes());
+            out[8..12].copy_from_slice(&c.blue.to_le_bytes());
+            out
+        };
+
+        bytes[4..12].copy_from_slice(&millis(self.elapsed));
+        bytes[12..20].copy_from_slice(&millis(self.off_period_elapsed));
+        bytes[20..32].copy_from_slice(&color(self.current_color));
+        bytes[32..36].copy_from_slice(&self.color_epsilon.to_le_bytes());
+        bytes[36..40].copy_from_slice(&self.brightness.to_le_bytes());
+        bytes[40..48].copy_from_slice(&millis(Some(self.phase_offset)));
+        bytes[48..60].copy_from_slice(&color(self.color_override.unwrap_or(BLACK)));
+        bytes[60..68].copy_from_slice(&millis(Some(self.fade_in)));
+        bytes[68..76].copy_from_slice(&millis(self.auto_restart));
+        bytes[76..84].copy_from_slice(&millis(self.max_catch_up));
+        bytes
+    }
+
+    /// Decodes a snapshot written by `to_bytes()`, re-attaching its sequence.
+    ///
+    /// Returns `SnapshotError::InvalidData` if the bytes are inconsistent (e.g. a playing
+    /// state without a position), and `SnapshotError::MissingSequence` if the saved state
+    /// needs a sequence (anything but `Idle`) and `sequence` is `None`.
+    pub fn from_bytes(
+        bytes: &[u8; SNAPSHOT_SIZE],
+        sequence: Option<RgbSequence<D, N>>,
+    ) -> Result<Self, SnapshotError> {
+        if bytes[0] != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(bytes[0]));
+        }
+
+        let state = match bytes[1] {
+            0 => SequencerState::Idle,
+            1 => SequencerState::Loaded,
+            2 => SequencerState::Running,
+            3 => SequencerState::Paused,
+            4 => SequencerState::Held,
+            5 => SequencerState::Complete,
+            _ => return Err(SnapshotError::InvalidData),
+        };
+        let on_complete = match bytes[3] {
+            0 => OnComplete::HoldColor,
+            1 => OnComplete::TurnOff,
+            2 => OnComplete::ReturnToLoaded,
+            _ => return Err(SnapshotError::InvalidData),
+        };
+
+        let flags = bytes[2];
+        let flag = |bit: u8| flags & (1 << bit) != 0;
+
+        // Playback states need a position, and an off-period needs auto-restart
+        let started = !matches!(state, SequencerState::Idle | SequencerState::Loaded);
+        if (started && !flag(0)) || (flag(1) && !flag(4)) {
+            return Err(SnapshotError::InvalidData);
+        }
+
+        if state != SequencerState::Idle && sequence.is_none() {
+            return Err(SnapshotError::MissingSequence);
+        }
+
+        let f32_at = |at: usize| f32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+        let millis_at =
+            |at: usize| D::from_millis(u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap()));
+        let color_at = |at: usize| Srgb::new(f32_at(at), f32_at(at + 4), f32_at(at + 8));
+
+        Ok(Self {
+            sequence,
+            state,
+            elapsed: flag(0).then(|| millis_at(4)),
+            off_period_elapsed: flag(1).then(|| millis_at(12)),
+            current_color: color_at(20),
+            color_epsilon: f32_at(32),
+            brightness: f32_at(36),
+            phase_offset: millis_at(40),
+            color_override: flag(2).then(|| color_at(48)),
+            output_enabled: flag(3),
+            fade_in: millis_at(60),
+            on_complete,
+            auto_restart: flag(4).then(|| millis_at(68)),
+            max_catch_up: flag(5).then(|| millis_at(76)),
+        })
+    }
+}
+
+/// Sequence held by a sequencer: its own copy, or a borrow of one shared with others.
+#[derive(Debug, Clone)]
+enum SequenceSlot<'t, D: TimeDuration, const N: usize> {
+    Owned(RgbSequence<D, N>),
+    Borrowed(&'t RgbSequence<D, N>),
+}
+
+impl<D: TimeDuration, const N: usize> SequenceSlot<'_, D, N> {
+    /// Returns the sequence mutably, copying a borrowed sequence first.
+    fn to_mut(&mut self) -> &mut RgbSequence<D, N> {
+        if let SequenceSlot::Borrowed(sequence) = *self {
+            *self = SequenceSlot::Owned(sequence.clone());
+        }
+        match self {
+            SequenceSlot::Owned(sequence) => sequence,
+            SequenceSlot::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the sequence by value, copying a borrowed sequence.
+    fn into_owned(self) -> RgbSequence<D, N> {
+        match self {
+            SequenceSlot::Owned(sequence) => sequence,
+            SequenceSlot::Borrowed(sequence) => sequence.clone(),
+        }
+    }
+}
+
+impl<D: TimeDuration, const N: usize> Deref for SequenceSlot<'_, D, N> {
+    type Target = RgbSequence<D, N>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            SequenceSlot::Owned(sequence) => sequence,
+            SequenceSlot::Borrowed(sequence) => sequence,
         }
     }
 }
@@ -91,17 +444,129 @@ pub struct RgbSequencer<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N
     led: L,
     time_source: &'t T,
     state: SequencerState,
-    sequence: Option<RgbSequence<I::Duration, N>>,
+    sequence: Option<SequenceSlot<'t, I::Duration, N>>,
+    chain: &'t [RgbSequence<I::Duration, N>],
+    chain_index: usize,
+    chain_loop: u32,
+    chain_loops: LoopCount,
     start_time: Option<I>,
     pause_start_time: Option<I>,
+    step_cursor: StepCursor,
     current_color: Srgb,
     color_epsilon: f32,
     brightness: f32,
+    speed: f32,
+    phase_offset: I::Duration,
+    seek_offset: I::Duration,
+    color_override: Option<Srgb>,
+    override_fade: Option<(Srgb, I, I::Duration)>,
+    output_enabled: bool,
+    fade_in: I::Duration,
+    fade_start: Option<I>,
+    linear_output: bool,
+    brightness_curve: BrightnessCurve,
+    white_balance: (f32, f32, f32),
+    max_channel_sum: Option<f32>,
+    output_filter: Option<fn(Srgb) -> Srgb>,
+    max_brightness: f32,
+    on_complete: OnComplete,
+    load_policy: LoadPolicy,
+    auto_restart: Option<I::Duration>,
+    off_period_start: Option<I>,
+    max_catch_up: Option<I::Duration>,
+    frame_interval: Option<I::Duration>,
+    phase_lock_gain: f32,
+    output_resolution: Option<u8>,
+    last_service: Option<(I, I::Duration)>,
 }
 
 /// Default epsilon for floating-point color comparisons.
 pub const DEFAULT_COLOR_EPSILON: f32 = 0.001;
 
+/// Slowest playback speed accepted by `set_speed()`.
+pub const MIN_SPEED: f32 = 0.01;
+
+/// Fastest playback speed accepted by `set_speed()`.
+pub const MAX_SPEED: f32 = 100.0;
+
+/// Default fraction of the phase error corrected by each `sync_to_phase()` call.
+pub const DEFAULT_PHASE_LOCK_GAIN: f32 = 0.125;
+
+/// Tuning settings applied together when a sequencer is created.
+///
+/// Build one with the chained setters and pass it to `RgbSequencer::with_config()`. The
+/// config is `Copy`, so the same value can configure every LED of a product. Unset values
+/// keep the `RgbSequencer::new()` defaults.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SequencerConfig<D: TimeDuration> {
+    color_epsilon: f32,
+    brightness: f32,
+    linear_output: bool,
+    on_complete: OnComplete,
+    frame_interval: Option<D>,
+    max_catch_up: Option<D>,
+}
+
+impl<D: TimeDuration> SequencerConfig<D> {
+    /// Creates a config with the default settings.
+    pub fn new() -> Self {
+        Self {
+            color_epsilon: DEFAULT_COLOR_EPSILON,
+            brightness: 1.0,
+            linear_output: false,
+            on_complete: OnComplete::HoldColor,
+            frame_interval: None,
+            max_catch_up: None,
+        }
+    }
+
+    /// Sets the color change detection threshold (default: `DEFAULT_COLOR_EPSILON`).
+    pub fn color_epsilon(mut self, epsilon: f32) -> Self {
+        self.color_epsilon = epsilon;
+        self
+    }
+
+    /// Sets the brightness multiplier (0.0-1.0, clamped, default: 1.0).
+    pub fn brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Enables gamma decoding to linear light before output (default: `false`, see
+    /// `RgbSequencer::set_linear_output()`).
+    pub fn linear_output(mut self, enabled: bool) -> Self {
+        self.linear_output = enabled;
+        self
+    }
+
+    /// Sets the completion behavior (default: `HoldColor`).
+    pub fn on_complete(mut self, behavior: OnComplete) -> Self {
+        self.on_complete = behavior;
+        self
+    }
+
+    /// Sets the frame interval returned in place of `Continuous` (default: none, see
+    /// `RgbSequencer::set_frame_interval()`).
+    pub fn frame_interval(mut self, interval: D) -> Self {
+        self.frame_interval = Some(interval);
+        self
+    }
+
+    /// Sets the maximum catch-up per service call (default: none, see
+    /// `RgbSequencer::set_max_catch_up()`).
+    pub fn max_catch_up(mut self, max_catch_up: D) -> Self {
+        self.max_catch_up = Some(max_catch_up);
+        self
+    }
+}
+
+impl<D: TimeDuration> Default for SequencerConfig<D> {
+    /// Returns a config with the default settings.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Returns true if two colors are approximately equal within the given epsilon.
 #[inline]
 fn colors_approximately_equal(a: Srgb, b: Srgb, epsilon: f32) -> bool {
@@ -110,6 +575,14 @@ fn colors_approximately_equal(a: Srgb, b: Srgb, epsilon: f32) -> bool {
         && (a.blue - b.blue).abs() < epsilon
 }
 
+/// Quantizes each channel to `bits` of resolution, rounding to nearest like the PWM adapters.
+#[inline]
+fn quantize(color: Srgb, bits: u8) -> [u32; 3] {
+    let max = ((1u32 << bits) - 1) as f32;
+    let level = |value: f32| (value.clamp(0.0, 1.0) * max + 0.5) as u32;
+    [level(color.red), level(color.green), level(color.blue)]
+}
+
 impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequencer<'t, I, L, T, N> {
     /// Creates sequencer with LED off and default color epsilon.
     pub fn new(mut led: L, time_source: &'t T) -> Self {
@@ -120,29 +593,59 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
             time_source,
             state: SequencerState::Idle,
             sequence: None,
+            chain: &[],
+            chain_index: 0,
+            chain_loop: 0,
+            chain_loops: LoopCount::Finite(1),
             start_time: None,
             pause_start_time: None,
+            step_cursor: StepCursor::default(),
             current_color: BLACK,
             color_epsilon: DEFAULT_COLOR_EPSILON,
             brightness: 1.0,
+            speed: 1.0,
+            phase_offset: I::Duration::ZERO,
+            seek_offset: I::Duration::ZERO,
+            color_override: None,
+            override_fade: None,
+            output_enabled: true,
+            fade_in: I::Duration::ZERO,
+            fade_start: None,
+            linear_output: false,
+            brightness_curve: BrightnessCurve::Linear,
+            white_balance: (1.0, 1.0, 1.0),
+            max_channel_sum: None,
+            output_filter: None,
+            max_brightness: 1.0,
+            on_complete: OnComplete::HoldColor,
+            load_policy: LoadPolicy::Replace,
+            auto_restart: None,
+            off_period_start: None,
+            max_catch_up: None,
+            frame_interval: None,
+            phase_lock_gain: DEFAULT_PHASE_LOCK_GAIN,
+            output_resolution: None,
+            last_service: None,
         }
     }
 
     /// Creates sequencer with custom color epsilon threshold.
-    pub fn with_epsilon(mut led: L, time_source: &'t T, epsilon: f32) -> Self {
-        led.set_color(BLACK);
+    pub fn with_epsilon(led: L, time_source: &'t T, epsilon: f32) -> Self {
+        let mut sequencer = Self::new(led, time_source);
+        sequencer.color_epsilon = epsilon;
+        sequencer
+    }
 
-        Self {
-            led,
-            time_source,
-            state: SequencerState::Idle,
-            sequence: None,
-            start_time: None,
-            pause_start_time: None,
-            current_color: BLACK,
-            color_epsilon: epsilon,
-            brightness: 1.0,
-        }
+    /// Creates sequencer with LED off and the settings of `config`.
+    pub fn with_config(led: L, time_source: &'t T, config: SequencerConfig<I::Duration>) -> Self {
+        let mut sequencer = Self::new(led, time_source);
+        sequencer.color_epsilon = config.color_epsilon;
+        sequencer.brightness = config.brightness;
+        sequencer.linear_output = config.linear_output;
+        sequencer.on_complete = config.on_complete;
+        sequencer.frame_interval = config.frame_interval;
+        sequencer.max_catch_up = config.max_catch_up;
+        sequencer
     }
 
     /// Dispatches action to appropriate method.
@@ -151,15 +654,15 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
         action: SequencerAction<I::Duration, N>,
     ) -> Result<(), SequencerError> {
         match action {
-            SequencerAction::Load(sequence) => {
-                self.load(sequence);
-                Ok(())
-            }
+            SequencerAction::Load(sequence) => self.try_load(sequence),
+            SequencerAction::LoadAndStart(sequence) => self.load_and_start(sequence),
             SequencerAction::Start => self.start(),
             SequencerAction::Stop => self.stop(),
             SequencerAction::Pause => self.pause(),
             SequencerAction::Resume => self.resume(),
+            SequencerAction::Hold => self.hold(),
             SequencerAction::Restart => self.restart(),
+            SequencerAction::Seek(position) => self.seek(position),
             SequencerAction::Clear => {
                 self.clear();
                 Ok(())
@@ -168,24 +671,187 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
                 self.set_brightness(brightness);
                 Ok(())
             }
+            SequencerAction::SetSpeed(speed) => {
+                self.set_speed(speed);
+                Ok(())
+            }
         }
     }
 
     /// Loads a sequence.
+    ///
+    /// Always replaces the current sequence; while `Running`, `LoadPolicy::StartImmediately`
+    /// starts the new one right away. Use `try_load()` to honor `LoadPolicy::Reject`.
     pub fn load(&mut self, sequence: RgbSequence<I::Duration, N>) {
+        self.chain = &[];
+        self.load_slot(SequenceSlot::Owned(sequence));
+    }
+
+    /// Loads a sequence unless the load policy rejects it.
+    ///
+    /// Behaves like `load()`, but returns `SequencerError::InvalidState` and keeps the
+    /// playing sequence if the sequencer is `Running` under `LoadPolicy::Reject`.
+    pub fn try_load(
+        &mut self,
+        sequence: RgbSequence<I::Duration, N>,
+    ) -> Result<(), SequencerError> {
+        self.check_load_policy()?;
+        self.load(sequence);
+        Ok(())
+    }
+
+    /// Loads a borrowed sequence without copying it.
+    ///
+    /// Lets many sequencers play one shared sequence (e.g. a `static`) instead of each
+    /// receiving its own copy on load. Behaves like `load()` otherwise. Operations that
+    /// modify the sequence, such as `set_remaining_loops()`, copy it first.
+    pub fn load_ref(&mut self, sequence: &'t RgbSequence<I::Duration, N>) {
+        self.chain = &[];
+        self.load_slot(SequenceSlot::Borrowed(sequence));
+    }
+
+    /// Loads a borrowed sequence unless the load policy rejects it (see `try_load()`).
+    pub fn try_load_ref(
+        &mut self,
+        sequence: &'t RgbSequence<I::Duration, N>,
+    ) -> Result<(), SequencerError> {
+        self.check_load_policy()?;
+        self.load_ref(sequence);
+        Ok(())
+    }
+
+    /// Loads `segments` as one logical sequence, played back to back without copying.
+    ///
+    /// Lets animations longer than `N` steps run on sequencers sized for one segment. When
+    /// a segment completes, the next one starts seamlessly, and `loop_count` repeats the
+    /// whole chain. Only the last segment's completion (landing color, `on_complete`)
+    /// takes effect, so earlier segments should be finite. Position queries, `seek()` and
+    /// snapshots refer to the current segment. Returns `SequencerError::NoSequenceLoaded`
+    /// if `segments` is empty. Follows the load policy like `try_load()`.
+    pub fn load_chain(
+        &mut self,
+        segments: &'t [RgbSequence<I::Duration, N>],
+        loop_count: LoopCount,
+    ) -> Result<(), SequencerError> {
+        let first = segments.first().ok_or(SequencerError::NoSequenceLoaded)?;
+        self.check_load_policy()?;
+        self.chain = segments;
+        self.chain_loops = loop_count;
+        self.load_slot(SequenceSlot::Borrowed(first));
+        Ok(())
+    }
+
+    /// Returns the index of the playing segment if a chain is loaded.
+    #[inline]
+    pub fn current_segment(&self) -> Option<usize> {
+        (!self.chain.is_empty()).then_some(self.chain_index)
+    }
+
+    /// Returns true if another chained segment follows the current one.
+    #[inline]
+    fn has_next_segment(&self) -> bool {
+        if self.chain.is_empty() {
+            return false;
+        }
+        self.chain_index + 1 < self.chain.len()
+            || match self.chain_loops {
+                LoopCount::Infinite => true,
+                LoopCount::Finite(count) => self.chain_loop + 1 < count,
+            }
+    }
+
+    /// Moves to the next chained segment after the current one completed at `elapsed`.
+    ///
+    /// Time played past the end of the segment carries over into the next one. Returns the
+    /// duration the completed segment consumed, or `None` at the end of the chain.
+    fn advance_chain(&mut self, now: I, elapsed: I::Duration) -> Option<I::Duration> {
+        if !self.has_next_segment() {
+            return None;
+        }
+
+        let consumed = self
+            .sequence
+            .as_deref()
+            .and_then(RgbSequence::total_duration)
+            .map_or(elapsed, |total| {
+                I::Duration::from_millis(total.as_millis().min(elapsed.as_millis()))
+            });
+
+        self.chain_index += 1;
+        if self.chain_index == self.chain.len() {
+            self.chain_index = 0;
+            self.chain_loop += 1;
+        }
+        self.sequence = Some(SequenceSlot::Borrowed(&self.chain[self.chain_index]));
+        self.start_time = Some(now);
+        self.seek_offset = elapsed
+            .saturating_sub(consumed)
+            .saturating_sub(self.phase_offset);
+        self.step_cursor = StepCursor::default();
+        Some(consumed)
+    }
+
+    /// Rewinds a loaded chain to its first segment.
+    fn rewind_chain(&mut self) {
+        if let Some(first) = self.chain.first() {
+            self.sequence = Some(SequenceSlot::Borrowed(first));
+            self.chain_index = 0;
+            self.chain_loop = 0;
+        }
+    }
+
+    /// Returns an error if the load policy rejects loading in the current state.
+    fn check_load_policy(&self) -> Result<(), SequencerError> {
+        if self.state == SequencerState::Running && self.load_policy == LoadPolicy::Reject {
+            return Err(SequencerError::InvalidState {
+                expected: "Idle, Loaded, Paused, Held, or Complete",
+                actual: self.state,
+            });
+        }
+        Ok(())
+    }
+
+    /// Loads an owned or borrowed sequence and resets playback, starting it if the load
+    /// policy asks for that.
+    fn load_slot(&mut self, sequence: SequenceSlot<'t, I::Duration, N>) {
+        let was_running = self.state == SequencerState::Running;
         self.sequence = Some(sequence);
+        self.chain_index = 0;
+        self.chain_loop = 0;
         self.start_time = None;
+        self.fade_start = None;
         self.pause_start_time = None;
+        self.step_cursor = StepCursor::default();
+        self.seek_offset = I::Duration::ZERO;
+        self.off_period_start = None;
+        self.last_service = None;
         self.state = SequencerState::Loaded;
+
+        if was_running && self.load_policy == LoadPolicy::StartImmediately {
+            // Cannot fail: a sequence was just loaded
+            let _ = self.start();
+        }
     }
 
     /// Starts sequence playback.
     ///
-    /// Transitions from `Loaded` to `Running` state.
+    /// Transitions from `Loaded` or `Complete` to `Running` state. Starting a completed
+    /// sequence plays it again from the beginning, like `restart()`.
     pub fn start(&mut self) -> Result<(), SequencerError> {
-        if self.state != SequencerState::Loaded {
+        self.start_at(self.time_source.now())
+    }
+
+    /// Starts sequence playback as if it had started at `start_time`.
+    ///
+    /// Lets several sequencers share one captured instant so they stay phase-aligned.
+    /// A `start_time` in the past begins playback part-way into the sequence.
+    pub fn start_at(&mut self, start_time: I) -> Result<(), SequencerError> {
+        if !matches!(
+            self.state,
+            SequencerState::Loaded | SequencerState::Complete
+        ) {
             return Err(SequencerError::InvalidState {
-                expected: "Loaded",
+                expected: "Loaded or Complete",
                 actual: self.state,
             });
         }
@@ -194,19 +860,45 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
             return Err(SequencerError::NoSequenceLoaded);
         }
 
-        self.start_time = Some(self.time_source.now());
+        self.rewind_chain();
+        self.start_time = Some(start_time);
+        self.fade_start = Some(start_time);
+        self.pause_start_time = None;
+        self.step_cursor = StepCursor::default();
+        self.seek_offset = I::Duration::ZERO;
+        self.off_period_start = None;
+        self.last_service = None;
         self.state = SequencerState::Running;
         Ok(())
     }
 
+    /// Starts playback paused at the first frame.
+    ///
+    /// Shows the sequence's initial color immediately but does not advance; call `resume()`
+    /// to release it, e.g. on an external trigger. Transitions from `Loaded` or `Complete` to
+    /// `Paused`.
+    pub fn start_paused(&mut self) -> Result<(), SequencerError> {
+        self.start()?;
+        self.service()?;
+
+        // A zero-duration sequence completes on its first frame
+        if self.state == SequencerState::Running {
+            self.pause()?;
+        }
+        Ok(())
+    }
+
     /// Loads and immediately starts a sequence.
     ///
-    /// Convenience method that combines `load()` and `start()`.
+    /// Convenience method that combines `try_load()` and `start()`.
     pub fn load_and_start(
         &mut self,
         sequence: RgbSequence<I::Duration, N>,
     ) -> Result<(), SequencerError> {
-        self.load(sequence);
+        self.try_load(sequence)?;
+        if self.state == SequencerState::Running {
+            return Ok(());
+        }
         self.start()
     }
 
@@ -214,29 +906,129 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
     ///
     /// Resets the start time and transitions to `Running` state.
     pub fn restart(&mut self) -> Result<(), SequencerError> {
+        self.restart_at(self.time_source.now())
+    }
+
+    /// Restarts sequence from beginning as if restarted at `start_time`.
+    ///
+    /// Like `start_at()`, lets several sequencers share one captured instant.
+    pub fn restart_at(&mut self, start_time: I) -> Result<(), SequencerError> {
         match self.state {
-            SequencerState::Running | SequencerState::Paused | SequencerState::Complete => {
+            SequencerState::Running
+            | SequencerState::Paused
+            | SequencerState::Held
+            | SequencerState::Complete => {
                 if self.sequence.is_none() {
                     return Err(SequencerError::NoSequenceLoaded);
                 }
 
-                self.start_time = Some(self.time_source.now());
+                self.rewind_chain();
+                self.start_time = Some(start_time);
+                self.fade_start = Some(start_time);
                 self.pause_start_time = None;
+                self.step_cursor = StepCursor::default();
+                self.seek_offset = I::Duration::ZERO;
+                self.off_period_start = None;
+                self.last_service = None;
                 self.state = SequencerState::Running;
                 Ok(())
             }
             _ => Err(SequencerError::InvalidState {
-                expected: "Running, Paused, or Complete",
+                expected: "Running, Paused, Held, or Complete",
                 actual: self.state,
             }),
         }
     }
 
-    /// Services sequencer, updating LED if color changed.
+    /// Rewinds to the beginning of the current loop iteration.
     ///
-    /// Must be called from `Running` state. Returns timing hint for next service call.
-    #[inline]
-    pub fn service(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+    /// Unlike `restart()`, the loop number is kept, so loops already played still count toward
+    /// a finite loop count. Works in `Running` and `Paused` states; a phase offset is kept, so
+    /// the rewind stops at the offset position in the first loop.
+    pub fn restart_current_loop(&mut self) -> Result<(), SequencerError> {
+        let reference_time = match self.state {
+            SequencerState::Running => self.time_source.now(),
+            SequencerState::Paused => self.pause_start_time.unwrap(),
+            _ => {
+                return Err(SequencerError::InvalidState {
+                    expected: "Running or Paused",
+                    actual: self.state,
+                });
+            }
+        };
+
+        let sequence = self
+            .sequence
+            .as_ref()
+            .ok_or(SequencerError::NoSequenceLoaded)?;
+        let elapsed = self.phase_shifted(reference_time.duration_since(self.start_time.unwrap()));
+        let position = sequence
+            .find_step_position(elapsed)
+            .ok_or(SequencerError::FunctionBasedSequence)?;
+
+        // The intro belongs to the first loop, so rewinding from it restarts the intro
+        let delay_millis = sequence.start_delay().as_millis();
+        let intro_millis = sequence.intro_duration().as_millis();
+        let loop_start = if elapsed.as_millis() < delay_millis + intro_millis {
+            delay_millis
+        } else {
+            delay_millis
+                + intro_millis
+                + sequence.loop_duration().as_millis() * position.current_loop as u64
+        }
+        .min(elapsed.as_millis());
+        self.start_time = Some(reference_time);
+        self.seek_offset =
+            I::Duration::from_millis(loop_start.saturating_sub(self.phase_offset.as_millis()));
+        self.step_cursor = StepCursor::default();
+        self.off_period_start = None;
+        Ok(())
+    }
+
+    /// Jumps to `position` in the sequence timeline, measured from the sequence start
+    /// (including any start delay).
+    ///
+    /// Works in `Running`, `Paused` and `Held` states; a paused sequencer resumes from the
+    /// new position. With a phase offset, positions before the offset clamp to it. Seeking
+    /// past the end of a finite sequence completes it on the next service call.
+    pub fn seek(&mut self, position: I::Duration) -> Result<(), SequencerError> {
+        let reference_time = match self.state {
+            SequencerState::Running | SequencerState::Held => self.time_source.now(),
+            SequencerState::Paused => self.pause_start_time.unwrap(),
+            _ => {
+                return Err(SequencerError::InvalidState {
+                    expected: "Running, Paused, or Held",
+                    actual: self.state,
+                });
+            }
+        };
+
+        self.start_time = Some(reference_time);
+        self.seek_offset = position.saturating_sub(self.phase_offset);
+        self.step_cursor = StepCursor::default();
+        self.off_period_start = None;
+        self.last_service = None;
+        Ok(())
+    }
+
+    /// Nudges playback toward an external periodic reference, such as a beat clock.
+    ///
+    /// `phase` is where the reference currently is within its `period` (0.0-1.0; values
+    /// outside wrap). The sequence's own position within `period` (time since the start of
+    /// its current loop, modulo `period`) is compared with it, and the timeline is shifted
+    /// by `phase_lock_gain()` of the difference in whichever direction is shorter, at least
+    /// 1ms. Calling this on every beat pulls the animation into lock within a few beats
+    /// without visible jumps, and keeps it there as the tempo drifts.
+    ///
+    /// Returns the phase error measured before the correction, as a fraction of `period`
+    /// between -0.5 and 0.5; positive when the sequence lags the reference. Works in
+    /// `Running` state. Returns `Ok(0.0)` without adjusting for a zero `period`, a NaN
+    /// `phase`, or a sequence without a loop phase (see `current_phase()`).
+    pub fn sync_to_phase(
+        &mut self,
+        period: I::Duration,
+        phase: f32,
+    ) -> Result<f32, SequencerError> {
         if self.state != SequencerState::Running {
             return Err(SequencerError::InvalidState {
                 expected: "Running",
@@ -244,46 +1036,415 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
             });
         }
 
-        let sequence = self.sequence.as_ref().unwrap();
-        let start_time = self.start_time.unwrap();
+        let period_millis = period.as_millis() as i64;
+        let Some((sequence, elapsed)) = self.playback_elapsed() else {
+            return Ok(0.0);
+        };
+        let Some(sequence_phase) = sequence.phase_at(elapsed) else {
+            return Ok(0.0);
+        };
+        if period_millis == 0 || phase.is_nan() {
+            return Ok(0.0);
+        }
+
+        // Both positions in milliseconds within the reference period
+        let loop_millis = sequence.loop_duration().as_millis() as f32;
+        let position = (sequence_phase * loop_millis) as i64 % period_millis;
+        let wrapped = phase - (phase as i64) as f32;
+        let wrapped = if wrapped < 0.0 {
+            wrapped + 1.0
+        } else {
+            wrapped
+        };
+        let target = (wrapped * period_millis as f32) as i64 % period_millis;
+
+        let mut error = target - position;
+        if error * 2 > period_millis {
+            error -= period_millis;
+        } else if error * 2 <= -period_millis {
+            error += period_millis;
+        }
+        if error == 0 || self.phase_lock_gain == 0.0 {
+            return Ok(error as f32 / period_millis as f32);
+        }
+
+        let correction = ((error.unsigned_abs() as f32 * self.phase_lock_gain) as u64).max(1);
+        let shift = self.wall_time(I::Duration::from_millis(correction));
+        let start_time = self.start_time.unwrap();
+        self.start_time = if error > 0 {
+            // Lagging: an earlier start moves playback forward
+            start_time.checked_sub(shift)
+        } else {
+            // Leading: a later start holds playback back, but never beyond now
+            let elapsed = self.time_source.now().duration_since(start_time);
+            start_time.checked_add(I::Duration::from_millis(
+                shift.as_millis().min(elapsed.as_millis()),
+            ))
+        }
+        .or(Some(start_time));
+        self.step_cursor = StepCursor::default();
+        Ok(error as f32 / period_millis as f32)
+    }
+
+    /// Returns the fraction of the phase error corrected by each `sync_to_phase()` call.
+    #[inline]
+    pub fn phase_lock_gain(&self) -> f32 {
+        self.phase_lock_gain
+    }
+
+    /// Sets the fraction of the phase error corrected by each `sync_to_phase()` call
+    /// (default: `DEFAULT_PHASE_LOCK_GAIN`).
+    ///
+    /// `1.0` snaps into phase at once; smaller values converge more slowly but smoothly.
+    /// Clamped to 0.0-1.0; NaN is ignored.
+    #[inline]
+    pub fn set_phase_lock_gain(&mut self, gain: f32) {
+        if !gain.is_nan() {
+            self.phase_lock_gain = gain.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Services sequencer, updating LED if color changed.
+    ///
+    /// Must be called from `Running` or `Held` state. Returns timing hint for next service
+    /// call. While `Held`, the timeline still advances (chained segments, auto-restart and
+    /// completion) but the LED keeps the held color.
+    #[inline]
+    pub fn service(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        if !self.needs_service() {
+            return Err(SequencerError::InvalidState {
+                expected: "Running or Held",
+                actual: self.state,
+            });
+        }
+
         let current_time = self.time_source.now();
-        let elapsed = current_time.duration_since(start_time);
+        self.clamp_catch_up(current_time);
+
+        // Wait out the auto-restart off-period, then start over at its end
+        if let (Some(off_period), Some(since)) = (self.auto_restart, self.off_period_start) {
+            let waited = current_time.duration_since(since);
+            if waited.as_millis() < off_period.as_millis() {
+                let timing = ServiceTiming::Delay(off_period.saturating_sub(waited));
+                return Ok(self.record_service(current_time, timing));
+            }
+
+            self.start_time = Some(since.checked_add(off_period).unwrap_or(current_time));
+            self.fade_start = self.start_time;
+            self.off_period_start = None;
+            self.seek_offset = I::Duration::ZERO;
+            self.step_cursor = StepCursor::default();
+            self.rewind_chain();
+        }
+
+        // Evaluate color and timing, resuming the step search from the previous call, and
+        // move on to the next chained segment whenever the current one has completed.
+        // Segments that consume no time are skipped at most one chain length in a row.
+        let mut idle_advances = 0;
+        let (new_color, next_service) = loop {
+            let sequence = self.sequence.as_deref().unwrap();
+            let elapsed = self.phase_shifted(current_time.duration_since(self.start_time.unwrap()));
+            let result = sequence.evaluate_with_cursor(elapsed, &mut self.step_cursor);
 
-        // Evaluate color and timing
-        let (new_color, next_service) = sequence.evaluate(elapsed);
+            if result.1.is_some() || idle_advances > self.chain.len() {
+                break result;
+            }
+            match self.advance_chain(current_time, elapsed) {
+                Some(consumed) if consumed == I::Duration::ZERO => idle_advances += 1,
+                Some(_) => idle_advances = 0,
+                None => break result,
+            }
+        };
+
+        // Ramp brightness up from zero during the master fade-in (first segment only)
+        let mut fading = false;
+        let mut brightness = self.brightness;
+        let fade_in_millis = self.fade_in.as_millis();
+        if fade_in_millis > 0
+            && self.chain_index == 0
+            && self.chain_loop == 0
+            && let Some(fade_start) = self.fade_start
+        {
+            // Measured in real time, so the fade is not scaled by the playback speed
+            let fade_millis = current_time.duration_since(fade_start).as_millis();
+            if fade_millis < fade_in_millis {
+                fading = true;
+                brightness *= fade_millis as f32 / fade_in_millis as f32;
+            }
+        }
 
         // Apply brightness to the evaluated color
+        let brightness = self.brightness_curve.apply(brightness);
         let dimmed_color = Srgb::new(
-            new_color.red * self.brightness,
-            new_color.green * self.brightness,
-            new_color.blue * self.brightness,
+            new_color.red * brightness,
+            new_color.green * brightness,
+            new_color.blue * brightness,
         );
 
-        // Update LED only if color changed (using epsilon for f32 comparison).
+        // An active override replaces the output, a fading one blends back to the sequence
+        let dimmed_color = if let Some(color) = self.color_override {
+            color
+        } else if let Some((from, since, duration)) = self.override_fade {
+            let fade_millis = current_time.duration_since(since).as_millis();
+            if fade_millis >= duration.as_millis() {
+                self.override_fade = None;
+                dimmed_color
+            } else {
+                fading = true;
+                let progress = fade_millis as f32 / duration.as_millis() as f32;
+                from.mix(dimmed_color, progress)
+            }
+        } else {
+            dimmed_color
+        };
+
+        // Update LED only if color changed (using epsilon for f32 comparison, or the
+        // quantized output level when an output resolution is set).
         // This avoids unnecessary hardware writes during static holds and prevents
         // spurious updates from floating-point rounding (<0.1% difference).
         // Particularly valuable for slow I2C/SPI LED drivers.
-        if !colors_approximately_equal(dimmed_color, self.current_color, self.color_epsilon) {
-            self.led.set_color(dimmed_color);
-            self.current_color = dimmed_color;
+        // Writes are suppressed entirely while output is disabled (the color is still
+        // tracked for re-enabling) or held, and skipped on completion when the LED is about
+        // to be turned off anyway. An override stays on through completion.
+        let turns_off = next_service.is_none()
+            && self.color_override.is_none()
+            && (self.auto_restart.is_some() || self.on_complete != OnComplete::HoldColor);
+        if !turns_off && self.state != SequencerState::Held {
+            if !self.output_enabled {
+                self.current_color = dimmed_color;
+            } else if self.output_changed(dimmed_color, self.current_color) {
+                self.led.set_color(self.encode_output(dimmed_color));
+                self.current_color = dimmed_color;
+            } else if self.output_resolution.is_some() {
+                // Same output level, so the LED already shows this color
+                self.current_color = dimmed_color;
+            }
         }
 
         // Convert timing hint to ServiceTiming
-        match next_service {
+        let timing = match next_service {
+            None if self.auto_restart.is_some() => self.begin_off_period(current_time),
             None => {
-                self.state = SequencerState::Complete;
-                Ok(ServiceTiming::Complete)
+                self.complete();
+                ServiceTiming::Complete
             }
-            Some(duration) if duration == I::Duration::ZERO => Ok(ServiceTiming::Continuous),
-            Some(duration) => Ok(ServiceTiming::Delay(duration)),
+            Some(_) if fading => ServiceTiming::Continuous,
+            Some(duration) if duration == I::Duration::ZERO => ServiceTiming::Continuous,
+            Some(duration) => ServiceTiming::Delay(self.wall_time(duration)),
+        };
+        Ok(self.record_service(current_time, timing))
+    }
+
+    /// Shifts the timeline forward past a stall longer than the allowed catch-up.
+    ///
+    /// A gap is a stall only beyond the delay hinted by the previous service call.
+    #[inline]
+    fn clamp_catch_up(&mut self, now: I) {
+        let (Some(max_catch_up), Some((last_time, hinted))) =
+            (self.max_catch_up, self.last_service)
+        else {
+            return;
+        };
+
+        let gap = now.duration_since(last_time).as_millis();
+        let allowed = hinted.as_millis() + max_catch_up.as_millis();
+        if gap <= allowed {
+            return;
         }
+
+        let stall = I::Duration::from_millis(gap - allowed);
+        self.start_time = self
+            .start_time
+            .map(|start| start.checked_add(stall).unwrap_or(start));
+        self.fade_start = self
+            .fade_start
+            .map(|start| start.checked_add(stall).unwrap_or(start));
+        self.off_period_start = self
+            .off_period_start
+            .map(|since| since.checked_add(stall).unwrap_or(since));
+    }
+
+    /// Applies the frame interval and records the service time and hinted delay for
+    /// catch-up clamping.
+    #[inline]
+    fn record_service(
+        &mut self,
+        now: I,
+        timing: ServiceTiming<I::Duration>,
+    ) -> ServiceTiming<I::Duration> {
+        let timing = self.paced(timing);
+        let hinted = match timing {
+            ServiceTiming::Delay(delay) => delay,
+            _ => I::Duration::ZERO,
+        };
+        self.last_service = Some((now, hinted));
+        timing
+    }
+
+    /// Replaces `Continuous` with a delay of one frame interval, if one is set.
+    #[inline]
+    fn paced(&self, timing: ServiceTiming<I::Duration>) -> ServiceTiming<I::Duration> {
+        match (timing, self.frame_interval) {
+            (ServiceTiming::Continuous, Some(interval)) => ServiceTiming::Delay(interval),
+            (timing, _) => timing,
+        }
+    }
+
+    /// Turns the LED off and starts the auto-restart off-period.
+    fn begin_off_period(&mut self, now: I) -> ServiceTiming<I::Duration> {
+        // A held LED keeps its color through the off-period
+        if self.state != SequencerState::Held {
+            self.turn_off();
+        }
+
+        let off_period = self.auto_restart.unwrap_or(I::Duration::ZERO);
+        self.off_period_start = Some(now);
+        if off_period == I::Duration::ZERO {
+            ServiceTiming::Continuous
+        } else {
+            ServiceTiming::Delay(off_period)
+        }
+    }
+
+    /// Applies the completion policy once the sequence has finished.
+    fn complete(&mut self) {
+        self.state = SequencerState::Complete;
+
+        match self.on_complete {
+            OnComplete::HoldColor => return,
+            OnComplete::TurnOff => {}
+            OnComplete::ReturnToLoaded => {
+                self.start_time = None;
+                self.fade_start = None;
+                self.state = SequencerState::Loaded;
+            }
+        }
+
+        self.turn_off();
+    }
+
+    /// Turns the LED off at the end of playback, unless a color override is showing.
+    fn turn_off(&mut self) {
+        if self.color_override.is_some() {
+            return;
+        }
+        if self.output_enabled {
+            self.led.set_color(BLACK);
+        }
+        self.current_color = BLACK;
+    }
+
+    /// Returns true if playback has turned the LED off, so `service()` will not repaint it.
+    fn is_turned_off(&self) -> bool {
+        self.off_period_start.is_some()
+            || match self.state {
+                SequencerState::Idle | SequencerState::Loaded => true,
+                SequencerState::Complete => self.on_complete != OnComplete::HoldColor,
+                _ => false,
+            }
+    }
+
+    /// Services sequencer like `service()`, returning the next service time as an instant.
+    ///
+    /// A `ServiceTiming::Delay` is added to the time this service call sampled from the time
+    /// source, so the deadline lands exactly on the next step boundary no matter how long
+    /// the caller takes before sleeping.
+    #[inline]
+    pub fn service_deadline(&mut self) -> Result<ServiceDeadline<I>, SequencerError> {
+        Ok(match self.service()? {
+            ServiceTiming::Continuous => ServiceDeadline::Continuous,
+            ServiceTiming::Complete => ServiceDeadline::Complete,
+            ServiceTiming::Delay(delay) => {
+                // Set by every service call that returns a timing
+                let (now, _) = self.last_service.unwrap();
+                ServiceDeadline::At(now.checked_add(delay).unwrap_or(now))
+            }
+        })
+    }
+
+    /// Services the sequencer if it is `Running` or `Held`, otherwise does nothing.
+    ///
+    /// Lenient variant of `service()` for loops that tick every sequencer regardless of
+    /// state: in any other state it returns `ServiceTiming::Complete` instead of
+    /// `SequencerError::InvalidState`, meaning no further servicing is needed until playback
+    /// is started or resumed.
+    #[inline]
+    pub fn service_if_running(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        if !self.needs_service() {
+            return Ok(ServiceTiming::Complete);
+        }
+        self.service()
     }
 
     /// Peeks at next timing hint without updating LED or advancing state.
     ///
-    /// Returns `SequencerError::InvalidState` if not in `Running` state.
+    /// In `Running` and `Held` states this is the hint the next `service()` would return.
+    /// In `Paused` and `Loaded` states it answers "if resumed (or started) now": the timing
+    /// from the frozen position, or from the beginning of the sequence. Returns
+    /// `SequencerError::InvalidState` in `Idle` and `Complete` states.
     #[inline]
     pub fn peek_next_timing(&self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        let (sequence, elapsed, off_period_waited, has_next_segment) = match self.state {
+            SequencerState::Running | SequencerState::Held | SequencerState::Paused => {
+                // A paused sequence resumes from where it was paused
+                let reference_time = match self.pause_start_time {
+                    Some(paused_at) if self.state == SequencerState::Paused => paused_at,
+                    _ => self.time_source.now(),
+                };
+                (
+                    self.sequence.as_deref().unwrap(),
+                    self.phase_shifted(reference_time.duration_since(self.start_time.unwrap())),
+                    self.off_period_start
+                        .map(|since| reference_time.duration_since(since)),
+                    self.has_next_segment(),
+                )
+            }
+            SequencerState::Loaded => {
+                // start() rewinds a chain and clears any seek
+                let sequence = match self.chain.first() {
+                    Some(first) => first,
+                    None => self.sequence.as_deref().unwrap(),
+                };
+                let has_next_segment = self.chain.len() > 1
+                    || (!self.chain.is_empty()
+                        && match self.chain_loops {
+                            LoopCount::Infinite => true,
+                            LoopCount::Finite(count) => count > 1,
+                        });
+                (sequence, self.phase_offset, None, has_next_segment)
+            }
+            _ => {
+                return Err(SequencerError::InvalidState {
+                    expected: "Running, Held, Paused, or Loaded",
+                    actual: self.state,
+                });
+            }
+        };
+
+        if let (Some(off_period), Some(waited)) = (self.auto_restart, off_period_waited) {
+            return Ok(ServiceTiming::Delay(off_period.saturating_sub(waited)));
+        }
+
+        // Evaluate timing without updating state
+        let (_color, next_service) = sequence.evaluate(elapsed);
+
+        // Convert timing hint to ServiceTiming
+        let timing = match next_service {
+            None if self.auto_restart.is_some() || has_next_segment => {
+                ServiceTiming::Delay(I::Duration::ZERO)
+            }
+            None => ServiceTiming::Complete,
+            Some(duration) if duration == I::Duration::ZERO => ServiceTiming::Continuous,
+            Some(duration) => ServiceTiming::Delay(self.wall_time(duration)),
+        };
+        Ok(self.paced(timing))
+    }
+
+    /// Skips the rest of the current step and services from the start of the next one.
+    ///
+    /// Following steps keep their timing relative to the new position. Skipping the last step
+    /// of a finite sequence completes it. Returns the timing hint of the resulting service call.
+    pub fn skip_current_step(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
         if self.state != SequencerState::Running {
             return Err(SequencerError::InvalidState {
                 expected: "Running",
@@ -291,37 +1452,44 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
             });
         }
 
-        let sequence = self.sequence.as_ref().unwrap();
+        let sequence = self
+            .sequence
+            .as_ref()
+            .ok_or(SequencerError::NoSequenceLoaded)?;
         let start_time = self.start_time.unwrap();
-        let current_time = self.time_source.now();
-        let elapsed = current_time.duration_since(start_time);
+        let elapsed = self.phase_shifted(self.time_source.now().duration_since(start_time));
 
-        // Evaluate timing without updating state
-        let (_color, next_service) = sequence.evaluate(elapsed);
+        let position = sequence
+            .find_step_position(elapsed)
+            .ok_or(SequencerError::FunctionBasedSequence)?;
 
-        // Convert timing hint to ServiceTiming
-        match next_service {
-            None => Ok(ServiceTiming::Complete),
-            Some(duration) if duration == I::Duration::ZERO => Ok(ServiceTiming::Continuous),
-            Some(duration) => Ok(ServiceTiming::Delay(duration)),
-        }
+        self.seek_offset = I::Duration::from_millis(
+            self.seek_offset.as_millis() + position.time_until_step_end.as_millis(),
+        );
+        self.service()
     }
 
     /// Stops sequence and turns LED off.
     pub fn stop(&mut self) -> Result<(), SequencerError> {
         match self.state {
-            SequencerState::Running | SequencerState::Paused | SequencerState::Complete => {
+            SequencerState::Running
+            | SequencerState::Paused
+            | SequencerState::Held
+            | SequencerState::Complete => {
                 self.start_time = None;
+                self.fade_start = None;
                 self.pause_start_time = None;
                 self.state = SequencerState::Loaded;
 
-                self.led.set_color(BLACK);
+                if self.output_enabled {
+                    self.led.set_color(BLACK);
+                }
                 self.current_color = BLACK;
 
                 Ok(())
             }
             _ => Err(SequencerError::InvalidState {
-                expected: "Running, Paused, or Complete",
+                expected: "Running, Paused, Held, or Complete",
                 actual: self.state,
             }),
         }
@@ -331,6 +1499,14 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
     ///
     /// Timing is compensated on resume - sequence continues from same position.
     pub fn pause(&mut self) -> Result<(), SequencerError> {
+        self.pause_at(self.time_source.now())
+    }
+
+    /// Pauses sequence as if paused at `instant`.
+    ///
+    /// The position is frozen at `instant`, so sequencers paused with one captured instant
+    /// stay aligned. Requires `Running` state.
+    pub fn pause_at(&mut self, instant: I) -> Result<(), SequencerError> {
         if self.state != SequencerState::Running {
             return Err(SequencerError::InvalidState {
                 expected: "Running",
@@ -338,25 +1514,55 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
             });
         }
 
-        self.pause_start_time = Some(self.time_source.now());
+        self.pause_start_time = Some(instant);
         self.state = SequencerState::Paused;
         Ok(())
     }
 
-    /// Resumes paused sequence.
+    /// Holds the LED at its currently displayed color.
+    ///
+    /// Unlike `pause()`, the sequence timeline keeps running: `resume()` continues from where
+    /// the sequence would be by then, not from where it was held. Keep calling `service()`
+    /// while held; a finite sequence still completes on time. Requires `Running` state.
+    pub fn hold(&mut self) -> Result<(), SequencerError> {
+        if self.state != SequencerState::Running {
+            return Err(SequencerError::InvalidState {
+                expected: "Running",
+                actual: self.state,
+            });
+        }
+
+        self.state = SequencerState::Held;
+        Ok(())
+    }
+
+    /// Resumes paused or held sequence.
     ///
     /// Automatically compensates for the paused duration to maintain timing continuity.
+    /// A held sequence continues at its current timeline position without compensation.
     pub fn resume(&mut self) -> Result<(), SequencerError> {
+        self.resume_at(self.time_source.now())
+    }
+
+    /// Resumes paused or held sequence as if resumed at `instant`.
+    ///
+    /// The paused duration is measured up to `instant`, so sequencers paused and resumed
+    /// with shared instants stay aligned.
+    pub fn resume_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        if self.state == SequencerState::Held {
+            self.state = SequencerState::Running;
+            return Ok(());
+        }
+
         if self.state != SequencerState::Paused {
             return Err(SequencerError::InvalidState {
-                expected: "Paused",
+                expected: "Paused or Held",
                 actual: self.state,
             });
         }
 
         let pause_start = self.pause_start_time.unwrap();
-        let current_time = self.time_source.now();
-        let pause_duration = current_time.duration_since(pause_start);
+        let pause_duration = instant.duration_since(pause_start);
 
         // Add the pause duration to start time to compensate for the time spent paused.
         // This keeps the sequence at the same position it was at when paused.
@@ -365,23 +1571,161 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
         // prevents a crash. This is a graceful degradation on timer overflow.
         let old_start = self.start_time.unwrap();
         self.start_time = Some(old_start.checked_add(pause_duration).unwrap_or(old_start));
+        self.fade_start = self
+            .fade_start
+            .map(|start| start.checked_add(pause_duration).unwrap_or(start));
+        self.off_period_start = self
+            .off_period_start
+            .map(|since| since.checked_add(pause_duration).unwrap_or(since));
 
         self.pause_start_time = None;
+        self.last_service = None;
         self.state = SequencerState::Running;
         Ok(())
     }
 
-    /// Clears sequence and turns LED off.
+    /// Clears sequence and any color override, and turns LED off.
     pub fn clear(&mut self) {
         self.sequence = None;
+        self.chain = &[];
         self.start_time = None;
+        self.fade_start = None;
         self.pause_start_time = None;
+        self.step_cursor = StepCursor::default();
+        self.seek_offset = I::Duration::ZERO;
+        self.off_period_start = None;
+        self.last_service = None;
+        self.color_override = None;
+        self.override_fade = None;
         self.state = SequencerState::Idle;
 
-        self.led.set_color(BLACK);
+        if self.output_enabled {
+            self.led.set_color(BLACK);
+        }
         self.current_color = BLACK;
     }
 
+    /// Halts playback and drives the LED to `color` immediately.
+    ///
+    /// Keeps the sequence loaded (`Loaded` state, or `Idle` if none). Brightness is not
+    /// applied, so the LED shows exactly `color`.
+    pub(crate) fn halt_with_color(&mut self, color: Srgb) {
+        self.start_time = None;
+        self.fade_start = None;
+        self.pause_start_time = None;
+        self.state = if self.sequence.is_some() {
+            SequencerState::Loaded
+        } else {
+            SequencerState::Idle
+        };
+
+        if self.output_enabled {
+            self.led.set_color(self.encode_output(color));
+        }
+        self.current_color = color;
+    }
+
+    /// Overrides how many loops remain, counting the loop currently playing.
+    ///
+    /// `Finite(1)` finishes the current loop and then completes, `Finite(0)` completes on the
+    /// next service call, and `Infinite` keeps looping. Works in `Loaded`, `Running` and
+    /// `Paused` states. Has no effect on function-based sequences.
+    pub fn set_remaining_loops(&mut self, remaining: LoopCount) -> Result<(), SequencerError> {
+        if !matches!(
+            self.state,
+            SequencerState::Loaded
+                | SequencerState::Running
+                | SequencerState::Paused
+                | SequencerState::Held
+        ) {
+            return Err(SequencerError::InvalidState {
+                expected: "Loaded, Running, Paused, or Held",
+                actual: self.state,
+            });
+        }
+
+        let current_loop = self.current_position().map_or(0, |p| p.loop_number);
+        let sequence = self
+            .sequence
+            .as_mut()
+            .map(SequenceSlot::to_mut)
+            .ok_or(SequencerError::NoSequenceLoaded)?;
+
+        let loop_count = match remaining {
+            LoopCount::Finite(count) => LoopCount::Finite(current_loop.saturating_add(count)),
+            LoopCount::Infinite => LoopCount::Infinite,
+        };
+        sequence.set_loop_count(loop_count);
+        Ok(())
+    }
+
+    /// Overrides the LED output with `color`, written immediately.
+    ///
+    /// The sequence keeps playing underneath: `service()` still advances time, completes
+    /// and returns timing hints, but the LED shows `color` until `clear_override()`, even
+    /// through completion and auto-restart off-periods. Brightness is not applied to the
+    /// override color; linear output conversion is.
+    pub fn override_color(&mut self, color: Srgb) {
+        self.color_override = Some(color);
+        self.override_fade = None;
+
+        if self.output_enabled {
+            self.led.set_color(self.encode_output(color));
+        }
+        self.current_color = color;
+    }
+
+    /// Removes the color override; the next `service()` snaps back to the sequence.
+    ///
+    /// If playback has already turned the LED off (stopped, completed with `TurnOff` or
+    /// `ReturnToLoaded`, or waiting out an auto-restart off-period), the LED turns off now.
+    pub fn clear_override(&mut self) {
+        self.color_override = None;
+        self.override_fade = None;
+        if self.is_turned_off() {
+            self.turn_off();
+        }
+    }
+
+    /// Removes the color override, fading back to the sequence over `duration`.
+    ///
+    /// `service()` returns `Continuous` while the fade is in progress. Turns the LED off
+    /// immediately, like `clear_override()`, if playback already has.
+    pub fn clear_override_with_fade(&mut self, duration: I::Duration) {
+        if self.is_turned_off() {
+            self.clear_override();
+        } else if let Some(color) = self.color_override.take() {
+            self.override_fade = Some((color, self.time_source.now(), duration));
+        }
+    }
+
+    /// Enables or disables LED output without affecting playback.
+    ///
+    /// Disabling turns the LED off and suppresses all further writes; the sequence timeline
+    /// is untouched and `current_color()` keeps following it. Re-enabling writes
+    /// `current_color()` right away, so the LED shows where the animation has got to.
+    pub fn set_output_enabled(&mut self, enabled: bool) {
+        let was_enabled = self.output_enabled;
+        self.output_enabled = enabled;
+        if was_enabled && !enabled {
+            self.led.set_color(BLACK);
+        } else if !was_enabled && enabled {
+            self.rewrite_output();
+        }
+    }
+
+    /// Returns true if LED output is enabled.
+    #[inline]
+    pub fn is_output_enabled(&self) -> bool {
+        self.output_enabled
+    }
+
+    /// Returns the active override color, if any.
+    #[inline]
+    pub fn color_override(&self) -> Option<Srgb> {
+        self.color_override
+    }
+
     /// Returns current state.
     #[inline]
     pub fn state(&self) -> SequencerState {
@@ -400,16 +1744,28 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
         self.state == SequencerState::Paused
     }
 
+    /// Returns true if held.
+    #[inline]
+    pub fn is_held(&self) -> bool {
+        self.state == SequencerState::Held
+    }
+
     /// Returns true if running.
     #[inline]
     pub fn is_running(&self) -> bool {
         self.state == SequencerState::Running
     }
 
+    /// Returns true if `service()` advances playback (`Running` or `Held`).
+    #[inline]
+    pub(crate) fn needs_service(&self) -> bool {
+        matches!(self.state, SequencerState::Running | SequencerState::Held)
+    }
+
     /// Returns current sequence reference.
     #[inline]
     pub fn current_sequence(&self) -> Option<&RgbSequence<I::Duration, N>> {
-        self.sequence.as_ref()
+        self.sequence.as_deref()
     }
 
     /// Returns elapsed time since start.
@@ -434,6 +1790,36 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
         self.color_epsilon = epsilon;
     }
 
+    /// Returns the output resolution in bits, if one is set.
+    #[inline]
+    pub fn output_resolution(&self) -> Option<u8> {
+        self.output_resolution
+    }
+
+    /// Sets the resolution of the LED driver in bits per channel (default: `None`).
+    ///
+    /// When set, `service()` skips `set_color()` until the output, after linear conversion,
+    /// white balance and limiting, changes by at least one step at this resolution (e.g.
+    /// one of 1024 duty levels for `Some(10)`), instead of using the color epsilon. Slow
+    /// fades then write only when the driver would actually change its output, while
+    /// `current_color()` still follows the sequence exactly. Clamped to 1-16 bits.
+    #[inline]
+    pub fn set_output_resolution(&mut self, bits: Option<u8>) {
+        self.output_resolution = bits.map(|bits| bits.clamp(1, 16));
+    }
+
+    /// Returns true if writing `color` would change the output from `previous`.
+    #[inline]
+    fn output_changed(&self, color: Srgb, previous: Srgb) -> bool {
+        match self.output_resolution {
+            Some(bits) => {
+                quantize(self.encode_output(color), bits)
+                    != quantize(self.encode_output(previous), bits)
+            }
+            None => !colors_approximately_equal(color, previous, self.color_epsilon),
+        }
+    }
+
     /// Returns current brightness multiplier (0.0-1.0).
     #[inline]
     pub fn brightness(&self) -> f32 {
@@ -446,16 +1832,443 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
         self.brightness = brightness.clamp(0.0, 1.0);
     }
 
+    /// Returns the playback speed multiplier.
+    #[inline]
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the playback speed multiplier (default: 1.0).
+    ///
+    /// `2.0` plays the sequence twice as fast, `0.5` at half speed. Clamped to
+    /// `MIN_SPEED`-`MAX_SPEED`; NaN is ignored. The current position is kept, so the
+    /// animation changes pace without jumping. Timing hints are reported in real time.
+    /// Auto-restart off-periods, fade-in and override fades are not scaled, and
+    /// `elapsed_time()` counts from the moment of the change.
+    pub fn set_speed(&mut self, speed: f32) {
+        if speed.is_nan() {
+            return;
+        }
+
+        // Fold the time played at the old speed into the seek offset
+        if let Some(start_time) = self.start_time {
+            let reference_time = match self.state {
+                SequencerState::Paused => self.pause_start_time.unwrap_or(start_time),
+                _ => self.time_source.now(),
+            };
+            let played = self.sequence_time(reference_time.duration_since(start_time));
+            self.seek_offset =
+                I::Duration::from_millis(self.seek_offset.as_millis() + played.as_millis());
+            self.start_time = Some(reference_time);
+        }
+        self.speed = speed.clamp(MIN_SPEED, MAX_SPEED);
+    }
+
+    /// Converts real time since start into sequence time at the current speed.
+    #[inline]
+    fn sequence_time(&self, elapsed: I::Duration) -> I::Duration {
+        if self.speed == 1.0 {
+            return elapsed;
+        }
+        I::Duration::from_millis((elapsed.as_millis() as f64 * self.speed as f64) as u64)
+    }
+
+    /// Converts a sequence time span into real time at the current speed.
+    #[inline]
+    fn wall_time(&self, duration: I::Duration) -> I::Duration {
+        if self.speed == 1.0 {
+            return duration;
+        }
+        I::Duration::from_millis((duration.as_millis() as f64 / self.speed as f64) as u64)
+    }
+
+    /// Returns the brightness curve.
+    #[inline]
+    pub fn brightness_curve(&self) -> BrightnessCurve {
+        self.brightness_curve
+    }
+
+    /// Sets how the brightness level maps to the color multiplier (default: `Linear`).
+    ///
+    /// Also shapes the fade-in ramp. Takes effect on the next `service()`.
+    #[inline]
+    pub fn set_brightness_curve(&mut self, curve: BrightnessCurve) {
+        self.brightness_curve = curve;
+    }
+
+    /// Returns true if colors are converted to linear light before output.
+    #[inline]
+    pub fn linear_output(&self) -> bool {
+        self.linear_output
+    }
+
+    /// Enables conversion of output colors from gamma-encoded sRGB to linear light.
+    ///
+    /// PWM duty is linear in light output, so linear conversion makes fades look even
+    /// instead of lumpy. Applied after brightness, just before `set_color()`; color change
+    /// detection and `current_color()` still use the sRGB value. The LED is rewritten
+    /// immediately with the new encoding.
+    pub fn set_linear_output(&mut self, enabled: bool) {
+        self.linear_output = enabled;
+        self.rewrite_output();
+    }
+
+    /// Returns the per-channel white balance scales.
+    #[inline]
+    pub fn white_balance(&self) -> (f32, f32, f32) {
+        self.white_balance
+    }
+
+    /// Sets per-channel scales (0.0-1.0, clamped) calibrating this LED's white point.
+    ///
+    /// Applied last, after brightness and linear conversion. Like linear conversion it does
+    /// not affect change detection, so small scales never suppress updates. The LED is
+    /// rewritten immediately with the new calibration.
+    pub fn set_white_balance(&mut self, red: f32, green: f32, blue: f32) {
+        self.white_balance = (
+            red.clamp(0.0, 1.0),
+            green.clamp(0.0, 1.0),
+            blue.clamp(0.0, 1.0),
+        );
+        self.rewrite_output();
+    }
+
+    /// Returns the maximum summed channel value written to the LED, if limited.
+    #[inline]
+    pub fn max_channel_sum(&self) -> Option<f32> {
+        self.max_channel_sum
+    }
+
+    /// Caps the sum of the red, green and blue values written to the LED.
+    ///
+    /// Colors above the budget are scaled down uniformly, keeping their hue. With several
+    /// LEDs on one regulator this bounds worst-case current regardless of the loaded
+    /// sequence: `Some(1.0)` allows at most one channel's worth of full drive. The cap is
+    /// applied after all other output corrections. `None` removes it.
+    pub fn set_max_channel_sum(&mut self, max: Option<f32>) {
+        self.max_channel_sum = max.map(|max| max.max(0.0));
+        self.rewrite_output();
+    }
+
+    /// Returns the custom output filter, if any.
+    #[inline]
+    pub fn output_filter(&self) -> Option<fn(Srgb) -> Srgb> {
+        self.output_filter
+    }
+
+    /// Sets a custom transform applied to every color written to the LED.
+    ///
+    /// Covers sequence output (after brightness), color overrides, `restore()` and
+    /// `SequencerCollection::set_all()`; the black written when the LED is switched off
+    /// (`stop()`, `clear()`, completion) bypasses it. Runs after linear conversion and white
+    /// balance and before the `max_channel_sum` cap and `max_brightness` ceiling, so it
+    /// cannot lift them. Use it to derate by temperature or for product-specific
+    /// corrections such as a night-shift tint. Results are clamped to 0.0-1.0; change
+    /// detection and `current_color()` are not affected. `None` removes it.
+    pub fn set_output_filter(&mut self, filter: Option<fn(Srgb) -> Srgb>) {
+        self.output_filter = filter;
+        self.rewrite_output();
+    }
+
+    /// Returns the maximum channel value written to the LED.
+    #[inline]
+    pub fn max_brightness(&self) -> f32 {
+        self.max_brightness
+    }
+
+    /// Sets a hard ceiling (0.0-1.0, clamped) on every channel written to the LED (default:
+    /// 1.0).
+    ///
+    /// Unlike `set_brightness()`, this is not a multiplier: colors below the ceiling pass
+    /// unchanged and brighter ones are scaled down uniformly, keeping their hue. It is
+    /// applied after every other correction and also covers color overrides, so no
+    /// sequence, function animation, brightness setting, filter or command can exceed it.
+    /// Use it for safety and thermal limits. NaN is ignored.
+    pub fn set_max_brightness(&mut self, max: f32) {
+        if max.is_nan() {
+            return;
+        }
+        self.max_brightness = max.clamp(0.0, 1.0);
+        self.rewrite_output();
+    }
+
+    /// Rewrites the current color after an output correction setting changed.
+    fn rewrite_output(&mut self) {
+        if self.output_enabled {
+            self.led.set_color(self.encode_output(self.current_color));
+        }
+    }
+
+    /// Applies output corrections to a color about to be written to the LED.
+    #[inline]
+    fn encode_output(&self, color: Srgb) -> Srgb {
+        let color = if self.linear_output {
+            let linear = color.into_linear::<f32>();
+            Srgb::new(linear.red, linear.green, linear.blue)
+        } else {
+            color
+        };
+
+        let (red, green, blue) = self.white_balance;
+        let color = Srgb::new(color.red * red, color.green * green, color.blue * blue);
+
+        let color = match self.output_filter {
+            Some(filter) => sanitize_color(filter(color)),
+            None => color,
+        };
+
+        // Scale down uniformly when the summed drive exceeds the budget
+        let color = match self.max_channel_sum {
+            Some(max) if color.red + color.green + color.blue > max => {
+                let scale = max / (color.red + color.green + color.blue);
+                Srgb::new(color.red * scale, color.green * scale, color.blue * scale)
+            }
+            _ => color,
+        };
+
+        // Hard ceiling last, so nothing above can lift it
+        let peak = color.red.max(color.green).max(color.blue);
+        if peak > self.max_brightness {
+            let scale = self.max_brightness / peak;
+            Srgb::new(color.red * scale, color.green * scale, color.blue * scale)
+        } else {
+            color
+        }
+    }
+
+    /// Returns the completion behavior.
+    #[inline]
+    pub fn on_complete(&self) -> OnComplete {
+        self.on_complete
+    }
+
+    /// Sets what happens when a finite sequence completes (default: `HoldColor`).
+    #[inline]
+    pub fn set_on_complete(&mut self, behavior: OnComplete) {
+        self.on_complete = behavior;
+    }
+
+    /// Returns the load policy.
+    #[inline]
+    pub fn load_policy(&self) -> LoadPolicy {
+        self.load_policy
+    }
+
+    /// Sets what loading a sequence does while `Running` (default: `Replace`).
+    ///
+    /// Applies to `try_load()`, `try_load_ref()`, `load_chain()`, `load_and_start()` and the
+    /// matching commands; `load()` and `load_ref()` treat `Reject` as `Replace`. In other
+    /// states loading always replaces the sequence.
+    #[inline]
+    pub fn set_load_policy(&mut self, policy: LoadPolicy) {
+        self.load_policy = policy;
+    }
+
+    /// Returns the auto-restart off-period, if auto-restart is enabled.
+    #[inline]
+    pub fn auto_restart(&self) -> Option<I::Duration> {
+        self.auto_restart
+    }
+
+    /// Enables automatic restart of finite sequences after an off-period.
+    ///
+    /// When the sequence completes, the LED turns off for `off_period` and the sequence then
+    /// starts over, e.g. "blink 3 times, wait 10s, repeat". The sequencer stays `Running`
+    /// throughout and `service()` returns `Delay` for the off-period. Takes precedence over
+    /// `OnComplete`. `None` disables auto-restart.
+    #[inline]
+    pub fn set_auto_restart(&mut self, off_period: Option<I::Duration>) {
+        self.auto_restart = off_period;
+    }
+
+    /// Returns the maximum catch-up per service call, if clamping is enabled.
+    #[inline]
+    pub fn max_catch_up(&self) -> Option<I::Duration> {
+        self.max_catch_up
+    }
+
+    /// Limits how far the animation may jump forward after a stall.
+    ///
+    /// If `service()` is called later than the previous timing hint plus `max_catch_up`
+    /// (e.g. after sleep, a flash write or a debugger halt), the excess is treated like a
+    /// pause and the animation continues from near where it left off. `None` disables it.
+    #[inline]
+    pub fn set_max_catch_up(&mut self, max_catch_up: Option<I::Duration>) {
+        self.max_catch_up = max_catch_up;
+    }
+
+    /// Returns the frame interval used in place of `Continuous`, if one is set.
+    #[inline]
+    pub fn frame_interval(&self) -> Option<I::Duration> {
+        self.frame_interval
+    }
+
+    /// Sets the preferred frame interval for animated phases.
+    ///
+    /// When set, `service()` and `peek_next_timing()` return `ServiceTiming::Delay(interval)`
+    /// wherever they would return `Continuous`, so main loops can sleep on every hint alike
+    /// without a frame rate constant of their own. Longer intervals trade smoothness for
+    /// fewer wake-ups. `None` (the default) keeps returning `Continuous`.
+    #[inline]
+    pub fn set_frame_interval(&mut self, interval: Option<I::Duration>) {
+        self.frame_interval = interval;
+    }
+
+    /// Returns the master fade-in duration (`ZERO` if disabled).
+    #[inline]
+    pub fn fade_in(&self) -> I::Duration {
+        self.fade_in
+    }
+
+    /// Sets a master fade-in applied whenever playback starts.
+    ///
+    /// After `start()` or `restart()`, brightness ramps linearly from 0 to the configured
+    /// level over `duration`, independent of the sequence contents. The ramp is measured in
+    /// real time from the start, excluding pauses, so speed changes and seeks do not affect
+    /// it. `service()` returns `Continuous` during the ramp. `ZERO` disables the fade-in.
+    #[inline]
+    pub fn set_fade_in(&mut self, duration: I::Duration) {
+        self.fade_in = duration;
+    }
+
+    /// Returns the phase offset added to elapsed time before evaluation.
+    #[inline]
+    pub fn phase_offset(&self) -> I::Duration {
+        self.phase_offset
+    }
+
+    /// Sets phase offset added to elapsed time before evaluation.
+    ///
+    /// Shifts the sequence forward in time, so sequencers sharing one sequence can run
+    /// out of phase (e.g. three LEDs on a 3000ms rainbow offset by 0, 1000 and 2000ms).
+    /// Finite sequences complete earlier by the offset. Takes effect on the next service call.
+    #[inline]
+    pub fn set_phase_offset(&mut self, offset: I::Duration) {
+        self.phase_offset = offset;
+    }
+
+    /// Returns elapsed time scaled by the speed and shifted by the phase offset and any
+    /// skipped time.
+    #[inline]
+    fn phase_shifted(&self, elapsed: I::Duration) -> I::Duration {
+        let elapsed = self.sequence_time(elapsed);
+        if self.phase_offset == I::Duration::ZERO && self.seek_offset == I::Duration::ZERO {
+            return elapsed;
+        }
+        I::Duration::from_millis(
+            elapsed.as_millis() + self.phase_offset.as_millis() + self.seek_offset.as_millis(),
+        )
+    }
+
     /// Returns current playback position.
     ///
-    /// When running, returns the current position. When paused, returns the frozen position
-    /// where the sequence will resume from. Returns `None` if not running/paused or sequence
-    /// is function-based.
+    /// When running or held, returns the current position. When paused, returns the frozen position
+    /// where the sequence will resume from. Function-based sequences with a period (see
+    /// `RgbSequence::with_period()`) report step 0 and the number of completed periods.
+    /// Returns `None` if not running/paused/held or the sequence is function-based without a
+    /// period.
     #[inline]
     pub fn current_position(&self) -> Option<Position> {
+        if let Some(step_position) = self.step_position() {
+            return Some(Position {
+                step_index: step_position.step_index,
+                loop_number: step_position.current_loop,
+            });
+        }
+
+        let (sequence, elapsed) = self.playback_elapsed()?;
+        let period = sequence.period()?;
+        Some(Position {
+            step_index: 0,
+            loop_number: (elapsed.as_millis() / period.as_millis()) as u32,
+        })
+    }
+
+    /// Returns how far through the current loop playback is, from 0.0 to 1.0.
+    ///
+    /// Works for step-based sequences and for function-based sequences with a period. See
+    /// `RgbSequence::phase_at()`; returns `None` in the same cases and when not
+    /// running/paused/held. Paused sequencers report the frozen phase.
+    #[inline]
+    pub fn current_phase(&self) -> Option<f32> {
+        let (sequence, elapsed) = self.playback_elapsed()?;
+        sequence.phase_at(elapsed)
+    }
+
+    /// Returns the target color of the current step.
+    ///
+    /// Unlike `current_color()`, this is the step's destination color rather than the
+    /// mid-transition value, and brightness is not applied. Returns `None` under the same
+    /// conditions as `current_position()`.
+    #[inline]
+    pub fn current_step_color(&self) -> Option<Srgb> {
+        Some(self.current_step()?.color)
+    }
+
+    /// Returns the transition style of the current step.
+    ///
+    /// Returns `None` under the same conditions as `current_position()`.
+    #[inline]
+    pub fn current_transition(&self) -> Option<TransitionStyle> {
+        Some(self.current_step()?.transition)
+    }
+
+    /// Returns how far playback is into the current step.
+    ///
+    /// Returns `None` under the same conditions as `current_position()`.
+    #[inline]
+    pub fn time_in_current_step(&self) -> Option<I::Duration> {
+        Some(self.step_position()?.time_in_step)
+    }
+
+    /// Returns how many loop iterations have fully played.
+    ///
+    /// Returns `None` before playback starts and for function-based sequences.
+    pub fn loops_completed(&self) -> Option<u32> {
+        if self.state == SequencerState::Complete {
+            let sequence = self.sequence.as_deref()?;
+            return match sequence.loop_count() {
+                LoopCount::Finite(count) if !sequence.is_function_based() => Some(count),
+                _ => None,
+            };
+        }
+
+        let step_position = self.step_position()?;
+        if step_position.is_complete {
+            Some(step_position.current_loop + 1)
+        } else {
+            Some(step_position.current_loop)
+        }
+    }
+
+    /// Returns how many loop iterations are left, including the one playing.
+    ///
+    /// Returns `None` for infinite and function-based sequences, and before playback starts.
+    pub fn loops_remaining(&self) -> Option<u32> {
+        match self.sequence.as_deref()?.loop_count() {
+            LoopCount::Finite(count) => Some(count.saturating_sub(self.loops_completed()?)),
+            LoopCount::Infinite => None,
+        }
+    }
+
+    /// Returns the step currently playing.
+    #[inline]
+    fn current_step(&self) -> Option<&SequenceStep<I::Duration>> {
+        let step_position = self.step_position()?;
+        self.sequence.as_deref()?.get_step(step_position.step_index)
+    }
+
+    /// Returns the detailed step position while running, paused or held.
+    fn step_position(&self) -> Option<StepPosition<I::Duration>> {
+        let (sequence, elapsed) = self.playback_elapsed()?;
+        sequence.find_step_position(elapsed)
+    }
+
+    /// Returns the playing sequence and its timeline position, if running/paused/held.
+    fn playback_elapsed(&self) -> Option<(&RgbSequence<I::Duration, N>, I::Duration)> {
         match self.state {
-            SequencerState::Running | SequencerState::Paused => {
-                let sequence = self.sequence.as_ref()?;
+            SequencerState::Running | SequencerState::Paused | SequencerState::Held => {
+                let sequence = self.sequence.as_deref()?;
                 let start_time = self.start_time?;
 
                 // Use pause_start_time for paused state to get frozen position,
@@ -466,18 +2279,121 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
                     self.time_source.now()
                 };
 
-                let elapsed = reference_time.duration_since(start_time);
-
-                let step_position = sequence.find_step_position(elapsed)?;
-                Some(Position {
-                    step_index: step_position.step_index,
-                    loop_number: step_position.current_loop,
-                })
+                Some((
+                    sequence,
+                    self.phase_shifted(reference_time.duration_since(start_time)),
+                ))
             }
             _ => None,
         }
     }
 
+    /// Captures sequence, state, playback progress and settings.
+    ///
+    /// A paused sequencer saves its frozen position. Restore with `restore()`, possibly on a
+    /// new sequencer after the LED and time source were re-created.
+    pub fn snapshot(&self) -> SequencerSnapshot<I::Duration, N> {
+        let reference_time = match self.state {
+            SequencerState::Paused => self.pause_start_time,
+            _ => Some(self.time_source.now()),
+        };
+        let since = |instant: Option<I>| Some(reference_time?.duration_since(instant?));
+
+        let elapsed = since(self.start_time).map(|playback_time| {
+            I::Duration::from_millis(
+                self.sequence_time(playback_time).as_millis() + self.seek_offset.as_millis(),
+            )
+        });
+
+        SequencerSnapshot {
+            sequence: self.sequence.as_deref().cloned(),
+            state: self.state,
+            elapsed,
+            off_period_elapsed: since(self.off_period_start),
+            current_color: self.current_color,
+            color_epsilon: self.color_epsilon,
+            brightness: self.brightness,
+            phase_offset: self.phase_offset,
+            color_override: self.color_override,
+            output_enabled: self.output_enabled,
+            fade_in: self.fade_in,
+            on_complete: self.on_complete,
+            auto_restart: self.auto_restart,
+            max_catch_up: self.max_catch_up,
+        }
+    }
+
+    /// Restores a snapshot, continuing playback from the saved position.
+    ///
+    /// The saved color is written to the LED immediately unless the restored output is
+    /// disabled; a running sequencer picks up from the saved position on the next
+    /// `service()` call. A loaded chain is dropped.
+    ///
+    /// Snapshots carry the color epsilon, brightness, phase offset, color override, output
+    /// enable, fade-in, completion behavior, auto-restart and catch-up clamp. Everything
+    /// else keeps its current value on this sequencer and must be re-applied if it was
+    /// re-created: speed, brightness curve, linear output, white balance, output limits
+    /// and hooks, output resolution, load policy, frame interval and phase lock gain.
+    pub fn restore(&mut self, snapshot: SequencerSnapshot<I::Duration, N>) {
+        let now = self.time_source.now();
+
+        self.sequence = snapshot.sequence.map(SequenceSlot::Owned);
+        self.chain = &[];
+        self.chain_index = 0;
+        self.chain_loop = 0;
+        self.state = snapshot.state;
+        self.start_time = snapshot.elapsed.map(|_| now);
+        self.seek_offset = snapshot.elapsed.unwrap_or(I::Duration::ZERO);
+        // Snapshots carry no fade progress; treat the saved position as time since start
+        self.fade_start = snapshot
+            .elapsed
+            .map(|elapsed| now.checked_sub(elapsed).unwrap_or(now));
+        self.pause_start_time = (snapshot.state == SequencerState::Paused).then_some(now);
+        self.off_period_start = snapshot
+            .off_period_elapsed
+            .map(|waited| now.checked_sub(waited).unwrap_or(now));
+        self.step_cursor = StepCursor::default();
+        self.last_service = None;
+        self.override_fade = None;
+
+        self.color_epsilon = snapshot.color_epsilon;
+        self.brightness = snapshot.brightness;
+        self.phase_offset = snapshot.phase_offset;
+        self.color_override = snapshot.color_override;
+        self.output_enabled = snapshot.output_enabled;
+        self.fade_in = snapshot.fade_in;
+        self.on_complete = snapshot.on_complete;
+        self.auto_restart = snapshot.auto_restart;
+        self.max_catch_up = snapshot.max_catch_up;
+
+        self.current_color = snapshot.current_color;
+        if self.output_enabled {
+            self.led
+                .set_color(self.encode_output(snapshot.current_color));
+        }
+    }
+
+    /// Returns the time source.
+    #[inline]
+    pub fn time_source(&self) -> &'t T {
+        self.time_source
+    }
+
+    /// Returns the LED.
+    #[inline]
+    pub fn led(&self) -> &L {
+        &self.led
+    }
+
+    /// Returns the LED mutably, e.g. to drive adapters that need periodic updates.
+    ///
+    /// Colors written directly to the LED are not tracked; the sequencer keeps comparing
+    /// against its own last color, so it may skip rewriting a color it believes is shown.
+    #[inline]
+    pub fn led_mut(&mut self) -> &mut L {
+        &mut self.led
+    }
+
     /// Consumes the sequencer and returns the LED.
     #[inline]
     pub fn into_led(self) -> L {
@@ -487,6 +2403,6 @@ impl<'t, I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> RgbSequenc
     /// Consumes the sequencer and returns the LED and current sequence.
     #[inline]
     pub fn into_parts(self) -> (L, Option<RgbSequence<I::Duration, N>>) {
-        (self.led, self.sequence)
+        (self.led, self.sequence.map(SequenceSlot::into_owned))
     }
 }
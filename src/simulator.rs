@@ -0,0 +1,241 @@
+//! Terminal preview of sequences on a host machine (requires the `std` feature).
+//!
+//! `SimulatorLed` renders each color as an ANSI truecolor block, and `run()` drives a
+//! sequencer in real time using `std::time`, so sequences can be previewed on a laptop
+//! before flashing. `sample_to_csv()` exports animation curves for plotting.
+
+extern crate std;
+
+use crate::sequence::RgbSequence;
+use crate::sequencer::{RgbLed, RgbSequencer, SequencerError, SequencerState, ServiceTiming};
+use crate::time::{TimeDuration, TimeInstant, TimeSource};
+use crate::types::LoopCount;
+use palette::Srgb;
+use std::io::{Error, ErrorKind, Stdout, Write};
+use std::time::{Duration, Instant};
+
+impl TimeDuration for Duration {
+    const ZERO: Self = Duration::ZERO;
+
+    fn as_millis(&self) -> u64 {
+        Duration::as_millis(self) as u64
+    }
+
+    fn from_millis(millis: u64) -> Self {
+        Duration::from_millis(millis)
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        Duration::saturating_sub(self, other)
+    }
+}
+
+impl TimeInstant for Instant {
+    type Duration = Duration;
+
+    fn duration_since(&self, earlier: Self) -> Self::Duration {
+        self.saturating_duration_since(earlier)
+    }
+
+    fn checked_add(self, duration: Self::Duration) -> Option<Self> {
+        Instant::checked_add(&self, duration)
+    }
+
+    fn checked_sub(self, duration: Self::Duration) -> Option<Self> {
+        Instant::checked_sub(&self, duration)
+    }
+}
+
+/// Time source backed by `std::time::Instant`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdTimeSource;
+
+impl TimeSource<Instant> for StdTimeSource {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// LED that renders colors as ANSI truecolor blocks in a terminal.
+///
+/// Without timestamps the block is redrawn in place on one line. With timestamps every
+/// update is printed on its own line, prefixed with the time since the LED was created.
+#[derive(Debug)]
+pub struct SimulatorLed<W: Write = Stdout> {
+    writer: W,
+    created: Instant,
+    timestamps: bool,
+}
+
+impl SimulatorLed<Stdout> {
+    /// Creates a simulator LED writing to stdout.
+    pub fn new() -> Self {
+        Self::with_writer(std::io::stdout())
+    }
+}
+
+impl Default for SimulatorLed<Stdout> {
+    /// Returns a simulator LED writing to stdout.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> SimulatorLed<W> {
+    /// Creates a simulator LED writing to `writer`.
+    pub fn with_writer(writer: W) -> Self {
+        Self {
+            writer,
+            created: Instant::now(),
+            timestamps: false,
+        }
+    }
+
+    /// Prints each update on its own line, prefixed with a timestamp.
+    pub fn with_timestamps(mut self, enabled: bool) -> Self {
+        self.timestamps = enabled;
+        self
+    }
+
+    /// Consumes the LED and returns the writer.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> RgbLed for SimulatorLed<W> {
+    fn set_color(&mut self, color: Srgb) {
+        let [red, green, blue] = [color.red, color.green, color.blue]
+            .map(|channel| (channel.clamp(0.0, 1.0) * 255.0 + 0.5) as u8);
+
+        // Terminal output is best effort; a closed pipe must not stop the sequence
+        let _ = if self.timestamps {
+            let elapsed = self.created.elapsed().as_secs_f32();
+            writeln!(
+                self.writer,
+                "[{elapsed:>9.3}s] \x1b[48;2;{red};{green};{blue}m        \x1b[0m #{red:02X}{green:02X}{blue:02X}"
+            )
+        } else {
+            write!(
+                self.writer,
+                "\r\x1b[48;2;{red};{green};{blue}m        \x1b[0m #{red:02X}{green:02X}{blue:02X}"
+            )
+        };
+        let _ = self.writer.flush();
+    }
+}
+
+/// Services `sequencer` in real time until it completes or `limit` has passed.
+///
+/// Starts the sequencer if it is `Loaded`. Sleeps for `frame_interval` between continuous
+/// updates and for the hinted delay during static holds. Pass `None` as `limit` to run
+/// until completion (forever for infinite sequences).
+pub fn run<L: RgbLed, T: TimeSource<Instant>, const N: usize>(
+    sequencer: &mut RgbSequencer<'_, Instant, L, T, N>,
+    frame_interval: Duration,
+    limit: Option<Duration>,
+) -> Result<(), SequencerError> {
+    if sequencer.state() == SequencerState::Loaded {
+        sequencer.start()?;
+    }
+
+    let started = Instant::now();
+    loop {
+        let sleep = match sequencer.service()? {
+            ServiceTiming::Continuous => frame_interval,
+            ServiceTiming::Delay(delay) => delay,
+            ServiceTiming::Complete => return Ok(()),
+        };
+
+        let sleep = match limit {
+            Some(limit) => {
+                let elapsed = started.elapsed();
+                if elapsed >= limit {
+                    return Ok(());
+                }
+                sleep.min(limit - elapsed)
+            }
+            None => sleep,
+        };
+        std::thread::sleep(sleep);
+    }
+}
+
+/// Sampling length for function-based sequences that never report completion.
+const FUNCTION_SAMPLE_LIMIT_MILLIS: u64 = 10_000;
+
+/// Evaluates `sequence` every `sample_interval` and writes `time_ms,r,g,b` CSV rows.
+///
+/// Writes a header row first. Finite sequences are sampled through completion, including
+/// a final row at the completion time. Infinite step-based sequences are sampled over the
+/// start delay, intro and one loop; function-based sequences stop when the timing function
+/// returns `None` or after 10 seconds. Returns `ErrorKind::InvalidInput` for a zero
+/// sample interval.
+pub fn sample_to_csv<D: TimeDuration, const N: usize, W: Write>(
+    sequence: &RgbSequence<D, N>,
+    sample_interval: D,
+    mut writer: W,
+) -> std::io::Result<()> {
+    let interval = sample_interval.as_millis();
+    if interval == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "sample interval must be non-zero",
+        ));
+    }
+
+    let limit = if sequence.is_function_based() {
+        FUNCTION_SAMPLE_LIMIT_MILLIS
+    } else if sequence.loop_count() == LoopCount::Infinite {
+        sequence.start_delay().as_millis()
+            + sequence.intro_duration().as_millis()
+            + sequence.loop_duration().as_millis()
+    } else {
+        u64::MAX
+    };
+
+    writeln!(writer, "time_ms,r,g,b")?;
+    let mut millis = 0;
+    loop {
+        let elapsed = D::from_millis(millis.min(limit));
+        let (color, timing) = sequence.evaluate(elapsed);
+        writeln!(
+            writer,
+            "{},{:.4},{:.4},{:.4}",
+            elapsed.as_millis(),
+            color.red,
+            color.green,
+            color.blue
+        )?;
+
+        if timing.is_none() || millis >= limit {
+            return Ok(());
+        }
+
+        // Land exactly on the completion time instead of skipping past it
+        let next = millis + interval;
+        millis = if sequence.has_completed(D::from_millis(next)) {
+            completion_millis(sequence, millis, next)
+        } else {
+            next
+        };
+    }
+}
+
+/// Finds the first millisecond in `(from, to]` at which `sequence` has completed.
+fn completion_millis<D: TimeDuration, const N: usize>(
+    sequence: &RgbSequence<D, N>,
+    from: u64,
+    to: u64,
+) -> u64 {
+    let (mut low, mut high) = (from, to);
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if sequence.has_completed(D::from_millis(mid)) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    high
+}
@@ -0,0 +1,169 @@
+//! SK6812-class RGBW pixel output with white extraction.
+//!
+//! `Sk6812Led` converts each evaluated color into red, green, blue and white channels
+//! using a `WhiteExtraction` mapper, then writes the pixel through an `RgbwWrite` driver.
+//! Extraction runs on the color the sequencer outputs, i.e. after brightness, so white
+//! and color channels dim together and mixed colors keep their balance at any level.
+
+use crate::output::ColorMapper;
+use crate::sequencer::RgbLed;
+use palette::Srgb;
+
+/// Smart-LED style driver for RGBW pixels.
+///
+/// Mirrors `smart_leds::SmartLedsWrite` for four-channel pixels: implement it by
+/// forwarding to your driver (e.g. `ws2812-spi` in RGBW mode). Channel order on the wire
+/// (often GRBW) is the driver's concern.
+pub trait RgbwWrite {
+    /// Driver error type.
+    type Error;
+
+    /// Writes `[red, green, blue, white]` values to consecutive pixels.
+    fn write(&mut self, pixels: &[[u8; 4]]) -> Result<(), Self::Error>;
+}
+
+/// How much of a color's common (gray) component moves to the white channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WhiteStrategy {
+    /// White channel stays off; colors use RGB only.
+    Off,
+    /// Moves the whole common component to white. Best efficiency and the cleanest whites.
+    Full,
+    /// Moves the given fraction (0.0-1.0) of the common component to white, keeping some
+    /// RGB mixing for smoother pastel transitions.
+    Partial(f32),
+    /// Copies the common component to white without removing it from RGB. Brighter
+    /// whites and pastels, at the cost of color accuracy and current.
+    Additive,
+}
+
+/// Color mapper that splits an sRGB color into red, green, blue and white channels.
+///
+/// Also usable with `MappedLed` for other RGBW hardware.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WhiteExtraction {
+    strategy: WhiteStrategy,
+    white_scale: f32,
+}
+
+impl WhiteExtraction {
+    /// Creates a mapper using `strategy` and an unscaled white channel.
+    pub const fn new(strategy: WhiteStrategy) -> Self {
+        Self {
+            strategy,
+            white_scale: 1.0,
+        }
+    }
+
+    /// Scales the white channel to match the RGB mix (0.0-1.0, clamped).
+    ///
+    /// White dies are usually brighter than full RGB; a scale below 1.0 keeps extracted
+    /// whites from jumping in brightness compared to the same color mixed from RGB.
+    pub fn with_white_scale(mut self, scale: f32) -> Self {
+        self.white_scale = if scale.is_nan() {
+            1.0
+        } else {
+            scale.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Returns the extraction strategy.
+    #[inline]
+    pub fn strategy(&self) -> WhiteStrategy {
+        self.strategy
+    }
+
+    /// Returns the white channel scale.
+    #[inline]
+    pub fn white_scale(&self) -> f32 {
+        self.white_scale
+    }
+}
+
+impl ColorMapper<4> for WhiteExtraction {
+    fn map(&self, color: Srgb) -> [f32; 4] {
+        let [red, green, blue] =
+            [color.red, color.green, color.blue].map(|channel| channel.clamp(0.0, 1.0));
+        let common = red.min(green).min(blue);
+
+        let (removed, white) = match self.strategy {
+            WhiteStrategy::Off => (0.0, 0.0),
+            WhiteStrategy::Full => (common, common),
+            WhiteStrategy::Partial(fraction) => {
+                let moved = common * fraction.clamp(0.0, 1.0);
+                (moved, moved)
+            }
+            WhiteStrategy::Additive => (0.0, common),
+        };
+
+        [
+            red - removed,
+            green - removed,
+            blue - removed,
+            white * self.white_scale,
+        ]
+    }
+}
+
+/// RGB LED driven as `PIXELS` SK6812 RGBW pixels showing the same color.
+///
+/// `RgbLed::set_color()` cannot fail, so driver errors are counted instead of returned;
+/// see `error_count()`. Leave dimming to `RgbSequencer::set_brightness()`: the pixels
+/// have no global brightness, and scaling after extraction is what keeps the white and
+/// color channels in proportion.
+#[derive(Debug)]
+pub struct Sk6812Led<D, const PIXELS: usize = 1> {
+    driver: D,
+    extraction: WhiteExtraction,
+    errors: u32,
+}
+
+impl<D: RgbwWrite, const PIXELS: usize> Sk6812Led<D, PIXELS> {
+    /// Creates an LED writing through `driver`, using `WhiteStrategy::Full`.
+    pub fn new(driver: D) -> Self {
+        Self {
+            driver,
+            extraction: WhiteExtraction::new(WhiteStrategy::Full),
+            errors: 0,
+        }
+    }
+
+    /// Sets the white extraction mapper.
+    pub fn with_extraction(mut self, extraction: WhiteExtraction) -> Self {
+        self.extraction = extraction;
+        self
+    }
+
+    /// Returns the white extraction mapper.
+    #[inline]
+    pub fn extraction(&self) -> &WhiteExtraction {
+        &self.extraction
+    }
+
+    /// Returns how many pixel writes have failed, saturating at `u32::MAX`.
+    #[inline]
+    pub fn error_count(&self) -> u32 {
+        self.errors
+    }
+
+    /// Consumes the LED and returns the driver.
+    #[inline]
+    pub fn into_driver(self) -> D {
+        self.driver
+    }
+}
+
+impl<D: RgbwWrite, const PIXELS: usize> RgbLed for Sk6812Led<D, PIXELS> {
+    fn set_color(&mut self, color: Srgb) {
+        let pixel = self
+            .extraction
+            .map(color)
+            .map(|channel| (channel * 255.0 + 0.5) as u8);
+
+        if self.driver.write(&[pixel; PIXELS]).is_err() {
+            self.errors = self.errors.saturating_add(1);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Asynchronous frame stream (requires the `async` feature).
+//!
+//! `Frames` services a sequencer at the cadence its `ServiceTiming` asks for and yields
+//! one `(instant, color)` frame per service call. Waiting is delegated to a
+//! caller-supplied `sleep_until` function, so the same stream runs in real time on an
+//! async runtime or in virtual time against a mock clock.
+
+use crate::sequencer::{RgbLed, RgbSequencer, SequencerState, ServiceDeadline};
+use crate::time::{TimeInstant, TimeSource};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+use palette::Srgb;
+
+/// Stream of `(instant, color)` frames produced by servicing a sequencer.
+///
+/// The first frame is produced immediately (starting the sequencer if it is `Loaded`).
+/// After each frame the stream waits until the next service is due: the deadline from
+/// `service_deadline()` during holds, or `frame_interval` while animating. The
+/// stream ends after the frame at which the sequence completes, or when the sequencer
+/// is neither running nor held, or reports an error. A held sequencer keeps yielding its
+/// held color until the timeline completes.
+///
+/// `next_frame()` accepts any sleep future. The `Stream` implementation stores the
+/// future between polls and therefore needs it to be `Unpin`; on `std` wrap
+/// runtime timers in `Box::pin`.
+pub struct Frames<'s, 't, I, L, T, S, F, const N: usize>
+where
+    I: TimeInstant,
+    L: RgbLed,
+    T: TimeSource<I>,
+{
+    sequencer: &'s mut RgbSequencer<'t, I, L, T, N>,
+    sleep_until: S,
+    frame_interval: I::Duration,
+    next: Option<I>,
+    started: bool,
+    sleeping: Option<F>,
+}
+
+impl<'s, 't, I, L, T, S, F, const N: usize> Frames<'s, 't, I, L, T, S, F, N>
+where
+    I: TimeInstant,
+    L: RgbLed,
+    T: TimeSource<I>,
+    S: FnMut(I) -> F,
+    F: Future<Output = ()>,
+{
+    /// Creates a stream over `sequencer`.
+    ///
+    /// `sleep_until` must return a future that completes once the sequencer's time source
+    /// has reached the given instant.
+    pub fn new(
+        sequencer: &'s mut RgbSequencer<'t, I, L, T, N>,
+        frame_interval: I::Duration,
+        sleep_until: S,
+    ) -> Self {
+        Self {
+            sequencer,
+            sleep_until,
+            frame_interval,
+            next: None,
+            started: false,
+            sleeping: None,
+        }
+    }
+
+    /// Waits for and returns the next frame, or `None` once the stream has ended.
+    pub async fn next_frame(&mut self) -> Option<(I, Srgb)> {
+        if self.started {
+            let deadline = self.next?;
+            (self.sleep_until)(deadline).await;
+        }
+        self.frame()
+    }
+
+    /// Services the sequencer and schedules the next frame.
+    fn frame(&mut self) -> Option<(I, Srgb)> {
+        if !self.started {
+            self.started = true;
+            if self.sequencer.state() == SequencerState::Loaded && self.sequencer.start().is_err() {
+                return None;
+            }
+        }
+        self.next = None;
+
+        if !self.sequencer.needs_service() {
+            return None;
+        }
+        let deadline = self.sequencer.service_deadline().ok()?;
+        let now = self.sequencer.time_source().now();
+
+        self.next = match deadline {
+            ServiceDeadline::Continuous => now.checked_add(self.frame_interval),
+            ServiceDeadline::At(deadline) => Some(deadline),
+            ServiceDeadline::Complete => None,
+        };
+        Some((now, self.sequencer.current_color()))
+    }
+}
+
+impl<I, L, T, S, F, const N: usize> Stream for Frames<'_, '_, I, L, T, S, F, N>
+where
+    I: TimeInstant + Unpin,
+    I::Duration: Unpin,
+    L: RgbLed,
+    T: TimeSource<I>,
+    S: FnMut(I) -> F + Unpin,
+    F: Future<Output = ()> + Unpin,
+{
+    type Item = (I, Srgb);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.started && this.sleeping.is_none() {
+            match this.next {
+                Some(deadline) => this.sleeping = Some((this.sleep_until)(deadline)),
+                None => return Poll::Ready(None),
+            }
+        }
+        if let Some(sleeping) = this.sleeping.as_mut() {
+            if Pin::new(sleeping).poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            this.sleeping = None;
+        }
+        Poll::Ready(this.frame())
+    }
+}
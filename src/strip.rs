@@ -0,0 +1,499 @@
+//! Sequencer for addressable LED strips.
+//!
+//! `StripSequencer` runs one sequence and renders a frame of `PIXELS` colors per service
+//! call. A `FrameRenderer` decides how the evaluated sequence color is laid out across
+//! the strip - `Uniform` drives the whole strip as one logical LED, while `Chase`,
+//! `Scanner` and `Wipe` move the color across pixels.
+
+use crate::BLACK;
+use crate::command::SequencerAction;
+use crate::sequence::RgbSequence;
+use crate::sequencer::{
+    Position, RgbLed, RgbSequencer, SequencerError, SequencerState, ServiceTiming,
+};
+use crate::time::{TimeDuration, TimeInstant, TimeSource};
+use palette::Srgb;
+
+/// Trait for abstracting addressable LED strip hardware.
+pub trait RgbStrip<const PIXELS: usize> {
+    /// Writes a complete frame to the strip.
+    ///
+    /// Color components are in 0.0-1.0 range. Convert to your hardware's native format
+    /// (e.g. GRB bytes for WS2812) in your implementation.
+    fn write_frame(&mut self, frame: &[Srgb; PIXELS]);
+}
+
+/// Lays out the evaluated sequence color across a strip frame.
+pub trait FrameRenderer<D, const PIXELS: usize> {
+    /// Fills `frame` from the evaluated (brightness-scaled) sequence color.
+    fn render(&mut self, color: Srgb, elapsed: D, frame: &mut [Srgb; PIXELS]);
+
+    /// Returns true if the frame changes over time even while the sequence color holds.
+    ///
+    /// Animated renderers turn `ServiceTiming::Delay` into `ServiceTiming::Continuous`, or
+    /// shorten it to the frame interval if one is set.
+    #[inline]
+    fn is_animated(&self) -> bool {
+        false
+    }
+}
+
+/// Renders every pixel with the sequence color (strip acts as one logical LED).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uniform;
+
+impl<D, const PIXELS: usize> FrameRenderer<D, PIXELS> for Uniform {
+    #[inline]
+    fn render(&mut self, color: Srgb, _elapsed: D, frame: &mut [Srgb; PIXELS]) {
+        frame.fill(color);
+    }
+}
+
+/// Scales color by a fade factor (0.0-1.0).
+#[inline]
+fn fade(color: Srgb, factor: f32) -> Srgb {
+    Srgb::new(
+        color.red * factor,
+        color.green * factor,
+        color.blue * factor,
+    )
+}
+
+/// Returns brightness of a tail pixel `distance` pixels behind the head.
+#[inline]
+fn tail_factor(distance: usize, tail: usize) -> f32 {
+    1.0 - (distance as f32) / ((tail + 1) as f32)
+}
+
+/// Returns the number of whole `step` intervals in `elapsed` (zero step treated as 1ms).
+#[inline]
+fn steps_elapsed<D: TimeDuration>(elapsed: D, step: D) -> u64 {
+    elapsed.as_millis() / step.as_millis().max(1)
+}
+
+/// Moves the sequence color along the strip, wrapping from last pixel to first.
+///
+/// The head advances one pixel per `step` and is followed by `tail` pixels fading
+/// linearly to black.
+#[derive(Debug, Clone, Copy)]
+pub struct Chase<D> {
+    step: D,
+    tail: usize,
+}
+
+impl<D: TimeDuration> Chase<D> {
+    /// Creates chase effect advancing one pixel per `step` with a `tail`-pixel fade.
+    pub fn new(step: D, tail: usize) -> Self {
+        Self { step, tail }
+    }
+}
+
+impl<D: TimeDuration, const PIXELS: usize> FrameRenderer<D, PIXELS> for Chase<D> {
+    fn render(&mut self, color: Srgb, elapsed: D, frame: &mut [Srgb; PIXELS]) {
+        if PIXELS == 0 {
+            return;
+        }
+
+        let head = (steps_elapsed(elapsed, self.step) % PIXELS as u64) as usize;
+        let tail = self.tail.min(PIXELS - 1);
+
+        // Draw dimmest first so brighter pixels win where the tail wraps onto itself
+        for distance in (0..=tail).rev() {
+            let index = (head + PIXELS - distance) % PIXELS;
+            frame[index] = fade(color, tail_factor(distance, self.tail));
+        }
+    }
+
+    #[inline]
+    fn is_animated(&self) -> bool {
+        true
+    }
+}
+
+/// Bounces the sequence color back and forth across the strip (Knight Rider scanner).
+///
+/// The head advances one pixel per `step`, reversing at each end, and is followed by
+/// `tail` pixels fading linearly to black.
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<D> {
+    step: D,
+    tail: usize,
+}
+
+impl<D: TimeDuration> Scanner<D> {
+    /// Creates scanner effect advancing one pixel per `step` with a `tail`-pixel fade.
+    pub fn new(step: D, tail: usize) -> Self {
+        Self { step, tail }
+    }
+}
+
+impl<D: TimeDuration, const PIXELS: usize> FrameRenderer<D, PIXELS> for Scanner<D> {
+    fn render(&mut self, color: Srgb, elapsed: D, frame: &mut [Srgb; PIXELS]) {
+        if PIXELS == 0 {
+            return;
+        }
+
+        // One period covers the sweep out and back without repeating the end pixels
+        let period = (2 * (PIXELS - 1)).max(1);
+        let position = (steps_elapsed(elapsed, self.step) % period as u64) as usize;
+        let tail = self.tail.min(period - 1);
+
+        for distance in (0..=tail).rev() {
+            let trail = (position + period - distance) % period;
+            let index = if trail < PIXELS {
+                trail
+            } else {
+                period - trail
+            };
+            frame[index] = fade(color, tail_factor(distance, self.tail));
+        }
+    }
+
+    #[inline]
+    fn is_animated(&self) -> bool {
+        true
+    }
+}
+
+/// Fills the strip with the sequence color one pixel per `step`, starting at pixel 0.
+///
+/// Once every pixel is lit the strip behaves like `Uniform`.
+#[derive(Debug, Clone, Copy)]
+pub struct Wipe<D> {
+    step: D,
+}
+
+impl<D: TimeDuration> Wipe<D> {
+    /// Creates wipe effect lighting one additional pixel per `step`.
+    pub fn new(step: D) -> Self {
+        Self { step }
+    }
+}
+
+impl<D: TimeDuration, const PIXELS: usize> FrameRenderer<D, PIXELS> for Wipe<D> {
+    fn render(&mut self, color: Srgb, elapsed: D, frame: &mut [Srgb; PIXELS]) {
+        let lit = steps_elapsed(elapsed, self.step).saturating_add(1);
+        let lit = lit.min(PIXELS as u64) as usize;
+        frame[..lit].fill(color);
+    }
+
+    #[inline]
+    fn is_animated(&self) -> bool {
+        true
+    }
+}
+
+/// Captures the sequencer output without driving hardware.
+#[derive(Debug)]
+struct ColorSink;
+
+impl RgbLed for ColorSink {
+    #[inline]
+    fn set_color(&mut self, _color: Srgb) {}
+}
+
+/// Controls an addressable LED strip through sequences.
+pub struct StripSequencer<
+    't,
+    I: TimeInstant,
+    S: RgbStrip<PIXELS>,
+    T: TimeSource<I>,
+    const N: usize,
+    const PIXELS: usize,
+    R: FrameRenderer<I::Duration, PIXELS> = Uniform,
+> {
+    sequencer: RgbSequencer<'t, I, ColorSink, T, N>,
+    strip: S,
+    renderer: R,
+    frame: [Srgb; PIXELS],
+}
+
+/// Returns true if any pixel differs by more than epsilon.
+#[inline]
+fn frames_differ<const PIXELS: usize>(
+    a: &[Srgb; PIXELS],
+    b: &[Srgb; PIXELS],
+    epsilon: f32,
+) -> bool {
+    a.iter().zip(b.iter()).any(|(a, b)| {
+        (a.red - b.red).abs() >= epsilon
+            || (a.green - b.green).abs() >= epsilon
+            || (a.blue - b.blue).abs() >= epsilon
+    })
+}
+
+impl<'t, I: TimeInstant, S: RgbStrip<PIXELS>, T: TimeSource<I>, const N: usize, const PIXELS: usize>
+    StripSequencer<'t, I, S, T, N, PIXELS, Uniform>
+{
+    /// Creates sequencer driving the whole strip as one LED, with all pixels off.
+    pub fn new(strip: S, time_source: &'t T) -> Self {
+        Self::with_renderer(strip, time_source, Uniform)
+    }
+}
+
+impl<
+    't,
+    I: TimeInstant,
+    S: RgbStrip<PIXELS>,
+    T: TimeSource<I>,
+    const N: usize,
+    const PIXELS: usize,
+    R: FrameRenderer<I::Duration, PIXELS>,
+> StripSequencer<'t, I, S, T, N, PIXELS, R>
+{
+    /// Creates sequencer with a custom frame renderer, with all pixels off.
+    pub fn with_renderer(mut strip: S, time_source: &'t T, renderer: R) -> Self {
+        let frame = [BLACK; PIXELS];
+        strip.write_frame(&frame);
+
+        Self {
+            sequencer: RgbSequencer::new(ColorSink, time_source),
+            strip,
+            renderer,
+            frame,
+        }
+    }
+
+    /// Dispatches action to appropriate method.
+    pub fn handle_action(
+        &mut self,
+        action: SequencerAction<I::Duration, N>,
+    ) -> Result<(), SequencerError> {
+        match action {
+            SequencerAction::Stop => self.stop(),
+            SequencerAction::Clear => {
+                self.clear();
+                Ok(())
+            }
+            action => self.sequencer.handle_action(action),
+        }
+    }
+
+    /// Loads a sequence.
+    pub fn load(&mut self, sequence: RgbSequence<I::Duration, N>) {
+        self.sequencer.load(sequence);
+    }
+
+    /// Loads a borrowed sequence without copying it.
+    pub fn load_ref(&mut self, sequence: &'t RgbSequence<I::Duration, N>) {
+        self.sequencer.load_ref(sequence);
+    }
+
+    /// Starts sequence playback.
+    pub fn start(&mut self) -> Result<(), SequencerError> {
+        self.sequencer.start()
+    }
+
+    /// Starts sequence playback as if it had started at `start_time`.
+    pub fn start_at(&mut self, start_time: I) -> Result<(), SequencerError> {
+        self.sequencer.start_at(start_time)
+    }
+
+    /// Loads and immediately starts a sequence.
+    pub fn load_and_start(
+        &mut self,
+        sequence: RgbSequence<I::Duration, N>,
+    ) -> Result<(), SequencerError> {
+        self.sequencer.load_and_start(sequence)
+    }
+
+    /// Restarts sequence from beginning.
+    pub fn restart(&mut self) -> Result<(), SequencerError> {
+        self.sequencer.restart()
+    }
+
+    /// Restarts sequence from beginning as if restarted at `start_time`.
+    pub fn restart_at(&mut self, start_time: I) -> Result<(), SequencerError> {
+        self.sequencer.restart_at(start_time)
+    }
+
+    /// Services sequencer, rendering a frame and writing it if any pixel changed.
+    ///
+    /// Must be called from `Running` state. Returns timing hint for next service call.
+    pub fn service(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        let timing = self.sequencer.service()?;
+        let elapsed = self.sequencer.elapsed_time().unwrap_or(I::Duration::ZERO);
+
+        let mut frame = [BLACK; PIXELS];
+        self.renderer
+            .render(self.sequencer.current_color(), elapsed, &mut frame);
+
+        if frames_differ(&frame, &self.frame, self.sequencer.color_epsilon()) {
+            self.strip.write_frame(&frame);
+            self.frame = frame;
+        }
+
+        match timing {
+            ServiceTiming::Delay(delay) if self.renderer.is_animated() => {
+                match self.sequencer.frame_interval() {
+                    Some(interval) if interval.as_millis() < delay.as_millis() => {
+                        Ok(ServiceTiming::Delay(interval))
+                    }
+                    Some(_) => Ok(ServiceTiming::Delay(delay)),
+                    None => Ok(ServiceTiming::Continuous),
+                }
+            }
+            timing => Ok(timing),
+        }
+    }
+
+    /// Services the sequencer if it is `Running` or `Held`, otherwise returns
+    /// `ServiceTiming::Complete`.
+    ///
+    /// See `RgbSequencer::service_if_running()`.
+    pub fn service_if_running(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        if !self.sequencer.needs_service() {
+            return Ok(ServiceTiming::Complete);
+        }
+        self.service()
+    }
+
+    /// Stops sequence and turns all pixels off.
+    pub fn stop(&mut self) -> Result<(), SequencerError> {
+        self.sequencer.stop()?;
+        self.blank();
+        Ok(())
+    }
+
+    /// Pauses sequence at current frame.
+    pub fn pause(&mut self) -> Result<(), SequencerError> {
+        self.sequencer.pause()
+    }
+
+    /// Pauses sequence as if paused at `instant`.
+    pub fn pause_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        self.sequencer.pause_at(instant)
+    }
+
+    /// Resumes paused sequence.
+    pub fn resume(&mut self) -> Result<(), SequencerError> {
+        self.sequencer.resume()
+    }
+
+    /// Resumes paused sequence as if resumed at `instant`.
+    pub fn resume_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        self.sequencer.resume_at(instant)
+    }
+
+    /// Clears sequence and turns all pixels off.
+    pub fn clear(&mut self) {
+        self.sequencer.clear();
+        self.blank();
+    }
+
+    /// Writes an all-black frame.
+    fn blank(&mut self) {
+        self.frame = [BLACK; PIXELS];
+        self.strip.write_frame(&self.frame);
+    }
+
+    /// Returns current state.
+    #[inline]
+    pub fn state(&self) -> SequencerState {
+        self.sequencer.state()
+    }
+
+    /// Returns true if paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.sequencer.is_paused()
+    }
+
+    /// Returns true if running.
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.sequencer.is_running()
+    }
+
+    /// Returns current evaluated sequence color (before rendering).
+    #[inline]
+    pub fn current_color(&self) -> Srgb {
+        self.sequencer.current_color()
+    }
+
+    /// Returns the last frame written to the strip.
+    #[inline]
+    pub fn frame(&self) -> &[Srgb; PIXELS] {
+        &self.frame
+    }
+
+    /// Returns current sequence reference.
+    #[inline]
+    pub fn current_sequence(&self) -> Option<&RgbSequence<I::Duration, N>> {
+        self.sequencer.current_sequence()
+    }
+
+    /// Returns elapsed time since start.
+    pub fn elapsed_time(&self) -> Option<I::Duration> {
+        self.sequencer.elapsed_time()
+    }
+
+    /// Returns current playback position.
+    #[inline]
+    pub fn current_position(&self) -> Option<Position> {
+        self.sequencer.current_position()
+    }
+
+    /// Returns the current color epsilon threshold.
+    #[inline]
+    pub fn color_epsilon(&self) -> f32 {
+        self.sequencer.color_epsilon()
+    }
+
+    /// Sets the color epsilon threshold used for color and frame change detection.
+    #[inline]
+    pub fn set_color_epsilon(&mut self, epsilon: f32) {
+        self.sequencer.set_color_epsilon(epsilon);
+    }
+
+    /// Returns current brightness multiplier (0.0-1.0).
+    #[inline]
+    pub fn brightness(&self) -> f32 {
+        self.sequencer.brightness()
+    }
+
+    /// Sets global brightness multiplier.
+    #[inline]
+    pub fn set_brightness(&mut self, brightness: f32) {
+        self.sequencer.set_brightness(brightness);
+    }
+
+    /// Returns the frame interval used in place of `Continuous`, if one is set.
+    #[inline]
+    pub fn frame_interval(&self) -> Option<I::Duration> {
+        self.sequencer.frame_interval()
+    }
+
+    /// Sets the preferred frame interval for animated phases and animated renderers.
+    ///
+    /// See `RgbSequencer::set_frame_interval()`.
+    #[inline]
+    pub fn set_frame_interval(&mut self, interval: Option<I::Duration>) {
+        self.sequencer.set_frame_interval(interval);
+    }
+
+    /// Returns the frame renderer.
+    #[inline]
+    pub fn renderer(&self) -> &R {
+        &self.renderer
+    }
+
+    /// Returns the frame renderer for reconfiguration.
+    #[inline]
+    pub fn renderer_mut(&mut self) -> &mut R {
+        &mut self.renderer
+    }
+
+    /// Consumes the sequencer and returns the strip.
+    #[inline]
+    pub fn into_strip(self) -> S {
+        self.strip
+    }
+
+    /// Consumes the sequencer and returns the strip and current sequence.
+    #[inline]
+    pub fn into_parts(self) -> (S, Option<RgbSequence<I::Duration, N>>) {
+        let (_, sequence) = self.sequencer.into_parts();
+        (self.strip, sequence)
+    }
+}
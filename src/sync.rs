@@ -0,0 +1,220 @@
+//! Coordinated playback control for sequencers of different types.
+//!
+//! `SyncGroup` registers any mix of `RgbSequencer`s and `StripSequencer`s (different LED
+//! types, capacities or strip lengths) and starts, restarts, pauses and resumes them all
+//! against one captured instant, so they stay phase-aligned without sharing a
+//! `SequencerCollection`.
+
+use crate::collection::{CollectionError, ErrorReport, first_error, merge_service_results};
+use crate::sequencer::{RgbLed, RgbSequencer, SequencerError, SequencerState, ServiceTiming};
+use crate::strip::{FrameRenderer, RgbStrip, StripSequencer};
+use crate::time::{TimeInstant, TimeSource};
+use heapless::Vec;
+
+/// Playback control shared by everything a `SyncGroup` can coordinate.
+///
+/// Every method takes the instant to act at, so one captured instant can be applied to
+/// many members.
+pub trait SyncMember<I: TimeInstant> {
+    /// Starts playback as if started at `instant` (see `RgbSequencer::start_at()`).
+    fn start_at(&mut self, instant: I) -> Result<(), SequencerError>;
+
+    /// Restarts playback as if restarted at `instant` (see `RgbSequencer::restart_at()`).
+    fn restart_at(&mut self, instant: I) -> Result<(), SequencerError>;
+
+    /// Pauses playback as if paused at `instant` (see `RgbSequencer::pause_at()`).
+    fn pause_at(&mut self, instant: I) -> Result<(), SequencerError>;
+
+    /// Resumes playback as if resumed at `instant` (see `RgbSequencer::resume_at()`).
+    fn resume_at(&mut self, instant: I) -> Result<(), SequencerError>;
+
+    /// Services the member if it is running or held (see
+    /// `RgbSequencer::service_if_running()`).
+    fn service_if_running(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError>;
+
+    /// Returns the current state.
+    fn state(&self) -> SequencerState;
+}
+
+impl<I: TimeInstant, L: RgbLed, T: TimeSource<I>, const N: usize> SyncMember<I>
+    for RgbSequencer<'_, I, L, T, N>
+{
+    fn start_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        RgbSequencer::start_at(self, instant)
+    }
+
+    fn restart_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        RgbSequencer::restart_at(self, instant)
+    }
+
+    fn pause_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        RgbSequencer::pause_at(self, instant)
+    }
+
+    fn resume_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        RgbSequencer::resume_at(self, instant)
+    }
+
+    fn service_if_running(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        RgbSequencer::service_if_running(self)
+    }
+
+    fn state(&self) -> SequencerState {
+        RgbSequencer::state(self)
+    }
+}
+
+impl<I, S, T, R, const N: usize, const PIXELS: usize> SyncMember<I>
+    for StripSequencer<'_, I, S, T, N, PIXELS, R>
+where
+    I: TimeInstant,
+    S: RgbStrip<PIXELS>,
+    T: TimeSource<I>,
+    R: FrameRenderer<I::Duration, PIXELS>,
+{
+    fn start_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        StripSequencer::start_at(self, instant)
+    }
+
+    fn restart_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        StripSequencer::restart_at(self, instant)
+    }
+
+    fn pause_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        StripSequencer::pause_at(self, instant)
+    }
+
+    fn resume_at(&mut self, instant: I) -> Result<(), SequencerError> {
+        StripSequencer::resume_at(self, instant)
+    }
+
+    fn service_if_running(&mut self) -> Result<ServiceTiming<I::Duration>, SequencerError> {
+        StripSequencer::service_if_running(self)
+    }
+
+    fn state(&self) -> SequencerState {
+        StripSequencer::state(self)
+    }
+}
+
+/// Up to `CAPACITY` borrowed sequencers of any type, controlled as one.
+///
+/// Members are addressed by the index `add()` returned. Every group operation is
+/// attempted on every member; failures are collected per index in an `ErrorReport`.
+pub struct SyncGroup<'m, I: TimeInstant, const CAPACITY: usize> {
+    members: Vec<&'m mut dyn SyncMember<I>, CAPACITY>,
+}
+
+impl<'m, I: TimeInstant, const CAPACITY: usize> SyncGroup<'m, I, CAPACITY> {
+    /// Creates an empty group.
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+
+    /// Registers `member` and returns its index.
+    ///
+    /// Returns `CollectionError::Full` if `CAPACITY` members are already registered.
+    pub fn add(&mut self, member: &'m mut dyn SyncMember<I>) -> Result<usize, CollectionError> {
+        self.members
+            .push(member)
+            .map_err(|_| CollectionError::Full)?;
+        Ok(self.members.len() - 1)
+    }
+
+    /// Returns the member at `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut (dyn SyncMember<I> + 'm)> {
+        self.members.get_mut(index).map(|member| &mut **member)
+    }
+
+    /// Returns the number of registered members.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns true if no member is registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Returns the maximum number of members.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Starts every member as if started at `instant`.
+    ///
+    /// Capture `instant` once (e.g. `time_source.now()`) so all members stay phase-aligned.
+    pub fn start_at(&mut self, instant: I) -> Result<(), ErrorReport<CAPACITY>> {
+        self.for_each(|member| member.start_at(instant))
+    }
+
+    /// Restarts every member from the beginning as if restarted at `instant`.
+    pub fn restart_at(&mut self, instant: I) -> Result<(), ErrorReport<CAPACITY>> {
+        self.for_each(|member| member.restart_at(instant))
+    }
+
+    /// Pauses every member as if paused at `instant`.
+    pub fn pause_at(&mut self, instant: I) -> Result<(), ErrorReport<CAPACITY>> {
+        self.for_each(|member| member.pause_at(instant))
+    }
+
+    /// Resumes every member as if resumed at `instant`.
+    pub fn resume_at(&mut self, instant: I) -> Result<(), ErrorReport<CAPACITY>> {
+        self.for_each(|member| member.resume_at(instant))
+    }
+
+    /// Services every running and held member and returns the most urgent timing hint.
+    ///
+    /// Returns `ServiceTiming::Complete` if no member is running or held. Every member is
+    /// serviced even if one fails; the first error is returned afterwards. Use
+    /// `service_all_lossy()` to get the timing hint and all errors.
+    pub fn service_all(&mut self) -> Result<ServiceTiming<I::Duration>, CollectionError> {
+        first_error(self.service_all_lossy())
+    }
+
+    /// Services every running and held member, collecting failures by index instead of
+    /// stopping.
+    pub fn service_all_lossy(&mut self) -> (ServiceTiming<I::Duration>, ErrorReport<CAPACITY>) {
+        let mut errors = ErrorReport::new();
+        let results = self
+            .members
+            .iter_mut()
+            .enumerate()
+            .map(|(index, member)| (index, member.service_if_running()));
+        let timing = merge_service_results(results, ServiceTiming::Complete, &mut errors);
+        (timing, errors)
+    }
+
+    /// Applies `operation` to every member, collecting failures by index.
+    fn for_each(
+        &mut self,
+        mut operation: impl FnMut(&mut dyn SyncMember<I>) -> Result<(), SequencerError>,
+    ) -> Result<(), ErrorReport<CAPACITY>> {
+        let mut errors = ErrorReport::new();
+
+        for (index, member) in self.members.iter_mut().enumerate() {
+            if let Err(error) = operation(&mut **member) {
+                // Cannot overflow: at most one entry per member
+                let _ = errors.push((index, error.into()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<I: TimeInstant, const CAPACITY: usize> Default for SyncGroup<'_, I, CAPACITY> {
+    /// Returns an empty group.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,205 @@
+//! Deterministic helpers for testing sequences (requires the `test-utils` feature).
+//!
+//! Provides millisecond time types, a manually advanced time source, a mock LED that
+//! records its color history, tolerant color comparisons and a golden-sample harness that
+//! plays a sequence at a fixed frame rate without real time passing. The `simulate`
+//! submodule runs a sequencer the way a main loop would, following its timing hints.
+
+use crate::sequence::RgbSequence;
+use crate::sequencer::{RgbLed, RgbSequencer, ServiceTiming};
+use crate::time::{TimeDuration, TimeInstant, TimeSource};
+use core::cell::Cell;
+use heapless::Vec;
+use palette::Srgb;
+
+pub mod simulate;
+
+/// Millisecond duration for tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TestDuration(pub u64);
+
+impl TimeDuration for TestDuration {
+    const ZERO: Self = TestDuration(0);
+
+    fn as_millis(&self) -> u64 {
+        self.0
+    }
+
+    fn from_millis(millis: u64) -> Self {
+        TestDuration(millis)
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        TestDuration(self.0.saturating_sub(other.0))
+    }
+}
+
+/// Millisecond instant for tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TestInstant(pub u64);
+
+impl TimeInstant for TestInstant {
+    type Duration = TestDuration;
+
+    fn duration_since(&self, earlier: Self) -> Self::Duration {
+        TestDuration(self.0.saturating_sub(earlier.0))
+    }
+
+    fn checked_add(self, duration: Self::Duration) -> Option<Self> {
+        self.0.checked_add(duration.0).map(TestInstant)
+    }
+
+    fn checked_sub(self, duration: Self::Duration) -> Option<Self> {
+        self.0.checked_sub(duration.0).map(TestInstant)
+    }
+}
+
+/// Time source that only moves when told to.
+#[derive(Debug)]
+pub struct MockTimeSource {
+    current_time: Cell<TestInstant>,
+}
+
+impl MockTimeSource {
+    /// Creates a time source starting at 0ms.
+    pub fn new() -> Self {
+        Self {
+            current_time: Cell::new(TestInstant(0)),
+        }
+    }
+
+    /// Advances time by `duration`.
+    pub fn advance(&self, duration: TestDuration) {
+        let current = self.current_time.get();
+        self.current_time.set(TestInstant(current.0 + duration.0));
+    }
+
+    /// Jumps to `time`.
+    pub fn set_time(&self, time: TestInstant) {
+        self.current_time.set(time);
+    }
+}
+
+impl Default for MockTimeSource {
+    /// Returns a time source starting at 0ms.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeSource<TestInstant> for MockTimeSource {
+    fn now(&self) -> TestInstant {
+        self.current_time.get()
+    }
+}
+
+/// LED that records every color written to it.
+///
+/// The history holds the first `HISTORY` writes; later writes still update the last
+/// color but are not recorded.
+#[derive(Debug, Clone)]
+pub struct MockLed<const HISTORY: usize = 32> {
+    current_color: Srgb,
+    color_history: Vec<Srgb, HISTORY>,
+}
+
+impl<const HISTORY: usize> MockLed<HISTORY> {
+    /// Creates an LED that is off and has no history.
+    pub fn new() -> Self {
+        Self {
+            current_color: Srgb::new(0.0, 0.0, 0.0),
+            color_history: Vec::new(),
+        }
+    }
+
+    /// Returns the last color written, or black if none was.
+    pub fn get_last_color(&self) -> Srgb {
+        self.current_color
+    }
+
+    /// Returns the recorded colors, oldest first.
+    pub fn color_history(&self) -> &[Srgb] {
+        &self.color_history
+    }
+
+    /// Forgets the recorded colors, keeping the last color.
+    pub fn clear_history(&mut self) {
+        self.color_history.clear();
+    }
+}
+
+impl<const HISTORY: usize> Default for MockLed<HISTORY> {
+    /// Returns an LED that is off and has no history.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const HISTORY: usize> RgbLed for MockLed<HISTORY> {
+    fn set_color(&mut self, color: Srgb) {
+        self.current_color = color;
+        let _ = self.color_history.push(color);
+    }
+}
+
+/// Tolerance used by `colors_equal()`.
+pub const COLOR_TOLERANCE: f32 = 0.001;
+
+/// Returns true if every channel of `a` and `b` differs by less than `COLOR_TOLERANCE`.
+pub fn colors_equal(a: Srgb, b: Srgb) -> bool {
+    colors_equal_epsilon(a, b, COLOR_TOLERANCE)
+}
+
+/// Returns true if every channel of `a` and `b` differs by less than `epsilon`.
+pub fn colors_equal_epsilon(a: Srgb, b: Srgb, epsilon: f32) -> bool {
+    (a.red - b.red).abs() < epsilon
+        && (a.green - b.green).abs() < epsilon
+        && (a.blue - b.blue).abs() < epsilon
+}
+
+/// LED that discards writes; the harness reads the sequencer's current color instead.
+struct NullLed;
+
+impl RgbLed for NullLed {
+    fn set_color(&mut self, _color: Srgb) {}
+}
+
+/// Plays `sequence` at a fixed frame rate and returns the LED color at every frame.
+///
+/// Frames are sampled at 0ms, `frame_interval`, 2 × `frame_interval`, ... up to and
+/// including `duration`, servicing on every frame regardless of timing hints. Stops early
+/// when the sequence completes (after recording the completion frame) or when `FRAMES`
+/// samples have been collected. The result is fully deterministic, so it can be compared
+/// against a stored golden sample. A zero `frame_interval` records a single frame.
+pub fn golden_samples<const N: usize, const FRAMES: usize>(
+    sequence: RgbSequence<TestDuration, N>,
+    frame_interval: TestDuration,
+    duration: TestDuration,
+) -> Vec<(TestDuration, Srgb), FRAMES> {
+    let clock = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, NullLed, MockTimeSource, N>::new(NullLed, &clock);
+    let mut samples = Vec::new();
+
+    if sequencer.load_and_start(sequence).is_err() {
+        return samples;
+    }
+
+    let mut time = TestDuration::ZERO;
+    loop {
+        clock.set_time(TestInstant(time.0));
+        let complete = !matches!(
+            sequencer.service(),
+            Ok(ServiceTiming::Continuous | ServiceTiming::Delay(_))
+        );
+
+        if samples.push((time, sequencer.current_color())).is_err()
+            || complete
+            || frame_interval.0 == 0
+            || time.0 + frame_interval.0 > duration.0
+        {
+            return samples;
+        }
+        time = TestDuration(time.0 + frame_interval.0);
+    }
+}
@@ -0,0 +1,151 @@
+//! Deterministic main-loop simulation.
+//!
+//! `run_for()` drives a sequencer the way a firmware main loop does: service, then wait
+//! for exactly as long as the returned `ServiceTiming` asks. Waiting moves a
+//! `MockTimeSource` instead of sleeping, so every service lands precisely on a step
+//! boundary or frame and the run is fully reproducible.
+
+use super::{MockTimeSource, TestDuration, TestInstant};
+use crate::sequencer::{
+    Position, RgbLed, RgbSequencer, SequencerError, SequencerState, ServiceTiming,
+};
+use crate::time::{TimeInstant, TimeSource};
+use heapless::Vec;
+use palette::Srgb;
+
+/// Something that happened during a simulated run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimEvent {
+    /// The playback position changed (reported at the first service that saw it).
+    StepChanged {
+        /// Time of the service call.
+        at: TestInstant,
+        /// New playback position.
+        position: Position,
+    },
+    /// The sequence completed; the run ends here.
+    Completed {
+        /// Time of the service call.
+        at: TestInstant,
+    },
+    /// Starting or servicing the sequencer failed; the run ends here.
+    Failed {
+        /// Time of the failed call.
+        at: TestInstant,
+        /// Error returned by the sequencer.
+        error: SequencerError,
+    },
+}
+
+/// Result of `run_for()`.
+///
+/// Events and samples beyond the `EVENTS` and `SAMPLES` capacities are dropped and flagged
+/// in `truncated`; the simulation itself keeps going until the end of the window.
+#[derive(Debug, Clone)]
+pub struct Simulation<const EVENTS: usize, const SAMPLES: usize> {
+    /// Position changes, completion and errors, in order.
+    pub events: Vec<SimEvent, EVENTS>,
+    /// Time of every service call and the color it produced.
+    pub samples: Vec<(TestInstant, Srgb), SAMPLES>,
+    /// When the next service would have been due, or `None` if the run ended because the
+    /// sequencer stopped, completed or failed.
+    pub next_service: Option<TestInstant>,
+    /// True if any event or sample did not fit.
+    pub truncated: bool,
+}
+
+impl<const EVENTS: usize, const SAMPLES: usize> Simulation<EVENTS, SAMPLES> {
+    fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            samples: Vec::new(),
+            next_service: None,
+            truncated: false,
+        }
+    }
+
+    fn event(&mut self, event: SimEvent) {
+        self.truncated |= self.events.push(event).is_err();
+    }
+
+    fn sample(&mut self, at: TestInstant, color: Srgb) {
+        self.truncated |= self.samples.push((at, color)).is_err();
+    }
+}
+
+/// Runs `sequencer` for `total` of mock time, servicing it exactly like a main loop.
+///
+/// Starts a `Loaded` sequencer first. Each service is followed by a jump of `clock` to the
+/// next deadline: the hinted delay for `ServiceTiming::Delay`, or `frame_interval` for
+/// `ServiceTiming::Continuous`. Deadlines are at least 1ms apart so zero delays and a zero
+/// `frame_interval` cannot stall the run. Services happen at every deadline up to and
+/// including `clock.now() + total`; afterwards the clock is left at the end of the window,
+/// so consecutive runs continue where the previous one stopped.
+///
+/// `clock` must be the time source the sequencer was created with.
+pub fn run_for<L, const N: usize, const EVENTS: usize, const SAMPLES: usize>(
+    sequencer: &mut RgbSequencer<'_, TestInstant, L, MockTimeSource, N>,
+    clock: &MockTimeSource,
+    total: TestDuration,
+    frame_interval: TestDuration,
+) -> Simulation<EVENTS, SAMPLES>
+where
+    L: RgbLed,
+{
+    let mut simulation = Simulation::new();
+    let end = TestInstant(clock.now().0.saturating_add(total.0));
+    let mut position = sequencer.current_position();
+
+    if sequencer.state() == SequencerState::Loaded
+        && let Err(error) = sequencer.start()
+    {
+        simulation.event(SimEvent::Failed {
+            at: clock.now(),
+            error,
+        });
+        return simulation;
+    }
+
+    while sequencer.is_running() {
+        let now = clock.now();
+        let timing = match sequencer.service() {
+            Ok(timing) => timing,
+            Err(error) => {
+                simulation.event(SimEvent::Failed { at: now, error });
+                break;
+            }
+        };
+        simulation.sample(now, sequencer.current_color());
+
+        let current = sequencer.current_position();
+        if current != position {
+            if let Some(new_position) = current {
+                simulation.event(SimEvent::StepChanged {
+                    at: now,
+                    position: new_position,
+                });
+            }
+            position = current;
+        }
+
+        let wait = match timing {
+            ServiceTiming::Continuous => frame_interval,
+            ServiceTiming::Delay(delay) => delay,
+            ServiceTiming::Complete => {
+                simulation.event(SimEvent::Completed { at: now });
+                break;
+            }
+        };
+        let next = now
+            .checked_add(TestDuration(wait.0.max(1)))
+            .unwrap_or(TestInstant(u64::MAX));
+        if next > end {
+            simulation.next_service = Some(next);
+            break;
+        }
+        clock.set_time(next);
+    }
+
+    clock.set_time(end);
+    simulation
+}
@@ -22,9 +22,11 @@ pub trait TimeDuration: Copy + PartialEq {
 }
 
 /// Trait abstraction for instant types.
-pub trait TimeInstant: Copy {
-    /// Duration type for this instant.
-    type Duration: TimeDuration;
+///
+/// Instants are ordered by time, so later instants compare greater.
+pub trait TimeInstant: Copy + PartialOrd {
+    /// Duration type for this instant (a plain value without borrowed data).
+    type Duration: TimeDuration + 'static;
 
     /// Calculates duration since an earlier instant.
     fn duration_since(&self, earlier: Self) -> Self::Duration;
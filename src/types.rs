@@ -23,6 +23,10 @@ pub enum TransitionStyle {
 
     /// Quadratic ease-out-in: fast start and end, slow middle. Requires non-zero duration.
     EaseOutIn,
+
+    /// Catmull-Rom spline through neighboring step colors, avoiding corners at step
+    /// boundaries. Requires non-zero duration.
+    CatmullRom,
 }
 
 /// How many times a sequence should repeat.
@@ -43,7 +47,7 @@ impl Default for LoopCount {
 }
 
 /// A single step in an RGB sequence.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SequenceStep<D: TimeDuration> {
     /// Target color (0.0-1.0 range).
     pub color: Srgb,
@@ -69,6 +73,7 @@ impl<D: TimeDuration> SequenceStep<D> {
 
 /// Sequence validation errors.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum SequenceError {
     /// No steps provided.
     EmptySequence,
@@ -84,6 +89,24 @@ pub enum SequenceError {
 
     /// Landing color set with infinite loop count.
     LandingColorWithInfiniteLoop,
+
+    /// End hold set with infinite loop count.
+    EndHoldWithInfiniteLoop,
+
+    /// Loop start index leaves no steps in the looping section.
+    LoopStartOutOfRange,
+
+    /// Infinite looping section after an intro has zero duration.
+    ZeroDurationLoop,
+
+    /// Step duration does not fit in compact `u16` millisecond storage.
+    DurationOutOfRange,
+
+    /// Palette-indexed step refers to a color the palette does not have.
+    PaletteIndexOutOfRange,
+
+    /// Function-based sequence has no steps to store.
+    FunctionBased,
 }
 
 impl core::fmt::Display for SequenceError {
@@ -114,6 +137,30 @@ impl core::fmt::Display for SequenceError {
                     "landing_color only applies to finite sequences (infinite loops never complete)"
                 )
             }
+            SequenceError::EndHoldWithInfiniteLoop => {
+                write!(
+                    f,
+                    "end_hold only applies to finite sequences (infinite loops never complete)"
+                )
+            }
+            SequenceError::LoopStartOutOfRange => {
+                write!(f, "loop_from index must leave at least one step to loop")
+            }
+            SequenceError::ZeroDurationLoop => {
+                write!(
+                    f,
+                    "an infinite loop after an intro must have a non-zero duration"
+                )
+            }
+            SequenceError::DurationOutOfRange => {
+                write!(f, "compact step durations are limited to 65535 ms")
+            }
+            SequenceError::PaletteIndexOutOfRange => {
+                write!(f, "step color index is outside the palette")
+            }
+            SequenceError::FunctionBased => {
+                write!(f, "function-based sequences have no steps to store")
+            }
         }
     }
 }
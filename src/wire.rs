@@ -0,0 +1,311 @@
+//! Byte-level command protocol for remote control over serial, radio or network links.
+//!
+//! Each frame addresses one LED of a `SequencerCollection` (or all of them) and carries one
+//! `SequencerAction`:
+//!
+//! ```text
+//! [led_id: u8] [opcode: u8] [payload...]
+//! ```
+//!
+//! `led_id` is the collection slot, or `BROADCAST_ID` for every present LED. Multi-byte
+//! values are little-endian. The protocol does not delimit frames itself; pass one
+//! complete frame per call, as delivered by the transport (a UDP datagram, a CAN message,
+//! a SLIP or COBS-decoded serial packet).
+//!
+//! | Opcode | Action | Payload |
+//! |--------|--------|---------|
+//! | `0x01` | `Start` | - |
+//! | `0x02` | `Stop` | - |
+//! | `0x03` | `Pause` | - |
+//! | `0x04` | `Resume` | - |
+//! | `0x05` | `Hold` | - |
+//! | `0x06` | `Restart` | - |
+//! | `0x07` | `Clear` | - |
+//! | `0x08` | `Seek` | position in milliseconds (`u32`) |
+//! | `0x09` | `SetBrightness` | brightness, 0-255 for 0.0-1.0 (`u8`) |
+//! | `0x0A` | `SetSpeed` | speed in hundredths, 100 = 1.0x (`u16`) |
+//! | `0x10` | `Load` | loop count (`u8`, 0 = infinite), then steps |
+//! | `0x11` | `LoadAndStart` | as `Load` |
+//!
+//! Each step is 6 bytes: red, green, blue (`u8` each), duration in milliseconds (`u16`)
+//! and a transition code (0 = `Step`, 1 = `Linear`, 2 = `EaseIn`, 3 = `EaseOut`,
+//! 4 = `EaseInOut`, 5 = `EaseOutIn`, 6 = `CatmullRom`). Only steps and the loop count
+//! travel over the wire, the same subset `CompactSequence` stores; `encode()` refuses
+//! sequences with any other setting rather than send a different animation.
+
+use crate::collection::CollectionError;
+use crate::colors::{rgb8, to_u32};
+use crate::command::SequencerAction;
+use crate::compact::{CompactSequence, CompactStep};
+use crate::sequence::RgbSequence;
+use crate::time::TimeDuration;
+use crate::types::{LoopCount, SequenceError, TransitionStyle};
+use heapless::Vec;
+
+/// LED ID that addresses every present LED of a collection.
+pub const BROADCAST_ID: u8 = 0xFF;
+
+/// Size of an encoded step in bytes.
+pub const STEP_SIZE: usize = 6;
+
+/// Errors that can occur when encoding, decoding or routing a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WireError {
+    /// Frame is shorter than its header or its opcode's payload.
+    Truncated,
+    /// Opcode is not part of the protocol.
+    UnknownOpcode(u8),
+    /// Payload has the wrong length or contains an unknown transition code.
+    InvalidPayload,
+    /// Received steps do not form a valid sequence, or a sequence cannot be encoded.
+    InvalidSequence(SequenceError),
+    /// Sequence is function-based or uses settings the protocol cannot carry.
+    UnsupportedSequence,
+    /// Finite loop count is 0 or above 255 and cannot be encoded.
+    LoopCountOutOfRange,
+    /// Output buffer is too small for the encoded frame.
+    BufferTooSmall,
+    /// The decoded command was rejected by the collection.
+    Collection(CollectionError),
+}
+
+impl From<CollectionError> for WireError {
+    /// Wraps a collection error.
+    fn from(error: CollectionError) -> Self {
+        WireError::Collection(error)
+    }
+}
+
+impl From<SequenceError> for WireError {
+    /// Wraps a sequence error.
+    fn from(error: SequenceError) -> Self {
+        WireError::InvalidSequence(error)
+    }
+}
+
+impl core::fmt::Display for WireError {
+    /// Formats the error for display.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WireError::Truncated => write!(f, "frame is truncated"),
+            WireError::UnknownOpcode(opcode) => write!(f, "unknown opcode 0x{:02X}", opcode),
+            WireError::InvalidPayload => write!(f, "invalid frame payload"),
+            WireError::InvalidSequence(error) => write!(f, "invalid sequence: {}", error),
+            WireError::UnsupportedSequence => {
+                write!(f, "sequence has settings the wire format cannot carry")
+            }
+            WireError::LoopCountOutOfRange => {
+                write!(f, "loop count must be 1-255 or infinite")
+            }
+            WireError::BufferTooSmall => write!(f, "buffer too small for frame"),
+            WireError::Collection(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+const START: u8 = 0x01;
+const STOP: u8 = 0x02;
+const PAUSE: u8 = 0x03;
+const RESUME: u8 = 0x04;
+const HOLD: u8 = 0x05;
+const RESTART: u8 = 0x06;
+const CLEAR: u8 = 0x07;
+const SEEK: u8 = 0x08;
+const SET_BRIGHTNESS: u8 = 0x09;
+const SET_SPEED: u8 = 0x0A;
+const LOAD: u8 = 0x10;
+const LOAD_AND_START: u8 = 0x11;
+
+/// Decodes a frame into the addressed LED ID and its action.
+///
+/// The LED ID is returned as sent; compare it with `BROADCAST_ID` before routing.
+pub fn decode<D: TimeDuration, const N: usize>(
+    frame: &[u8],
+) -> Result<(u8, SequencerAction<D, N>), WireError> {
+    let [led_id, opcode, payload @ ..] = frame else {
+        return Err(WireError::Truncated);
+    };
+
+    let action = match *opcode {
+        START | STOP | PAUSE | RESUME | HOLD | RESTART | CLEAR if !payload.is_empty() => {
+            return Err(WireError::InvalidPayload);
+        }
+        START => SequencerAction::Start,
+        STOP => SequencerAction::Stop,
+        PAUSE => SequencerAction::Pause,
+        RESUME => SequencerAction::Resume,
+        HOLD => SequencerAction::Hold,
+        RESTART => SequencerAction::Restart,
+        CLEAR => SequencerAction::Clear,
+        SEEK => {
+            let millis = u32::from_le_bytes(fixed(payload)?);
+            SequencerAction::Seek(D::from_millis(millis as u64))
+        }
+        SET_BRIGHTNESS => {
+            let [level] = fixed(payload)?;
+            SequencerAction::SetBrightness(level as f32 / 255.0)
+        }
+        SET_SPEED => {
+            let hundredths = u16::from_le_bytes(fixed(payload)?);
+            SequencerAction::SetSpeed(hundredths as f32 / 100.0)
+        }
+        LOAD => SequencerAction::Load(decode_sequence(payload)?.to_sequence()?),
+        LOAD_AND_START => SequencerAction::LoadAndStart(decode_sequence(payload)?.to_sequence()?),
+        other => return Err(WireError::UnknownOpcode(other)),
+    };
+    Ok((*led_id, action))
+}
+
+/// Encodes `action` for `led_id` into `buffer` and returns the frame length.
+///
+/// Sequences travel as their steps and loop count only. Returns
+/// `WireError::UnsupportedSequence` for function-based sequences and for any other setting
+/// (`loop_from`, start or landing color, start delay, end hold, brightness),
+/// `WireError::InvalidSequence` for steps longer than 65535 ms,
+/// `WireError::LoopCountOutOfRange` for finite loop counts above 255, and
+/// `WireError::BufferTooSmall` if the frame does not fit.
+pub fn encode<D: TimeDuration, const N: usize>(
+    led_id: u8,
+    action: &SequencerAction<D, N>,
+    buffer: &mut [u8],
+) -> Result<usize, WireError> {
+    let mut writer = Writer { buffer, len: 0 };
+    writer.put(&[led_id])?;
+
+    match action {
+        SequencerAction::Start => writer.put(&[START])?,
+        SequencerAction::Stop => writer.put(&[STOP])?,
+        SequencerAction::Pause => writer.put(&[PAUSE])?,
+        SequencerAction::Resume => writer.put(&[RESUME])?,
+        SequencerAction::Hold => writer.put(&[HOLD])?,
+        SequencerAction::Restart => writer.put(&[RESTART])?,
+        SequencerAction::Clear => writer.put(&[CLEAR])?,
+        SequencerAction::Seek(position) => {
+            let millis = u32::try_from(position.as_millis()).unwrap_or(u32::MAX);
+            writer.put(&[SEEK])?;
+            writer.put(&millis.to_le_bytes())?;
+        }
+        SequencerAction::SetBrightness(brightness) => {
+            let level = (brightness.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+            writer.put(&[SET_BRIGHTNESS, level])?;
+        }
+        SequencerAction::SetSpeed(speed) => {
+            let hundredths = (speed * 100.0 + 0.5) as u16;
+            writer.put(&[SET_SPEED])?;
+            writer.put(&hundredths.to_le_bytes())?;
+        }
+        SequencerAction::Load(sequence) | SequencerAction::LoadAndStart(sequence) => {
+            let opcode = match action {
+                SequencerAction::Load(_) => LOAD,
+                _ => LOAD_AND_START,
+            };
+            if !fits_wire(sequence) {
+                return Err(WireError::UnsupportedSequence);
+            }
+            let compact = CompactSequence::from_sequence(sequence)?;
+            let loops = match compact.loop_count() {
+                LoopCount::Infinite => 0,
+                LoopCount::Finite(count @ 1..=255) => count as u8,
+                LoopCount::Finite(_) => return Err(WireError::LoopCountOutOfRange),
+            };
+            writer.put(&[opcode, loops])?;
+            for step in compact.steps() {
+                let [_, red, green, blue] = to_u32(step.color).to_be_bytes();
+                let [low, high] = step.millis.to_le_bytes();
+                writer.put(&[
+                    red,
+                    green,
+                    blue,
+                    low,
+                    high,
+                    transition_code(step.transition),
+                ])?;
+            }
+        }
+    }
+    Ok(writer.len)
+}
+
+/// Returns true if `sequence` consists of nothing but steps and a loop count.
+fn fits_wire<D: TimeDuration, const N: usize>(sequence: &RgbSequence<D, N>) -> bool {
+    !sequence.is_function_based()
+        && sequence.loop_start() == 0
+        && sequence.start_color().is_none()
+        && sequence.landing_color().is_none()
+        && sequence.start_delay() == D::ZERO
+        && sequence.end_hold() == D::ZERO
+        && sequence.brightness() == 1.0
+}
+
+/// Returns the payload as a fixed-size array, or `InvalidPayload` on length mismatch.
+fn fixed<const LEN: usize>(payload: &[u8]) -> Result<[u8; LEN], WireError> {
+    payload.try_into().map_err(|_| WireError::InvalidPayload)
+}
+
+/// Decodes a loop count byte followed by steps.
+fn decode_sequence<const N: usize>(payload: &[u8]) -> Result<CompactSequence<N>, WireError> {
+    let [loops, steps @ ..] = payload else {
+        return Err(WireError::Truncated);
+    };
+    if steps.len() % STEP_SIZE != 0 {
+        return Err(WireError::InvalidPayload);
+    }
+
+    let loop_count = match loops {
+        0 => LoopCount::Infinite,
+        count => LoopCount::Finite(*count as u32),
+    };
+    let mut compact = Vec::<CompactStep, N>::new();
+    for step in steps.chunks_exact(STEP_SIZE) {
+        let transition = match step[5] {
+            0 => TransitionStyle::Step,
+            1 => TransitionStyle::Linear,
+            2 => TransitionStyle::EaseIn,
+            3 => TransitionStyle::EaseOut,
+            4 => TransitionStyle::EaseInOut,
+            5 => TransitionStyle::EaseOutIn,
+            6 => TransitionStyle::CatmullRom,
+            _ => return Err(WireError::InvalidPayload),
+        };
+        compact
+            .push(CompactStep::new(
+                rgb8(step[0], step[1], step[2]),
+                u16::from_le_bytes([step[3], step[4]]),
+                transition,
+            ))
+            .map_err(|_| SequenceError::CapacityExceeded)?;
+    }
+    Ok(CompactSequence::from_steps(&compact, loop_count)?)
+}
+
+/// Returns the wire code of a transition style.
+fn transition_code(transition: TransitionStyle) -> u8 {
+    match transition {
+        TransitionStyle::Step => 0,
+        TransitionStyle::Linear => 1,
+        TransitionStyle::EaseIn => 2,
+        TransitionStyle::EaseOut => 3,
+        TransitionStyle::EaseInOut => 4,
+        TransitionStyle::EaseOutIn => 5,
+        TransitionStyle::CatmullRom => 6,
+    }
+}
+
+/// Appends bytes to a caller-supplied buffer.
+struct Writer<'b> {
+    buffer: &'b mut [u8],
+    len: usize,
+}
+
+impl Writer<'_> {
+    fn put(&mut self, bytes: &[u8]) -> Result<(), WireError> {
+        let end = self.len + bytes.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(WireError::BufferTooSmall)?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+//! Integration tests for the APA102 adapter (requires the `embedded-hal-1` feature)
+
+#![cfg(feature = "embedded-hal-1")]
+
+use embedded_hal_1::spi::{ErrorKind, ErrorType, SpiBus};
+use palette::Srgb;
+use rgb_sequencer::{Apa102Brightness, Apa102Led, RgbLed};
+
+/// Mock SPI bus that records the last write; reads and transfers are no-ops
+#[derive(Default)]
+struct MockSpi {
+    written: [u8; 12],
+    writes: u32,
+    fail: bool,
+}
+
+impl ErrorType for MockSpi {
+    type Error = ErrorKind;
+}
+
+impl SpiBus for MockSpi {
+    fn read(&mut self, _words: &mut [u8]) -> Result<(), ErrorKind> {
+        Ok(())
+    }
+
+    fn write(&mut self, words: &[u8]) -> Result<(), ErrorKind> {
+        if self.fail {
+            return Err(ErrorKind::Other);
+        }
+        self.written.copy_from_slice(words);
+        self.writes += 1;
+        Ok(())
+    }
+
+    fn transfer(&mut self, _read: &mut [u8], _write: &[u8]) -> Result<(), ErrorKind> {
+        Ok(())
+    }
+
+    fn transfer_in_place(&mut self, _words: &mut [u8]) -> Result<(), ErrorKind> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), ErrorKind> {
+        Ok(())
+    }
+}
+
+#[test]
+fn set_color_writes_start_led_and_end_frames() {
+    let mut led = Apa102Led::new(MockSpi::default());
+    led.set_color(Srgb::new(1.0, 0.5, 0.0));
+
+    let spi = led.into_spi();
+    assert_eq!(spi.writes, 1);
+    assert_eq!(
+        spi.written,
+        [0, 0, 0, 0, 0xE0 | 31, 0, 128, 255, 0, 0, 0, 0]
+    );
+}
+
+#[test]
+fn led_frames_per_brightness_mode() {
+    let cases = [
+        // (mode, color, expected frame)
+        (
+            Apa102Brightness::Fixed(31),
+            Srgb::new(1.0, 0.5, 0.0),
+            [0xFF, 0, 128, 255],
+        ),
+        (
+            Apa102Brightness::Fixed(10),
+            Srgb::new(0.1, 0.0, 0.0),
+            [0xEA, 0, 0, 26],
+        ),
+        (
+            Apa102Brightness::Fixed(200),
+            Srgb::new(0.0, 0.0, 1.0),
+            [0xFF, 255, 0, 0],
+        ),
+        (
+            Apa102Brightness::Adaptive,
+            Srgb::new(0.0, 0.0, 0.0),
+            [0xE0, 0, 0, 0],
+        ),
+        (
+            Apa102Brightness::Adaptive,
+            Srgb::new(1.0, 1.0, 1.0),
+            [0xFF, 255, 255, 255],
+        ),
+        // 0.1 needs level 4 (4/31 >= 0.1); channels rescale by 31/4
+        (
+            Apa102Brightness::Adaptive,
+            Srgb::new(0.1, 0.05, 0.0),
+            [0xE4, 0, 99, 198],
+        ),
+        // Exactly 10/31 uses level 10 and a full-scale channel
+        (
+            Apa102Brightness::Adaptive,
+            Srgb::new(10.0 / 31.0, 0.0, 0.0),
+            [0xEA, 0, 0, 255],
+        ),
+    ];
+
+    for (mode, color, expected) in cases {
+        let led = Apa102Led::new(MockSpi::default()).with_brightness(mode);
+        assert_eq!(led.led_frame(color), expected, "{mode:?} {color:?}");
+    }
+}
+
+#[test]
+fn failed_writes_are_counted() {
+    let mut led = Apa102Led::new(MockSpi {
+        fail: true,
+        ..MockSpi::default()
+    });
+
+    led.set_color(Srgb::new(1.0, 0.0, 0.0));
+    led.set_color(Srgb::new(0.0, 1.0, 0.0));
+    assert_eq!(led.error_count(), 2);
+}
@@ -0,0 +1,990 @@
+//! Integration tests for SequencerCollection
+
+mod common;
+use common::*;
+
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::{LoopCount, TransitionStyle};
+use rgb_sequencer::{
+    CollectionError, RgbLed, SequencerAction, SequencerCollection, SequencerCommand,
+    SequencerState, ServiceTiming,
+};
+
+type TestCollection<'t, const CAPACITY: usize> =
+    SequencerCollection<'t, TestInstant, MockLed, MockTimeSource, 4, CAPACITY>;
+
+fn hold(color: palette::Srgb, millis: u64) -> RgbSequence<TestDuration, 4> {
+    RgbSequence::<TestDuration, 4>::builder()
+        .step(color, TestDuration(millis), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap()
+}
+
+fn start(collection: &mut TestCollection<'_, 4>, id: usize, color: palette::Srgb, millis: u64) {
+    collection
+        .handle_command(SequencerCommand::new(
+            id,
+            SequencerAction::Load(hold(color, millis)),
+        ))
+        .unwrap();
+    collection
+        .handle_command(SequencerCommand::new(id, SequencerAction::Start))
+        .unwrap();
+}
+
+#[test]
+fn add_sequencer_assigns_sequential_ids_until_full() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<2>::new(&timer);
+
+    assert!(collection.is_empty());
+    assert_eq!(collection.add_sequencer(MockLed::new()).unwrap(), 0);
+    assert_eq!(collection.add_sequencer(MockLed::new()).unwrap(), 1);
+    assert_eq!(collection.len(), 2);
+}
+
+#[test]
+fn add_sequencer_returns_led_when_full() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<1>::new(&timer);
+    collection.add_sequencer(MockLed::new()).unwrap();
+
+    let mut led = MockLed::new();
+    led.set_color(RED);
+
+    let (error, led) = collection.add_sequencer(led).unwrap_err();
+    assert_eq!(error, CollectionError::Full);
+    // LED was handed back untouched
+    assert_eq!(led.color_history().len(), 1);
+    assert!(colors_equal(led.get_last_color(), RED));
+}
+
+#[test]
+fn capacity_and_free_slots_track_occupancy() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<3>::new(&timer);
+
+    assert_eq!(collection.capacity(), 3);
+    assert_eq!(collection.free_slots(), 3);
+
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+    assert_eq!(collection.free_slots(), 1);
+
+    collection.remove(0).unwrap();
+    assert_eq!(collection.free_slots(), 2);
+    assert_eq!(collection.capacity(), 3);
+}
+
+#[test]
+fn remove_returns_led_and_frees_slot() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+    start(&mut collection, 1, RED, 100);
+    collection.service_all().unwrap();
+
+    let led = collection.remove(1).unwrap();
+    assert!(colors_equal(led.get_last_color(), RED));
+    assert_eq!(collection.len(), 2);
+
+    // Removed ID is no longer addressable, other IDs are unchanged
+    assert_eq!(
+        collection.remove(1).err(),
+        Some(CollectionError::InvalidId(1))
+    );
+    assert!(collection.get_sequencer(1).is_err());
+    assert!(collection.get_sequencer(2).is_ok());
+
+    // Freed slot is reused
+    assert_eq!(collection.add_sequencer(MockLed::new()).unwrap(), 1);
+    assert_eq!(
+        collection.get_sequencer(1).unwrap().state(),
+        SequencerState::Idle
+    );
+}
+
+#[test]
+fn remove_rejects_out_of_range_id() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    assert_eq!(
+        collection.remove(7).err(),
+        Some(CollectionError::InvalidId(7))
+    );
+}
+
+#[test]
+fn service_all_returns_most_urgent_timing() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    assert_eq!(collection.service_all(), Ok(ServiceTiming::Complete));
+
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+    start(&mut collection, 0, RED, 300);
+    start(&mut collection, 1, GREEN, 100);
+
+    assert_eq!(
+        collection.service_all(),
+        Ok(ServiceTiming::Delay(TestDuration(100)))
+    );
+    assert!(colors_equal(
+        collection.get_sequencer(0).unwrap().current_color(),
+        RED
+    ));
+}
+
+#[test]
+fn service_all_detailed_reports_each_led() {
+    let timer = MockTimeSource::new();
+    let mut collection: SequencerCollection<'_, TestInstant, MockLed, MockTimeSource, 4, 4, 4> =
+        SequencerCollection::new(&timer);
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    for (id, millis) in [(0, 300), (1, 100)] {
+        collection
+            .handle_command(SequencerCommand::new(
+                id,
+                SequencerAction::LoadAndStart(hold(RED, millis)),
+            ))
+            .unwrap();
+    }
+    collection
+        .handle_command(SequencerCommand::new(
+            2,
+            SequencerAction::Load(hold(BLUE, 100)),
+        ))
+        .unwrap();
+    collection
+        .handle_command(SequencerCommand::at(
+            2,
+            SequencerAction::Start,
+            TestInstant(250),
+        ))
+        .unwrap();
+
+    // LED 2 waits for its scheduled start, slot 3 is free
+    let (timings, errors) = collection.service_all_detailed();
+    assert!(errors.is_empty());
+    assert_eq!(
+        timings,
+        [
+            Some(ServiceTiming::Delay(TestDuration(300))),
+            Some(ServiceTiming::Delay(TestDuration(100))),
+            Some(ServiceTiming::Delay(TestDuration(250))),
+            None,
+        ]
+    );
+
+    // A failing scheduled command is reported without hiding the other LEDs' hints
+    collection
+        .handle_command(SequencerCommand::at(
+            1,
+            SequencerAction::Resume,
+            TestInstant(250),
+        ))
+        .unwrap();
+    timer.set_time(TestInstant(250));
+    let (timings, errors) = collection.service_all_detailed();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 1);
+    assert_eq!(
+        timings,
+        [
+            Some(ServiceTiming::Delay(TestDuration(50))),
+            Some(ServiceTiming::Delay(TestDuration(50))),
+            Some(ServiceTiming::Delay(TestDuration(100))),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn handle_command_reports_sequencer_errors() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+    collection.add_sequencer(MockLed::new()).unwrap();
+
+    let result = collection.handle_command(SequencerCommand::new(0, SequencerAction::Start));
+    assert!(matches!(result, Err(CollectionError::Sequencer(_))));
+
+    let result = collection.handle_command(SequencerCommand::new(3, SequencerAction::Start));
+    assert_eq!(result, Err(CollectionError::InvalidId(3)));
+}
+
+#[test]
+fn broadcast_applies_action_to_every_sequencer() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    // Sparse IDs: slot 1 removed
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    collection.remove(1).unwrap();
+
+    collection
+        .broadcast(SequencerAction::Load(hold(RED, 100)))
+        .unwrap();
+    collection.broadcast(SequencerAction::Start).unwrap();
+
+    for id in [0, 2] {
+        assert_eq!(
+            collection.get_sequencer(id).unwrap().state(),
+            SequencerState::Running
+        );
+    }
+}
+
+#[test]
+fn broadcast_attempts_all_and_reports_failures() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    start(&mut collection, 1, RED, 100);
+
+    let errors = collection.broadcast(SequencerAction::Pause).unwrap_err();
+    let failed_ids: heapless::Vec<usize, 4> = errors.iter().map(|(id, _)| *id).collect();
+    assert_eq!(failed_ids.as_slice(), &[0, 2]);
+
+    // LED 1 was still paused despite the other failures
+    assert!(collection.get_sequencer(1).unwrap().is_paused());
+}
+
+#[test]
+fn group_commands_only_affect_members() {
+    const FRONT_PANEL: u32 = 1 << 0;
+    const REAR_PANEL: u32 = 1 << 1;
+
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..4 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    collection.set_groups(0, FRONT_PANEL).unwrap();
+    collection.set_groups(1, FRONT_PANEL | REAR_PANEL).unwrap();
+    collection.set_groups(2, REAR_PANEL).unwrap();
+    // LED 3 is the independent status LED
+
+    assert_eq!(collection.groups(1), Ok(FRONT_PANEL | REAR_PANEL));
+    assert_eq!(collection.groups(3), Ok(0));
+
+    collection
+        .handle_group_command(FRONT_PANEL, SequencerAction::Load(hold(RED, 100)))
+        .unwrap();
+
+    let states = [0, 1, 2, 3].map(|id| collection.get_sequencer(id).unwrap().state());
+    assert_eq!(
+        states,
+        [
+            SequencerState::Loaded,
+            SequencerState::Loaded,
+            SequencerState::Idle,
+            SequencerState::Idle
+        ]
+    );
+
+    // Errors are reported per member
+    let errors = collection
+        .handle_group_command(REAR_PANEL, SequencerAction::Start)
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 2);
+}
+
+#[test]
+fn group_membership_requires_present_led() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    assert_eq!(
+        collection.set_groups(0, 1),
+        Err(CollectionError::InvalidId(0))
+    );
+    assert_eq!(collection.groups(9), Err(CollectionError::InvalidId(9)));
+
+    // Re-added slot starts without membership
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.set_groups(0, 1).unwrap();
+    collection.remove(0).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+    assert_eq!(collection.groups(0), Ok(0));
+}
+
+#[test]
+fn start_all_synced_shares_one_start_instant() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    collection
+        .broadcast(SequencerAction::Load(hold(RED, 100)))
+        .unwrap();
+
+    timer.set_time(TestInstant(500));
+    collection.start_all_synced(&[0, 2]).unwrap();
+    timer.advance(TestDuration(40));
+
+    assert_eq!(
+        collection.get_sequencer(0).unwrap().elapsed_time(),
+        Some(TestDuration(40))
+    );
+    assert_eq!(
+        collection.get_sequencer(2).unwrap().elapsed_time(),
+        Some(TestDuration(40))
+    );
+    assert_eq!(
+        collection.get_sequencer(1).unwrap().state(),
+        SequencerState::Loaded
+    );
+}
+
+#[test]
+fn start_all_synced_reports_invalid_ids_and_states() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection
+        .handle_command(SequencerCommand::new(
+            0,
+            SequencerAction::Load(hold(RED, 100)),
+        ))
+        .unwrap();
+
+    let errors = collection.start_all_synced(&[0, 1, 3]).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], (1, CollectionError::Sequencer(_))));
+    assert_eq!(errors[1], (3, CollectionError::InvalidId(3)));
+    assert!(collection.get_sequencer(0).unwrap().is_running());
+}
+
+#[test]
+fn start_all_staggered_delays_each_led_by_offset() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..4 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    collection.remove(1).unwrap();
+    let chase = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(50), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(50), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(50), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    // Right after boot the timer cannot go back by the stagger
+    for now in [0, 1000] {
+        collection
+            .broadcast(SequencerAction::Load(chase.clone()))
+            .unwrap();
+        timer.set_time(TestInstant(now));
+        collection.start_all_staggered(TestDuration(50)).unwrap();
+        collection.service_all().unwrap();
+
+        let colors = [0, 2, 3].map(|id| collection.get_sequencer(id).unwrap().current_color());
+        for (actual, expected) in colors.into_iter().zip([BLUE, GREEN, RED]) {
+            assert!(colors_equal(actual, expected), "at {}: {:?}", now, colors);
+        }
+        assert_eq!(
+            collection.get_sequencer(3).unwrap().elapsed_time(),
+            Some(TestDuration(0))
+        );
+    }
+}
+
+#[test]
+fn start_all_staggered_reports_sequencers_not_loaded() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection
+        .handle_command(SequencerCommand::new(
+            1,
+            SequencerAction::Load(hold(RED, 100)),
+        ))
+        .unwrap();
+
+    let errors = collection
+        .start_all_staggered(TestDuration(10))
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 0);
+    assert!(collection.get_sequencer(1).unwrap().is_running());
+}
+
+/// LED on different hardware than `MockLed`
+struct CountingLed {
+    writes: usize,
+}
+
+impl RgbLed for CountingLed {
+    fn set_color(&mut self, _color: palette::Srgb) {
+        self.writes += 1;
+    }
+}
+
+#[test]
+fn collection_accepts_mixed_led_types_via_dyn_references() {
+    let timer = MockTimeSource::new();
+    let mut pwm_led = MockLed::new();
+    let mut expander_led = CountingLed { writes: 0 };
+
+    {
+        let mut collection: SequencerCollection<
+            '_,
+            TestInstant,
+            &mut dyn RgbLed,
+            MockTimeSource,
+            4,
+            2,
+        > = SequencerCollection::new(&timer);
+
+        assert!(collection.add_sequencer(&mut pwm_led).is_ok());
+        assert!(collection.add_sequencer(&mut expander_led).is_ok());
+        collection
+            .broadcast(SequencerAction::Load(hold(GREEN, 100)))
+            .unwrap();
+        collection.start_all_synced(&[0, 1]).unwrap();
+        collection.service_all().unwrap();
+    }
+
+    assert!(colors_equal(pwm_led.get_last_color(), GREEN));
+    // Off on creation, then green
+    assert_eq!(expander_led.writes, 2);
+}
+
+#[test]
+fn iter_yields_present_sequencers_with_ids() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    collection.remove(1).unwrap();
+
+    for (id, sequencer) in collection.iter_mut() {
+        let color = if id == 0 { RED } else { BLUE };
+        sequencer.load_and_start(hold(color, 100)).unwrap();
+    }
+    collection.service_all().unwrap();
+
+    let mut entries = collection.iter();
+    let (id, sequencer) = entries.next().unwrap();
+    assert_eq!(id, 0);
+    assert!(colors_equal(sequencer.current_color(), RED));
+    let (id, sequencer) = entries.next().unwrap();
+    assert_eq!(id, 2);
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+    assert!(entries.next().is_none());
+}
+
+#[test]
+fn brightness_routes_to_individual_or_all_leds() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+
+    collection.set_brightness_all(0.25);
+    collection.set_brightness(1, 0.5).unwrap();
+    assert_eq!(
+        collection.set_brightness(3, 0.5),
+        Err(CollectionError::InvalidId(3))
+    );
+
+    let brightness = [0, 1, 2].map(|id| collection.get_sequencer(id).unwrap().brightness());
+    assert_eq!(brightness, [0.25, 0.5, 0.25]);
+
+    // Clamped like RgbSequencer::set_brightness
+    collection.set_brightness_all(3.0);
+    assert_eq!(collection.get_sequencer(0).unwrap().brightness(), 1.0);
+}
+
+#[test]
+fn set_brightness_action_flows_through_command_routing() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    start(&mut collection, 0, rgb_sequencer::WHITE, 100);
+    collection.service_all().unwrap();
+
+    const DIMMED: u32 = 1 << 0;
+    collection.set_groups(1, DIMMED).unwrap();
+    collection.set_groups(2, DIMMED).unwrap();
+
+    collection
+        .broadcast(SequencerAction::SetBrightness(0.8))
+        .unwrap();
+    collection
+        .handle_group_command(DIMMED, SequencerAction::SetBrightness(0.4))
+        .unwrap();
+    collection
+        .handle_command(SequencerCommand::new(
+            2,
+            SequencerAction::SetBrightness(-1.0),
+        ))
+        .unwrap();
+
+    let brightness = [0, 1, 2].map(|id| collection.get_sequencer(id).unwrap().brightness());
+    assert_eq!(brightness, [0.8, 0.4, 0.0]);
+
+    // Takes effect on the next service of a running LED
+    collection.service_all().unwrap();
+    assert!(colors_equal(
+        collection.get_sequencer(0).unwrap().current_color(),
+        palette::Srgb::new(0.8, 0.8, 0.8)
+    ));
+}
+
+#[test]
+fn get_sequencer_mut_exposes_full_sequencer_api() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+    collection.add_sequencer(MockLed::new()).unwrap();
+
+    let sequencer = collection.get_sequencer_mut(0).unwrap();
+    sequencer.set_phase_offset(TestDuration(250));
+    sequencer.load_and_start(hold(RED, 100)).unwrap();
+
+    assert_eq!(
+        collection.get_sequencer(0).unwrap().phase_offset(),
+        TestDuration(250)
+    );
+    assert!(collection.get_sequencer(0).unwrap().is_running());
+    assert_eq!(
+        collection.get_sequencer_mut(1).err(),
+        Some(CollectionError::InvalidId(1))
+    );
+}
+
+#[test]
+fn resume_all_resumes_paused_and_held_leds() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    // LED 0 held, LED 1 paused, LED 2 still running
+    for id in 0..3 {
+        start(&mut collection, id, RED, 100);
+    }
+    collection.get_sequencer_mut(0).unwrap().hold().unwrap();
+    collection.get_sequencer_mut(1).unwrap().pause().unwrap();
+
+    assert_eq!(collection.resume_all(), 2);
+    for id in 0..3 {
+        assert_eq!(
+            collection.get_sequencer(id).unwrap().state(),
+            SequencerState::Running
+        );
+    }
+}
+
+#[test]
+fn batch_helpers_skip_incompatible_states() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..4 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    // LED 0 idle, LED 1 loaded, LEDs 2 and 3 running
+    collection
+        .get_sequencer_mut(1)
+        .unwrap()
+        .load(hold(RED, 100));
+    start(&mut collection, 2, GREEN, 100);
+    start(&mut collection, 3, BLUE, 100);
+
+    assert_eq!(collection.resume_all(), 0);
+    assert_eq!(collection.pause_all(), 2);
+    assert_eq!(collection.pause_all(), 0);
+    assert_eq!(collection.resume_all(), 2);
+
+    collection.get_sequencer_mut(3).unwrap().pause().unwrap();
+    assert_eq!(collection.stop_all(), 2);
+
+    let states = [0, 1, 2, 3].map(|id| collection.get_sequencer(id).unwrap().state());
+    assert_eq!(
+        states,
+        [
+            SequencerState::Idle,
+            SequencerState::Loaded,
+            SequencerState::Loaded,
+            SequencerState::Loaded
+        ]
+    );
+}
+
+#[test]
+fn status_counts_states_and_reports_next_deadline() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    let status = collection.status();
+    assert_eq!(status.running, 0);
+    assert_eq!(status.next_service, ServiceTiming::Complete);
+
+    for _ in 0..4 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    collection
+        .get_sequencer_mut(1)
+        .unwrap()
+        .load(hold(RED, 100));
+    start(&mut collection, 2, GREEN, 300);
+    start(&mut collection, 3, BLUE, 200);
+    collection.get_sequencer_mut(3).unwrap().pause().unwrap();
+
+    timer.advance(TestDuration(50));
+    let status = collection.status();
+    assert_eq!(
+        (
+            status.idle,
+            status.loaded,
+            status.running,
+            status.paused,
+            status.complete
+        ),
+        (1, 1, 1, 1, 0)
+    );
+    assert_eq!(status.held, 0);
+    assert_eq!(status.next_service, ServiceTiming::Delay(TestDuration(250)));
+
+    // status() does not service
+    assert!(colors_equal(
+        collection.get_sequencer(2).unwrap().current_color(),
+        BLACK
+    ));
+}
+
+#[test]
+fn status_requests_service_for_finished_but_unserviced_led() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+    collection.add_sequencer(MockLed::new()).unwrap();
+
+    let once = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+    collection
+        .get_sequencer_mut(0)
+        .unwrap()
+        .load_and_start(once)
+        .unwrap();
+
+    timer.advance(TestDuration(200));
+    assert_eq!(
+        collection.status().next_service,
+        ServiceTiming::Delay(TestDuration(0))
+    );
+
+    collection.service_all().unwrap();
+    let status = collection.status();
+    assert_eq!(status.complete, 1);
+    assert_eq!(status.next_service, ServiceTiming::Complete);
+}
+
+#[test]
+fn service_all_lossy_services_every_running_led() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    start(&mut collection, 0, RED, 300);
+    start(&mut collection, 2, BLUE, 200);
+
+    let (timing, errors) = collection.service_all_lossy();
+    assert_eq!(timing, ServiceTiming::Delay(TestDuration(200)));
+    assert!(errors.is_empty());
+
+    for (id, expected) in [(0, RED), (1, BLACK), (2, BLUE)] {
+        assert!(colors_equal(
+            collection.get_sequencer(id).unwrap().current_color(),
+            expected
+        ));
+    }
+}
+
+#[test]
+fn set_all_overrides_every_led_immediately() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+    start(&mut collection, 0, GREEN, 100);
+    start(&mut collection, 1, BLUE, 100);
+    collection.get_sequencer_mut(1).unwrap().pause().unwrap();
+    collection.set_brightness_all(0.5);
+    collection.service_all().unwrap();
+
+    collection.set_all(RED);
+
+    let states = [0, 1, 2].map(|id| collection.get_sequencer(id).unwrap().state());
+    assert_eq!(
+        states,
+        [
+            SequencerState::Loaded,
+            SequencerState::Loaded,
+            SequencerState::Idle
+        ]
+    );
+    for (_, sequencer) in collection.iter() {
+        assert!(colors_equal(sequencer.current_color(), RED));
+    }
+
+    // Servicing does not overwrite the override, restarting resumes the animation
+    collection.service_all().unwrap();
+    assert!(colors_equal(
+        collection.get_sequencer(0).unwrap().current_color(),
+        RED
+    ));
+    collection.start_all_synced(&[0]).unwrap();
+    collection.service_all().unwrap();
+    assert!(colors_equal(
+        collection.get_sequencer(0).unwrap().current_color(),
+        palette::Srgb::new(0.0, 0.5, 0.0)
+    ));
+}
+
+#[test]
+fn scheduled_commands_run_when_their_instant_arrives() {
+    let timer = MockTimeSource::new();
+    let mut collection: SequencerCollection<'_, TestInstant, MockLed, MockTimeSource, 4, 4, 4> =
+        SequencerCollection::new(&timer);
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+
+    // Cue list: load now, start LED 0 at 500ms, LED 1 at 300ms
+    collection
+        .handle_command(SequencerCommand::at(
+            0,
+            SequencerAction::Load(hold(RED, 100)),
+            TestInstant(0),
+        ))
+        .unwrap();
+    collection
+        .handle_command(SequencerCommand::new(
+            1,
+            SequencerAction::Load(hold(BLUE, 100)),
+        ))
+        .unwrap();
+    collection
+        .handle_command(SequencerCommand::at(
+            0,
+            SequencerAction::Start,
+            TestInstant(500),
+        ))
+        .unwrap();
+    collection
+        .handle_command(SequencerCommand::at(
+            1,
+            SequencerAction::Start,
+            TestInstant(300),
+        ))
+        .unwrap();
+    assert_eq!(collection.scheduled_len(), 2);
+    assert_eq!(
+        collection.status().next_service,
+        ServiceTiming::Delay(TestDuration(300))
+    );
+
+    let test_cases = [
+        (100, [SequencerState::Loaded, SequencerState::Loaded], 200),
+        (300, [SequencerState::Loaded, SequencerState::Running], 100),
+        (600, [SequencerState::Running, SequencerState::Running], 100),
+    ];
+
+    for (time, expected_states, expected_delay) in test_cases {
+        timer.set_time(TestInstant(time));
+        let timing = collection.service_all().unwrap();
+
+        let states = [0, 1].map(|id| collection.get_sequencer(id).unwrap().state());
+        assert_eq!(states, expected_states, "at {}ms", time);
+        assert_eq!(
+            timing,
+            ServiceTiming::Delay(TestDuration(expected_delay)),
+            "at {}ms",
+            time
+        );
+    }
+    assert_eq!(collection.scheduled_len(), 0);
+}
+
+#[test]
+fn scheduled_commands_run_in_instant_order_and_report_errors() {
+    let timer = MockTimeSource::new();
+    let mut collection: SequencerCollection<'_, TestInstant, MockLed, MockTimeSource, 4, 4, 2> =
+        SequencerCollection::new(&timer);
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection
+        .get_sequencer_mut(0)
+        .unwrap()
+        .load_and_start(hold(RED, 100))
+        .unwrap();
+
+    // Queued out of order: Pause at 200 must run before Resume at 400
+    collection
+        .handle_command(SequencerCommand::at(
+            0,
+            SequencerAction::Resume,
+            TestInstant(400),
+        ))
+        .unwrap();
+    collection
+        .handle_command(SequencerCommand::at(
+            0,
+            SequencerAction::Pause,
+            TestInstant(200),
+        ))
+        .unwrap();
+    assert_eq!(
+        collection.handle_command(SequencerCommand::at(
+            1,
+            SequencerAction::Start,
+            TestInstant(300)
+        )),
+        Err(CollectionError::ScheduleFull)
+    );
+    assert_eq!(
+        collection.handle_command(SequencerCommand::at(
+            3,
+            SequencerAction::Start,
+            TestInstant(300)
+        )),
+        Err(CollectionError::InvalidId(3))
+    );
+
+    timer.set_time(TestInstant(500));
+    let (_, errors) = collection.service_all_lossy();
+    assert!(errors.is_empty());
+    assert_eq!(
+        collection.get_sequencer(0).unwrap().state(),
+        SequencerState::Running
+    );
+
+    // Failures surface in the service report
+    collection
+        .handle_command(SequencerCommand::at(
+            1,
+            SequencerAction::Start,
+            TestInstant(600),
+        ))
+        .unwrap();
+    timer.set_time(TestInstant(600));
+    let (_, errors) = collection.service_all_lossy();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 1);
+    assert!(matches!(errors[0].1, CollectionError::Sequencer(_)));
+
+    // Removing an LED drops its pending commands
+    collection
+        .handle_command(SequencerCommand::at(
+            1,
+            SequencerAction::Start,
+            TestInstant(900),
+        ))
+        .unwrap();
+    collection.remove(1).unwrap();
+    assert_eq!(collection.scheduled_len(), 0);
+}
+
+#[test]
+fn service_report_lists_each_failing_led_once() {
+    let timer = MockTimeSource::new();
+    let mut collection: SequencerCollection<'_, TestInstant, MockLed, MockTimeSource, 4, 2, 4> =
+        SequencerCollection::new(&timer);
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+
+    // Two failing commands for idle LED 0 must not crowd out LED 1's failure
+    for (id, action) in [
+        (0, SequencerAction::Pause),
+        (0, SequencerAction::Start),
+        (1, SequencerAction::Pause),
+    ] {
+        collection
+            .handle_command(SequencerCommand::at(id, action, TestInstant(100)))
+            .unwrap();
+    }
+    timer.set_time(TestInstant(100));
+
+    let (_, errors) = collection.service_all_lossy();
+    let ids: Vec<usize> = errors.iter().map(|&(id, _)| id).collect();
+    assert_eq!(ids, [0, 1]);
+    assert!(matches!(errors[0].1, CollectionError::Sequencer(_)));
+}
+
+#[test]
+fn seek_command_routes_to_target_led() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::<4>::new(&timer);
+    collection.add_sequencer(MockLed::new()).unwrap();
+    collection.add_sequencer(MockLed::new()).unwrap();
+
+    let two_colors = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+    collection
+        .broadcast(SequencerAction::LoadAndStart(two_colors))
+        .unwrap();
+
+    collection
+        .handle_command(SequencerCommand::new(
+            1,
+            SequencerAction::Seek(TestDuration(1500)),
+        ))
+        .unwrap();
+    collection.service_all().unwrap();
+
+    for (id, expected) in [(0, RED), (1, BLUE)] {
+        assert!(colors_equal(
+            collection.get_sequencer(id).unwrap().current_color(),
+            expected
+        ));
+    }
+}
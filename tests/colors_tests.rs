@@ -66,3 +66,183 @@ fn hue_wraps_around_360() {
     let red2 = colors::hue(360.0);
     assert!(colors_equal(red1, red2));
 }
+
+#[test]
+fn hsl_creates_expected_colors() {
+    // (hue, saturation, lightness, expected)
+    let test_cases = [
+        (0.0, 1.0, 0.5, Srgb::new(1.0, 0.0, 0.0)),
+        (120.0, 1.0, 0.5, Srgb::new(0.0, 1.0, 0.0)),
+        (240.0, 1.0, 0.25, Srgb::new(0.0, 0.0, 0.5)),
+        (0.0, 1.0, 0.75, Srgb::new(1.0, 0.5, 0.5)),
+        (0.0, 0.0, 0.5, Srgb::new(0.5, 0.5, 0.5)),
+        (60.0, 1.0, 1.0, Srgb::new(1.0, 1.0, 1.0)),
+    ];
+
+    for (hue, saturation, lightness, expected) in test_cases {
+        let color = colors::hsl(hue, saturation, lightness);
+        assert!(
+            colors_equal(color, expected),
+            "hsl({}, {}, {}) = {:?}",
+            hue,
+            saturation,
+            lightness,
+            color
+        );
+    }
+}
+
+#[test]
+fn rgb8_and_palette_constants() {
+    const BRAND: Srgb = colors::rgb8(0, 51, 255);
+
+    let test_cases = [
+        (BRAND, Srgb::new(0.0, 0.2, 1.0)),
+        (colors::rgb8(255, 255, 255), Srgb::new(1.0, 1.0, 1.0)),
+        (colors::palette::ORANGE, colors::rgb8(255, 128, 0)),
+        (colors::palette::PURPLE, colors::rgb8(128, 0, 255)),
+    ];
+
+    for (color, expected) in test_cases {
+        assert!(colors_equal(color, expected), "{:?}", color);
+    }
+}
+
+#[test]
+fn wheel_yields_evenly_spaced_hues() {
+    let hues: Vec<Srgb> = colors::wheel(3).collect();
+    let expected = [
+        Srgb::new(1.0, 0.0, 0.0),
+        Srgb::new(0.0, 1.0, 0.0),
+        Srgb::new(0.0, 0.0, 1.0),
+    ];
+
+    assert_eq!(hues.len(), expected.len());
+    for (color, expected) in hues.into_iter().zip(expected) {
+        assert!(colors_equal(color, expected), "{:?}", color);
+    }
+
+    assert_eq!(colors::wheel(6).len(), 6);
+    assert_eq!(colors::wheel(0).count(), 0);
+}
+
+#[test]
+fn harmony_helpers_rotate_hue() {
+    let red = Srgb::new(1.0, 0.0, 0.0);
+    let dim_red = Srgb::new(0.5, 0.0, 0.0);
+
+    let test_cases = [
+        (colors::complementary(red), Srgb::new(0.0, 1.0, 1.0)),
+        (colors::complementary(dim_red), Srgb::new(0.0, 0.5, 0.5)),
+        (colors::triadic(red)[1], Srgb::new(0.0, 1.0, 0.0)),
+        (colors::triadic(red)[2], Srgb::new(0.0, 0.0, 1.0)),
+        (colors::analogous(red, 60.0)[0], Srgb::new(1.0, 0.0, 1.0)),
+        (colors::analogous(red, 60.0)[1], red),
+        (colors::analogous(red, 60.0)[2], Srgb::new(1.0, 1.0, 0.0)),
+    ];
+
+    for (color, expected) in test_cases {
+        assert!(colors_equal(color, expected), "{:?}", color);
+    }
+}
+
+#[test]
+fn lighten_darken_saturate_adjust_hsv_with_clamping() {
+    let dim_red = Srgb::new(0.5, 0.0, 0.0);
+    let pastel_red = Srgb::new(1.0, 0.5, 0.5);
+
+    let test_cases = [
+        (colors::lighten(dim_red, 0.25), Srgb::new(0.75, 0.0, 0.0)),
+        (colors::lighten(dim_red, 2.0), Srgb::new(1.0, 0.0, 0.0)),
+        (colors::darken(dim_red, 0.25), Srgb::new(0.25, 0.0, 0.0)),
+        (colors::darken(dim_red, 2.0), Srgb::new(0.0, 0.0, 0.0)),
+        (colors::saturate(pastel_red, 0.5), Srgb::new(1.0, 0.0, 0.0)),
+        (colors::saturate(pastel_red, 2.0), Srgb::new(1.0, 0.0, 0.0)),
+        (
+            colors::desaturate(pastel_red, 0.25),
+            Srgb::new(1.0, 0.75, 0.75),
+        ),
+        (
+            colors::desaturate(pastel_red, 2.0),
+            Srgb::new(1.0, 1.0, 1.0),
+        ),
+    ];
+
+    for (color, expected) in test_cases {
+        assert!(colors_equal(color, expected), "{:?}", color);
+    }
+}
+
+#[test]
+fn blend_modes_clamp_channel_math() {
+    use rgb_sequencer::colors::blend;
+
+    let a = Srgb::new(0.8, 0.5, 0.0);
+    let b = Srgb::new(0.5, 0.5, 0.2);
+
+    let test_cases = [
+        (blend::add(a, b), Srgb::new(1.0, 1.0, 0.2)),
+        (blend::multiply(a, b), Srgb::new(0.4, 0.25, 0.0)),
+        (blend::screen(a, b), Srgb::new(0.9, 0.75, 0.2)),
+        (blend::lerp(a, b, 0.5), Srgb::new(0.65, 0.5, 0.1)),
+        (blend::lerp(a, b, -1.0), a),
+        (blend::lerp(a, b, 2.0), b),
+    ];
+
+    for (color, expected) in test_cases {
+        assert!(colors_equal(color, expected), "{:?}", color);
+    }
+}
+
+#[test]
+fn from_hex_parses_six_digit_colors() {
+    use rgb_sequencer::colors::ColorParseError;
+
+    let test_cases = [
+        ("#FF8000", Ok(colors::rgb8(255, 128, 0))),
+        ("ff8000", Ok(colors::rgb8(255, 128, 0))),
+        ("#0a0B0c", Ok(colors::rgb8(10, 11, 12))),
+        ("#000000", Ok(Srgb::new(0.0, 0.0, 0.0))),
+        ("#FFF", Err(ColorParseError::InvalidLength)),
+        ("", Err(ColorParseError::InvalidLength)),
+        ("##FF8000", Err(ColorParseError::InvalidLength)),
+        ("#GG8000", Err(ColorParseError::InvalidDigit)),
+        ("#FF80 0", Err(ColorParseError::InvalidDigit)),
+    ];
+
+    for (input, expected) in test_cases {
+        match (colors::from_hex(input), expected) {
+            (Ok(color), Ok(expected)) => assert!(colors_equal(color, expected), "{}", input),
+            (result, expected) => assert_eq!(result.err(), expected.err(), "{}", input),
+        }
+    }
+}
+
+#[test]
+fn packed_u32_conversions_round_trip() {
+    let test_cases = [
+        (0xFF8000, colors::rgb8(255, 128, 0)),
+        (0x000000, Srgb::new(0.0, 0.0, 0.0)),
+        (0xFFFFFF, Srgb::new(1.0, 1.0, 1.0)),
+        (0x0A0B0C, colors::rgb8(10, 11, 12)),
+    ];
+
+    for (packed, color) in test_cases {
+        assert!(
+            colors_equal(colors::from_u32(packed), color),
+            "{:06X}",
+            packed
+        );
+        assert_eq!(colors::to_u32(color), packed);
+    }
+
+    // Every 8-bit channel value survives the round trip
+    for value in 0..=255u32 {
+        let packed = value << 16 | (255 - value) << 8 | value;
+        assert_eq!(colors::to_u32(colors::from_u32(packed)), packed);
+    }
+
+    // Top byte ignored, out-of-range channels clamped
+    assert_eq!(colors::to_u32(colors::from_u32(0xAA123456)), 0x123456);
+    assert_eq!(colors::to_u32(Srgb::new(1.5, -0.5, 0.5)), 0xFF0080);
+}
@@ -54,6 +54,7 @@ impl TimeInstant for TestInstant {
 // ============================================================================
 
 /// Mock LED that records all color changes for testing
+#[derive(Debug)]
 pub struct MockLed {
     current_color: Srgb,
     color_history: heapless::Vec<Srgb, 32>,
@@ -0,0 +1,149 @@
+//! Integration tests for compact sequence storage
+
+mod common;
+use common::*;
+
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::{LoopCount, SequenceError, SequenceStep, TransitionStyle};
+use rgb_sequencer::{CompactSequence, CompactStep, PaletteSequence, PaletteStep};
+
+const BLINK_CODE: [CompactStep; 3] = [
+    CompactStep::new(RED, 200, TransitionStyle::Step),
+    CompactStep::new(BLACK, 200, TransitionStyle::Step),
+    CompactStep::new(BLUE, 1000, TransitionStyle::Linear),
+];
+
+#[test]
+fn compact_step_is_smaller_than_sequence_step() {
+    assert!(
+        core::mem::size_of::<CompactStep>() < core::mem::size_of::<SequenceStep<TestDuration>>()
+    );
+}
+
+#[test]
+fn compact_sequence_expands_to_equivalent_sequence() {
+    let compact = CompactSequence::<4>::from_steps(&BLINK_CODE, LoopCount::Infinite).unwrap();
+    let expanded: RgbSequence<TestDuration, 4> = compact.to_sequence().unwrap();
+
+    let original = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(200), TransitionStyle::Step)
+        .unwrap()
+        .step(BLACK, TestDuration(200), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(1000), TransitionStyle::Linear)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    assert_eq!(expanded.loop_count(), LoopCount::Infinite);
+    for millis in [0, 150, 300, 900, 1400, 2500] {
+        let (expected, expected_timing) = original.evaluate(TestDuration(millis));
+        let (color, timing) = expanded.evaluate(TestDuration(millis));
+        assert!(colors_equal(color, expected), "at {}ms", millis);
+        assert_eq!(timing, expected_timing, "at {}ms", millis);
+    }
+
+    // Round trip through from_sequence keeps steps and loop count
+    let recompressed = CompactSequence::<4>::from_sequence(&original).unwrap();
+    assert_eq!(recompressed.steps(), &BLINK_CODE);
+    assert_eq!(recompressed.loop_count(), LoopCount::Infinite);
+
+    // Builders take further settings
+    let landing = compact
+        .to_builder::<TestDuration>()
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .landing_color(BLACK)
+        .build()
+        .unwrap();
+    assert_eq!(landing.landing_color(), Some(BLACK));
+}
+
+#[test]
+fn compact_sequence_rejects_invalid_input() {
+    let too_long = CompactSequence::<4>::new(LoopCount::Finite(1)).step(
+        RED,
+        TestDuration(65_536),
+        TransitionStyle::Step,
+    );
+    assert_eq!(too_long.unwrap_err(), SequenceError::DurationOutOfRange);
+
+    let longest = CompactSequence::<4>::new(LoopCount::Finite(1))
+        .step(RED, TestDuration(65_535), TransitionStyle::Step)
+        .unwrap();
+    assert_eq!(longest.steps()[0].millis, u16::MAX);
+
+    assert_eq!(
+        CompactSequence::<2>::from_steps(&BLINK_CODE, LoopCount::Finite(1)).unwrap_err(),
+        SequenceError::CapacityExceeded
+    );
+
+    // Validation happens on expansion
+    let empty = CompactSequence::<4>::new(LoopCount::Finite(1));
+    assert_eq!(
+        empty.to_sequence::<TestDuration>().unwrap_err(),
+        SequenceError::EmptySequence
+    );
+
+    let function_based = RgbSequence::<TestDuration, 4>::from_function(
+        RED,
+        |color, _| color,
+        |_| Some(TestDuration(0)),
+    );
+    assert_eq!(
+        CompactSequence::<4>::from_sequence(&function_based).unwrap_err(),
+        SequenceError::FunctionBased
+    );
+}
+
+#[test]
+fn palette_sequence_looks_up_colors_on_expansion() {
+    const FLASH: [PaletteStep; 4] = [
+        PaletteStep::new(0, 100, TransitionStyle::Step),
+        PaletteStep::new(2, 100, TransitionStyle::Step),
+        PaletteStep::new(1, 100, TransitionStyle::Step),
+        PaletteStep::new(2, 100, TransitionStyle::Step),
+    ];
+    assert_eq!(core::mem::size_of::<PaletteStep>(), 4);
+
+    let pattern = PaletteSequence::<4>::from_steps(&FLASH, LoopCount::Infinite).unwrap();
+    let police = [RED, BLUE, BLACK];
+    let amber = [rgb_sequencer::YELLOW, rgb_sequencer::YELLOW, BLACK];
+
+    // (palette, expected colors at 50, 150, 250ms)
+    let test_cases = [
+        (police, [RED, BLACK, BLUE]),
+        (amber, [amber[0], BLACK, amber[1]]),
+    ];
+
+    for (palette, expected) in test_cases {
+        let sequence = pattern.to_sequence::<TestDuration>(&palette).unwrap();
+        assert_eq!(sequence.loop_count(), LoopCount::Infinite);
+        for (millis, expected) in [50, 150, 250].into_iter().zip(expected) {
+            let (color, _) = sequence.evaluate(TestDuration(millis));
+            assert!(colors_equal(color, expected), "at {}ms", millis);
+        }
+    }
+
+    assert_eq!(
+        pattern
+            .to_sequence::<TestDuration>(&[RED, BLUE])
+            .unwrap_err(),
+        SequenceError::PaletteIndexOutOfRange
+    );
+
+    let built = PaletteSequence::<4>::new(LoopCount::Finite(1))
+        .step(1, TestDuration(250), TransitionStyle::Linear)
+        .unwrap();
+    assert_eq!(
+        built.steps(),
+        &[PaletteStep::new(1, 250, TransitionStyle::Linear)]
+    );
+    assert_eq!(
+        PaletteSequence::<4>::new(LoopCount::Finite(1))
+            .step(0, TestDuration(70_000), TransitionStyle::Step)
+            .unwrap_err(),
+        SequenceError::DurationOutOfRange
+    );
+}
@@ -0,0 +1,88 @@
+//! Integration tests for DMX512 fixture output
+
+mod common;
+use common::*;
+
+use palette::Srgb;
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::TransitionStyle;
+use rgb_sequencer::{DMX_SLOTS, DmxBuffer, DmxError, DmxFixture, MappedLed, RgbLed, RgbSequencer};
+
+#[test]
+fn fixture_addresses_must_fit_in_universe() {
+    let universe = DmxBuffer::new();
+    let cases = [
+        // (address, fits with 3 channels, fits with 4 channels)
+        (0, false, false),
+        (1, true, true),
+        (509, true, true),
+        (510, true, false),
+        (511, false, false),
+        (600, false, false),
+    ];
+
+    for (address, fits_3, fits_4) in cases {
+        assert_eq!(
+            DmxFixture::<_, 3>::new(&universe, address).is_ok(),
+            fits_3,
+            "address {address} with 3 channels"
+        );
+        assert_eq!(
+            DmxFixture::<_, 4>::new(&universe, address).map(|_| ()),
+            if fits_4 {
+                Ok(())
+            } else {
+                Err(DmxError::AddressOutOfRange)
+            },
+            "address {address} with 4 channels"
+        );
+    }
+}
+
+#[test]
+fn fixtures_share_one_universe() {
+    let universe = DmxBuffer::new();
+    let mut first = DmxFixture::<_, 3>::new(&universe, 1).unwrap();
+    let mut last = DmxFixture::<_, 3>::new(&universe, 510).unwrap();
+    assert!(universe.take_frame().is_none());
+
+    first.set_color(Srgb::new(1.0, 0.5, 0.0));
+    last.set_color(Srgb::new(0.0, 0.2, 1.5));
+
+    let frame = universe.take_frame().unwrap();
+    assert_eq!(frame[..4], [255, 128, 0, 0]);
+    assert_eq!(frame[DMX_SLOTS - 3..], [0, 51, 255]);
+    assert_eq!(universe.slot(512), Some(255));
+    assert_eq!(universe.slot(0), None);
+
+    // Nothing committed since the last frame was taken
+    assert!(universe.take_frame().is_none());
+}
+
+#[test]
+fn sequencer_drives_four_channel_fixture_through_mapper() {
+    fn rgb_plus_dimmer(color: Srgb) -> [f32; 4] {
+        [1.0, color.red, color.green, color.blue]
+    }
+
+    let universe = DmxBuffer::new();
+    let timer = MockTimeSource::new();
+    let fixture = DmxFixture::<_, 4>::new(&universe, 100).unwrap();
+    let led = MappedLed::new(fixture, rgb_plus_dimmer as fn(Srgb) -> [f32; 4]);
+    let mut sequencer = RgbSequencer::<TestInstant, _, MockTimeSource, 4>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 4>::builder()
+        .step(
+            Srgb::new(0.0, 1.0, 0.0),
+            TestDuration(100),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+
+    let frame = universe.take_frame().unwrap();
+    assert_eq!(frame[99..103], [255, 0, 255, 0]);
+}
@@ -0,0 +1,120 @@
+//! Integration tests for the embedded-hal 1.0 duty-cycle LED adapter (requires the
+//! `embedded-hal-1` feature)
+
+#![cfg(feature = "embedded-hal-1")]
+
+use embedded_hal_1::pwm::{ErrorKind, ErrorType, SetDutyCycle};
+use palette::Srgb;
+use rgb_sequencer::{DutyCycleRgbLed, RgbLed};
+
+/// Mock duty-cycle channel that records its duty and can be made to fail
+struct MockChannel {
+    duty: u16,
+    max_duty: u16,
+    fail: bool,
+}
+
+impl MockChannel {
+    fn new(max_duty: u16) -> Self {
+        Self {
+            duty: 0,
+            max_duty,
+            fail: false,
+        }
+    }
+
+    fn failing(max_duty: u16) -> Self {
+        Self {
+            fail: true,
+            ..Self::new(max_duty)
+        }
+    }
+}
+
+impl ErrorType for MockChannel {
+    type Error = ErrorKind;
+}
+
+impl SetDutyCycle for MockChannel {
+    fn max_duty_cycle(&self) -> u16 {
+        self.max_duty
+    }
+
+    fn set_duty_cycle(&mut self, duty: u16) -> Result<(), ErrorKind> {
+        if self.fail {
+            return Err(ErrorKind::Other);
+        }
+        self.duty = duty;
+        Ok(())
+    }
+}
+
+fn duties(led: DutyCycleRgbLed<MockChannel, MockChannel, MockChannel>) -> [u16; 3] {
+    let (red, green, blue) = led.into_channels();
+    [red.duty, green.duty, blue.duty]
+}
+
+#[test]
+fn duty_scaling_and_inversion() {
+    let cases = [
+        // (color, common_anode, expected duties)
+        (Srgb::new(1.0, 0.5, 0.0), false, [1000, 500, 0]),
+        (Srgb::new(1.0, 0.5, 0.0), true, [0, 500, 1000]),
+        (Srgb::new(0.2006, 0.0, 0.0), false, [201, 0, 0]),
+        (Srgb::new(1.5, -0.5, 0.25), true, [0, 1000, 750]),
+    ];
+
+    for (color, common_anode, expected) in cases {
+        let mut led = DutyCycleRgbLed::new(
+            MockChannel::new(1000),
+            MockChannel::new(1000),
+            MockChannel::new(1000),
+            common_anode,
+        );
+        led.set_color(color);
+        assert_eq!(duties(led), expected, "{color:?} anode={common_anode}");
+    }
+}
+
+#[test]
+fn max_duty_is_cached_per_channel() {
+    let mut led = DutyCycleRgbLed::new(
+        MockChannel::new(255),
+        MockChannel::new(1000),
+        MockChannel::new(65535),
+        false,
+    );
+    assert_eq!(led.max_duty(), [255, 1000, 65535]);
+
+    led.set_color(Srgb::new(1.0, 1.0, 1.0));
+    assert_eq!(duties(led), [255, 1000, 65535]);
+}
+
+#[test]
+fn gamma_decodes_before_scaling() {
+    let mut led = DutyCycleRgbLed::new(
+        MockChannel::new(1000),
+        MockChannel::new(1000),
+        MockChannel::new(1000),
+        false,
+    )
+    .with_gamma(true);
+
+    led.set_color(Srgb::new(1.0, 0.5, 0.0));
+    assert_eq!(duties(led), [1000, 214, 0]);
+}
+
+#[test]
+fn failed_writes_are_counted_and_other_channels_still_update() {
+    let mut led = DutyCycleRgbLed::new(
+        MockChannel::new(100),
+        MockChannel::failing(100),
+        MockChannel::new(100),
+        false,
+    );
+
+    led.set_color(Srgb::new(1.0, 1.0, 1.0));
+    led.set_color(Srgb::new(0.5, 0.5, 0.5));
+    assert_eq!(led.error_count(), 2);
+    assert_eq!(duties(led), [50, 0, 50]);
+}
@@ -0,0 +1,91 @@
+//! Integration tests for the Embassy task wrapper (requires the `embassy` feature)
+
+#![cfg(feature = "embassy")]
+
+mod common;
+use common::*;
+
+use core::pin::pin;
+use embassy_futures::{block_on, poll_once};
+use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+use embassy_sync::channel::Channel;
+use embassy_time::{Duration, MockDriver};
+use palette::Srgb;
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::TransitionStyle;
+use rgb_sequencer::{
+    BLACK, EmbassyCommand, EmbassyDuration, EmbassyTimeSource, RgbSequencer, SequencerAction,
+    SequencerCommand, SequencerError, SequencerState, SequencerTask,
+};
+
+fn blink() -> RgbSequence<EmbassyDuration, 4> {
+    RgbSequence::builder()
+        .step(
+            Srgb::new(1.0, 0.0, 0.0),
+            EmbassyDuration(Duration::from_millis(100)),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .step(
+            Srgb::new(0.0, 0.0, 1.0),
+            EmbassyDuration(Duration::from_millis(100)),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+// The mock driver's clock is global, so everything timing-related lives in one test
+#[test]
+fn task_processes_commands_and_services_on_deadlines() {
+    let time_source = EmbassyTimeSource;
+    let channel = Channel::<NoopRawMutex, EmbassyCommand<(), 4>, 2>::new();
+    let sequencer = RgbSequencer::new(MockLed::new(), &time_source);
+    let mut task = SequencerTask::new(sequencer, channel.receiver());
+
+    // Nothing running: the first pass services nothing and the task then waits for commands
+    block_on(task.run_once()).unwrap();
+    assert!(poll_once(pin!(task.run_once())).is_pending());
+
+    // A command is applied and serviced right away
+    channel
+        .try_send(SequencerCommand::new(
+            (),
+            SequencerAction::LoadAndStart(blink()),
+        ))
+        .unwrap();
+    block_on(task.run_once()).unwrap();
+    assert_eq!(task.sequencer().state(), SequencerState::Running);
+    assert!(colors_equal(
+        task.sequencer().current_color(),
+        Srgb::new(1.0, 0.0, 0.0)
+    ));
+
+    // The next service waits for the step deadline
+    {
+        let mut pass = pin!(task.run_once());
+        assert!(poll_once(pass.as_mut()).is_pending());
+        MockDriver::get().advance(Duration::from_millis(100));
+        block_on(pass).unwrap();
+    }
+    assert!(colors_equal(
+        task.sequencer().current_color(),
+        Srgb::new(0.0, 0.0, 1.0)
+    ));
+
+    // Errors are returned and the task keeps going
+    channel
+        .try_send(SequencerCommand::new((), SequencerAction::Resume))
+        .unwrap();
+    assert!(matches!(
+        block_on(task.run_once()),
+        Err(SequencerError::InvalidState { .. })
+    ));
+
+    channel
+        .try_send(SequencerCommand::new((), SequencerAction::Stop))
+        .unwrap();
+    block_on(task.run_once()).unwrap();
+    assert!(colors_equal(task.into_sequencer().current_color(), BLACK));
+}
@@ -0,0 +1,100 @@
+//! Integration tests for ErasedCollection
+
+mod common;
+use common::*;
+
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::{LoopCount, TransitionStyle};
+use rgb_sequencer::{
+    CollectionError, ErasedCollection, RgbSequencer, SequencerAction, SequencerCommand,
+    SequencerError, SequencerState, ServiceTiming,
+};
+
+fn cycle(steps: usize) -> RgbSequence<TestDuration, 8> {
+    (0..steps)
+        .try_fold(RgbSequence::builder(), |builder, index| {
+            let color = if index % 2 == 0 { RED } else { BLUE };
+            builder.step(color, TestDuration(100), TransitionStyle::Step)
+        })
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn collection_mixes_step_capacities() {
+    let timer = MockTimeSource::new();
+    let mut status = RgbSequencer::<TestInstant, MockLed, _, 2>::new(MockLed::new(), &timer);
+    let mut ambient = RgbSequencer::<TestInstant, MockLed, _, 8>::new(MockLed::new(), &timer);
+
+    let mut leds = ErasedCollection::<TestInstant, 2>::new();
+    assert_eq!(leds.add(&mut status), Ok(0));
+    assert_eq!(leds.add(&mut ambient), Ok(1));
+    assert_eq!(leds.get_mut(0).map(|led| led.capacity()), Ok(2));
+    assert_eq!(
+        leds.get_mut(2).map(|led| led.capacity()),
+        Err(CollectionError::InvalidId(2))
+    );
+
+    // A sequence of any capacity is copied into each sequencer if its steps fit
+    let blink = cycle(2);
+    let rainbow = cycle(6);
+    for (id, sequence) in [(0, &blink), (1, &rainbow)] {
+        leds.handle_command(SequencerCommand::new(
+            id,
+            SequencerAction::LoadAndStart(sequence.clone()),
+        ))
+        .unwrap();
+    }
+    assert_eq!(
+        leds.handle_command(SequencerCommand::new(0, SequencerAction::Load(rainbow))),
+        Err(CollectionError::Sequencer(SequencerError::CapacityExceeded))
+    );
+    assert_eq!(
+        leds.get_mut(0).map(|led| led.state()),
+        Ok(SequencerState::Running)
+    );
+
+    timer.advance(TestDuration(150));
+    assert_eq!(
+        leds.service_all(),
+        Ok(ServiceTiming::Delay(TestDuration(50)))
+    );
+
+    // Views load directly through the trait
+    let led = leds.get_mut(1).unwrap();
+    led.load(blink.as_view()).unwrap();
+    led.start().unwrap();
+
+    drop(leds);
+    assert!(colors_equal(status.current_color(), BLUE));
+    assert_eq!(ambient.current_sequence(), Some(&blink));
+}
+
+#[test]
+fn scheduled_commands_are_refused() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, _, 8>::new(MockLed::new(), &timer);
+    sequencer.load(cycle(2));
+
+    let mut leds = ErasedCollection::<TestInstant, 1>::new();
+    leds.add(&mut sequencer).unwrap();
+
+    assert_eq!(
+        leds.handle_command(SequencerCommand::<usize, TestDuration, 8>::at(
+            0,
+            SequencerAction::Start,
+            ()
+        )),
+        Err(CollectionError::ScheduleFull)
+    );
+    assert_eq!(
+        leds.get_mut(0).map(|led| led.state()),
+        Ok(SequencerState::Loaded)
+    );
+
+    let (timing, errors) = leds.service_all_lossy();
+    assert_eq!(timing, ServiceTiming::Complete);
+    assert!(errors.is_empty());
+}
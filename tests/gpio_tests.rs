@@ -0,0 +1,123 @@
+//! Integration tests for the dithered GPIO LED adapter (requires the `embedded-hal-1`
+//! feature)
+
+#![cfg(feature = "embedded-hal-1")]
+
+use embedded_hal_1::digital::{ErrorKind, ErrorType, OutputPin};
+use palette::Srgb;
+use rgb_sequencer::{BinaryRgbLed, RgbLed};
+
+/// Mock output pin that counts high ticks and writes
+#[derive(Default)]
+struct MockPin {
+    high: bool,
+    writes: u32,
+    fail: bool,
+}
+
+impl ErrorType for MockPin {
+    type Error = ErrorKind;
+}
+
+impl OutputPin for MockPin {
+    fn set_low(&mut self) -> Result<(), ErrorKind> {
+        self.set(false)
+    }
+
+    fn set_high(&mut self) -> Result<(), ErrorKind> {
+        self.set(true)
+    }
+}
+
+impl MockPin {
+    fn set(&mut self, high: bool) -> Result<(), ErrorKind> {
+        if self.fail {
+            return Err(ErrorKind::Other);
+        }
+        self.high = high;
+        self.writes += 1;
+        Ok(())
+    }
+}
+
+type TestLed = BinaryRgbLed<MockPin, MockPin, MockPin>;
+
+fn led(common_anode: bool) -> TestLed {
+    BinaryRgbLed::new(
+        MockPin::default(),
+        MockPin::default(),
+        MockPin::default(),
+        common_anode,
+    )
+}
+
+/// Sets `color`, then ticks `ticks - 1` more times and counts lit ticks per channel
+fn lit_counts(led: &mut TestLed, color: Srgb, ticks: u32) -> [u32; 3] {
+    let mut counts = [0; 3];
+    led.set_color(color);
+    for tick in 0..ticks {
+        if tick > 0 {
+            led.tick();
+        }
+        for (count, lit) in counts.iter_mut().zip(led.lit()) {
+            *count += lit as u32;
+        }
+    }
+    counts
+}
+
+#[test]
+fn dithering_averages_to_requested_level() {
+    let cases = [
+        // (color, expected lit ticks out of 100)
+        (Srgb::new(1.0, 0.5, 0.0), [100, 50, 0]),
+        (Srgb::new(0.25, 0.1, 0.75), [25, 10, 75]),
+        (Srgb::new(1.5, -0.5, f32::NAN), [100, 0, 0]),
+    ];
+
+    for (color, expected) in cases {
+        let mut led = led(false);
+        assert_eq!(lit_counts(&mut led, color, 100), expected, "{color:?}");
+    }
+}
+
+#[test]
+fn common_anode_drives_lit_channels_low() {
+    let mut led = led(true);
+    led.set_color(Srgb::new(1.0, 0.0, 0.0));
+
+    assert_eq!(led.lit(), [true, false, false]);
+    let (red, green, blue) = led.into_pins();
+    assert_eq!([red.high, green.high, blue.high], [false, true, true]);
+}
+
+#[test]
+fn pins_are_only_written_on_change() {
+    let mut led = led(false);
+    led.set_color(Srgb::new(1.0, 0.0, 0.5));
+    for _ in 0..9 {
+        led.tick();
+    }
+
+    let (red, green, blue) = led.into_pins();
+    // One write from new(), then toggles only
+    assert_eq!([red.writes, green.writes, blue.writes], [2, 1, 10]);
+}
+
+#[test]
+fn failed_writes_are_counted_and_retried() {
+    let mut led = BinaryRgbLed::new(
+        MockPin::default(),
+        MockPin {
+            fail: true,
+            ..MockPin::default()
+        },
+        MockPin::default(),
+        false,
+    );
+    assert_eq!(led.error_count(), 1);
+
+    led.set_color(Srgb::new(0.0, 1.0, 0.0));
+    led.tick();
+    assert_eq!(led.error_count(), 3);
+}
@@ -0,0 +1,81 @@
+//! Integration tests for heap-backed sequences (requires the `alloc` feature)
+
+#![cfg(feature = "alloc")]
+
+mod common;
+use common::*;
+
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::{LoopCount, SequenceError, TransitionStyle};
+use rgb_sequencer::{HeapSequence, RgbSequencer};
+
+#[test]
+fn heap_sequence_evaluates_like_fixed_capacity_sequence() {
+    let fixed = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(200), TransitionStyle::EaseInOut)
+        .unwrap()
+        .start_delay(TestDuration(50))
+        .loop_count(LoopCount::Finite(2))
+        .landing_color(GREEN)
+        .build()
+        .unwrap();
+    let heap = HeapSequence::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .step(BLUE, TestDuration(200), TransitionStyle::EaseInOut)
+        .start_delay(TestDuration(50))
+        .loop_count(LoopCount::Finite(2))
+        .landing_color(GREEN)
+        .build()
+        .unwrap();
+
+    assert_eq!(heap.as_view(), fixed.as_view());
+    assert_eq!(HeapSequence::from_view(fixed.as_view()), Ok(heap.clone()));
+    assert_eq!(heap.total_duration(), Some(TestDuration(650)));
+    for millis in [0, 75, 200, 400, 649, 650, 1000] {
+        let elapsed = TestDuration(millis);
+        assert_eq!(
+            heap.evaluate(elapsed),
+            fixed.evaluate(elapsed),
+            "{}ms",
+            millis
+        );
+    }
+}
+
+#[test]
+fn long_heap_sequence_loads_once_it_fits() {
+    let heap = HeapSequence::builder()
+        .steps_from(
+            (0..100).map(|i| if i % 2 == 0 { RED } else { BLUE }),
+            TestDuration(10),
+            TransitionStyle::Step,
+        )
+        .build()
+        .unwrap();
+    assert_eq!(heap.step_count(), 100);
+    assert_eq!(
+        heap.to_sequence::<64>().unwrap_err(),
+        SequenceError::CapacityExceeded
+    );
+
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, _, _, 128>::new(MockLed::new(), &timer);
+    sequencer.load(heap.to_sequence().unwrap());
+    sequencer.start().unwrap();
+    timer.advance(TestDuration(15));
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+}
+
+#[test]
+fn heap_builder_validates_like_sequence_builder() {
+    let result = HeapSequence::<TestDuration>::builder().build();
+    assert_eq!(result, Err(SequenceError::EmptySequence));
+
+    let result = HeapSequence::builder()
+        .step(RED, TestDuration(0), TransitionStyle::Linear)
+        .build();
+    assert_eq!(result, Err(SequenceError::ZeroDurationWithInterpolation));
+}
@@ -0,0 +1,79 @@
+//! Integration tests for output module
+
+mod common;
+use common::*;
+
+use palette::Srgb;
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::TransitionStyle;
+use rgb_sequencer::{ColorMapper, MappedLed, MultiChannelLed, RgbLed, RgbSequencer};
+
+/// Mock RGBW LED that records the last frame
+struct MockRgbwLed {
+    channels: [f32; 4],
+}
+
+impl MultiChannelLed<4> for MockRgbwLed {
+    fn set_channels(&mut self, channels: [f32; 4]) {
+        self.channels = channels;
+    }
+}
+
+/// Extracts the common white component into the W channel
+struct WhiteExtraction;
+
+impl ColorMapper<4> for WhiteExtraction {
+    fn map(&self, color: Srgb) -> [f32; 4] {
+        let white = color.red.min(color.green).min(color.blue);
+        [
+            color.red - white,
+            color.green - white,
+            color.blue - white,
+            white,
+        ]
+    }
+}
+
+fn rgba(color: Srgb) -> [f32; 4] {
+    [color.red, color.green, color.blue, 0.0]
+}
+
+#[test]
+fn mapped_led_applies_mapper_before_output() {
+    let mut led = MappedLed::new(MockRgbwLed { channels: [0.0; 4] }, WhiteExtraction);
+
+    led.set_color(Srgb::new(1.0, 0.5, 0.5));
+    assert_eq!(led.led().channels, [0.5, 0.0, 0.0, 0.5]);
+}
+
+#[test]
+fn function_pointer_works_as_mapper() {
+    let mapper: fn(Srgb) -> [f32; 4] = rgba;
+    let mut led = MappedLed::new(MockRgbwLed { channels: [1.0; 4] }, mapper);
+
+    led.set_color(GREEN);
+    assert_eq!(led.led().channels, [0.0, 1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn sequencer_drives_mapped_led() {
+    let timer = MockTimeSource::new();
+    let led = MappedLed::new(MockRgbwLed { channels: [1.0; 4] }, WhiteExtraction);
+    let mut sequencer = RgbSequencer::<TestInstant, _, MockTimeSource, 4>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 4>::builder()
+        .step(
+            Srgb::new(1.0, 1.0, 1.0),
+            TestDuration(100),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+
+    let (led, _) = sequencer.into_led().into_parts();
+    assert_eq!(led.channels, [0.0, 0.0, 0.0, 1.0]);
+}
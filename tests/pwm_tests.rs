@@ -0,0 +1,120 @@
+//! Integration tests for the PWM LED adapter (requires the `embedded-hal` feature)
+
+#![cfg(feature = "embedded-hal")]
+
+use embedded_hal_02::PwmPin;
+use palette::Srgb;
+use rgb_sequencer::{PwmRgbLed, RgbLed};
+
+/// Mock PWM channel that records its duty and enabled state
+struct MockPwm {
+    duty: u16,
+    max_duty: u16,
+    enabled: bool,
+}
+
+impl MockPwm {
+    fn new(max_duty: u16) -> Self {
+        Self {
+            duty: 0,
+            max_duty,
+            enabled: false,
+        }
+    }
+}
+
+impl PwmPin for MockPwm {
+    type Duty = u16;
+
+    fn disable(&mut self) {
+        self.enabled = false;
+    }
+
+    fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    fn get_duty(&self) -> u16 {
+        self.duty
+    }
+
+    fn get_max_duty(&self) -> u16 {
+        self.max_duty
+    }
+
+    fn set_duty(&mut self, duty: u16) {
+        self.duty = duty;
+    }
+}
+
+fn duties(led: PwmRgbLed<MockPwm, MockPwm, MockPwm>) -> [u16; 3] {
+    let (red, green, blue) = led.into_channels();
+    [red.duty, green.duty, blue.duty]
+}
+
+#[test]
+fn new_enables_all_channels() {
+    let led = PwmRgbLed::new(
+        MockPwm::new(100),
+        MockPwm::new(100),
+        MockPwm::new(100),
+        false,
+    );
+
+    let (red, green, blue) = led.into_channels();
+    assert!(red.enabled && green.enabled && blue.enabled);
+}
+
+#[test]
+fn duty_scaling_and_inversion() {
+    let cases = [
+        // (color, common_anode, expected duties)
+        (Srgb::new(1.0, 0.5, 0.0), false, [1000, 500, 0]),
+        (Srgb::new(1.0, 0.5, 0.0), true, [0, 500, 1000]),
+        (Srgb::new(0.2004, 0.0, 0.0), false, [200, 0, 0]),
+        (Srgb::new(0.2006, 0.0, 0.0), false, [201, 0, 0]),
+        (Srgb::new(1.5, -0.5, 0.25), false, [1000, 0, 250]),
+        (Srgb::new(1.5, -0.5, 0.25), true, [0, 1000, 750]),
+    ];
+
+    for (color, common_anode, expected) in cases {
+        let mut led = PwmRgbLed::new(
+            MockPwm::new(1000),
+            MockPwm::new(1000),
+            MockPwm::new(1000),
+            common_anode,
+        );
+        led.set_color(color);
+        assert_eq!(duties(led), expected, "{color:?} anode={common_anode}");
+    }
+}
+
+#[test]
+fn channels_scale_to_their_own_max_duty() {
+    let mut led = PwmRgbLed::new(
+        MockPwm::new(255),
+        MockPwm::new(1000),
+        MockPwm::new(65535),
+        true,
+    );
+    led.set_color(Srgb::new(1.0, 1.0, 0.0));
+
+    assert_eq!(duties(led), [0, 0, 65535]);
+}
+
+#[test]
+fn gamma_decodes_before_scaling() {
+    let mut led = PwmRgbLed::new(
+        MockPwm::new(1000),
+        MockPwm::new(1000),
+        MockPwm::new(1000),
+        false,
+    )
+    .with_gamma(true);
+    assert!(led.gamma());
+
+    led.set_color(Srgb::new(1.0, 0.5, 0.0));
+
+    // sRGB 0.5 decodes to ~0.214 linear light; the endpoints are unchanged
+    assert_eq!(duties(led), [1000, 214, 0]);
+}
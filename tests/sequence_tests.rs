@@ -6,7 +6,7 @@ use common::*;
 use palette::{Mix, Srgb};
 use rgb_sequencer::sequence::RgbSequence;
 use rgb_sequencer::types::{LoopCount, SequenceError, TransitionStyle};
-use rgb_sequencer::{TimeDuration, YELLOW};
+use rgb_sequencer::{RgbSequenceRef, TimeDuration, YELLOW};
 
 #[test]
 fn builder_rejects_empty_sequence() {
@@ -210,6 +210,56 @@ fn function_based_sequence_respects_timing_function() {
     assert!(seq.has_completed(TestDuration(1000)));
 }
 
+#[test]
+fn phase_covers_step_loops_and_periodic_functions() {
+    fn pulse(base: Srgb, _elapsed: TestDuration) -> Srgb {
+        base
+    }
+
+    fn forever(_elapsed: TestDuration) -> Option<TestDuration> {
+        Some(TestDuration::ZERO)
+    }
+
+    let function_based = RgbSequence::<TestDuration, 8>::from_function(RED, pulse, forever);
+    assert_eq!(function_based.period(), None);
+    assert_eq!(function_based.phase_at(TestDuration(500)), None);
+
+    let periodic = function_based.with_period(TestDuration(2000));
+    assert_eq!(periodic.period(), Some(TestDuration(2000)));
+    assert_eq!(periodic.phase_at(TestDuration(5000)), Some(0.5));
+
+    // Intro of 100ms, then a 200ms loop played twice
+    let stepped = RgbSequence::<TestDuration, 8>::builder()
+        .step(YELLOW, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_from(1)
+        .loop_count(LoopCount::Finite(2))
+        .build()
+        .unwrap()
+        .with_period(TestDuration(1000));
+    assert_eq!(stepped.period(), None);
+
+    // (elapsed, expected phase)
+    let test_cases = [
+        (50, Some(0.0)),
+        (150, Some(0.25)),
+        (350, Some(0.25)),
+        (500, None),
+    ];
+    for (elapsed, expected) in test_cases {
+        assert_eq!(
+            stepped.phase_at(TestDuration(elapsed)),
+            expected,
+            "at {}ms",
+            elapsed
+        );
+    }
+}
+
 #[test]
 fn evaluate_returns_both_color_and_timing() {
     let sequence = RgbSequence::<TestDuration, 8>::builder()
@@ -853,6 +903,7 @@ fn easing_transitions_return_continuous_timing() {
         TransitionStyle::EaseOut,
         TransitionStyle::EaseInOut,
         TransitionStyle::EaseOutIn,
+        TransitionStyle::CatmullRom,
     ];
 
     for transition in test_cases {
@@ -879,6 +930,7 @@ fn zero_duration_with_easing_is_rejected() {
         TransitionStyle::EaseOut,
         TransitionStyle::EaseInOut,
         TransitionStyle::EaseOutIn,
+        TransitionStyle::CatmullRom,
     ];
 
     for transition in test_cases {
@@ -960,3 +1012,590 @@ fn easing_loops_correctly() {
     let (color_250, _) = sequence.evaluate(TestDuration(250));
     assert!((color_250.red - first_loop_red).abs() < 0.01);
 }
+
+#[test]
+fn start_delay_holds_start_color_once_before_first_step() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .start_delay(TestDuration(500))
+        .start_color(BLUE)
+        .step(RED, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .loop_count(LoopCount::Finite(2))
+        .build()
+        .unwrap();
+
+    assert_eq!(sequence.start_delay(), TestDuration(500));
+    assert_eq!(sequence.loop_duration(), TestDuration(200));
+
+    let test_cases = [
+        (0, BLUE, Some(TestDuration(500)), "delay holds start color"),
+        (300, BLUE, Some(TestDuration(200)), "still delaying"),
+        (500, BLUE, Some(TestDuration::ZERO), "first step begins"),
+        (600, RED, Some(TestDuration::ZERO), "end of first step"),
+        (
+            700,
+            GREEN,
+            Some(TestDuration::ZERO),
+            "second loop starts without delay",
+        ),
+        (
+            800,
+            RED,
+            Some(TestDuration::ZERO),
+            "second loop, second step",
+        ),
+        (900, GREEN, None, "complete after delay plus loops"),
+    ];
+
+    for (elapsed, color, timing, desc) in test_cases {
+        let (actual_color, actual_timing) = sequence.evaluate(TestDuration(elapsed));
+        assert!(colors_equal(actual_color, color), "{}", desc);
+        assert_eq!(actual_timing, timing, "{}", desc);
+    }
+
+    assert!(!sequence.has_completed(TestDuration(899)));
+    assert!(sequence.has_completed(TestDuration(900)));
+    assert_eq!(
+        sequence
+            .find_step_position(TestDuration(650))
+            .unwrap()
+            .step_index,
+        1
+    );
+}
+
+#[test]
+fn start_delay_without_start_color_is_black() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .start_delay(TestDuration(100))
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        sequence.evaluate(TestDuration(50)),
+        (BLACK, Some(TestDuration(50)))
+    );
+    assert!(colors_equal(sequence.evaluate(TestDuration(100)).0, RED));
+}
+
+#[test]
+fn end_hold_keeps_final_color_before_completion() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .loop_count(LoopCount::Finite(2))
+        .landing_color(BLUE)
+        .end_hold(TestDuration(300))
+        .build()
+        .unwrap();
+
+    assert_eq!(sequence.end_hold(), TestDuration(300));
+
+    let test_cases = [
+        (
+            350,
+            GREEN.mix(RED, 0.5),
+            Some(TestDuration::ZERO),
+            "last loop",
+        ),
+        (400, BLUE, Some(TestDuration(300)), "end hold begins"),
+        (650, BLUE, Some(TestDuration(50)), "end hold"),
+        (700, BLUE, None, "complete after end hold"),
+    ];
+
+    for (elapsed, color, timing, desc) in test_cases {
+        let (actual_color, actual_timing) = sequence.evaluate(TestDuration(elapsed));
+        assert!(colors_equal(actual_color, color), "{}", desc);
+        assert_eq!(actual_timing, timing, "{}", desc);
+    }
+
+    assert!(!sequence.has_completed(TestDuration(699)));
+    assert!(sequence.has_completed(TestDuration(700)));
+}
+
+#[test]
+fn builder_rejects_end_hold_with_infinite_loop() {
+    let result = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .end_hold(TestDuration(100))
+        .build();
+    assert!(matches!(
+        result,
+        Err(SequenceError::EndHoldWithInfiniteLoop)
+    ));
+}
+
+#[test]
+fn sequence_brightness_scales_evaluated_colors() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .brightness(0.5)
+        .build()
+        .unwrap();
+
+    assert_eq!(sequence.brightness(), 0.5);
+
+    let test_cases = [
+        (0, Srgb::new(0.5, 0.0, 0.0)),
+        (150, Srgb::new(0.25, 0.0, 0.25)),
+        (200, Srgb::new(0.0, 0.0, 0.5)),
+    ];
+
+    for (elapsed, expected) in test_cases {
+        let (color, _) = sequence.evaluate(TestDuration(elapsed));
+        assert!(colors_equal(color, expected), "at {}ms", elapsed);
+    }
+
+    // Clamped to valid range
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .brightness(3.0)
+        .build()
+        .unwrap();
+    assert_eq!(sequence.brightness(), 1.0);
+}
+
+#[test]
+fn step_color_uses_default_transition() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step_color(RED, TestDuration(100))
+        .unwrap()
+        .default_transition(TransitionStyle::Linear)
+        .step_color(GREEN, TestDuration(100))
+        .unwrap()
+        .step_color(BLUE, TestDuration(100))
+        .unwrap()
+        .step(BLACK, TestDuration(100), TransitionStyle::EaseIn)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let expected = [
+        TransitionStyle::Step,
+        TransitionStyle::Linear,
+        TransitionStyle::Linear,
+        TransitionStyle::EaseIn,
+    ];
+    for (index, transition) in expected.into_iter().enumerate() {
+        assert_eq!(sequence.get_step(index).unwrap().transition, transition);
+    }
+
+    // Zero duration with an interpolating default is still rejected at build
+    let result = RgbSequence::<TestDuration, 8>::builder()
+        .default_transition(TransitionStyle::Linear)
+        .step_color(RED, TestDuration(0))
+        .unwrap()
+        .build();
+    assert!(matches!(
+        result,
+        Err(SequenceError::ZeroDurationWithInterpolation)
+    ));
+}
+
+#[test]
+fn loop_from_plays_intro_once_then_loops_body() {
+    // Intro: RED (100ms), then loop GREEN -> BLUE (100ms each) twice
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .loop_from(1)
+        .loop_count(LoopCount::Finite(2))
+        .build()
+        .unwrap();
+
+    assert_eq!(sequence.loop_start(), 1);
+    assert_eq!(sequence.intro_duration(), TestDuration(100));
+    assert_eq!(sequence.loop_duration(), TestDuration(200));
+
+    // (elapsed, expected color, step index, loop)
+    let test_cases = [
+        (0, RED, 0, 0),
+        (100, GREEN, 1, 0),
+        (250, GREEN.mix(BLUE, 0.5), 2, 0),
+        (300, GREEN, 1, 1),
+        (450, GREEN.mix(BLUE, 0.5), 2, 1),
+    ];
+
+    for (elapsed, expected, step_index, current_loop) in test_cases {
+        let (color, _) = sequence.evaluate(TestDuration(elapsed));
+        assert!(colors_equal(color, expected), "color at {}ms", elapsed);
+
+        let position = sequence.find_step_position(TestDuration(elapsed)).unwrap();
+        assert_eq!(position.step_index, step_index, "step at {}ms", elapsed);
+        assert_eq!(position.current_loop, current_loop, "loop at {}ms", elapsed);
+    }
+
+    assert!(!sequence.has_completed(TestDuration(499)));
+    assert!(sequence.has_completed(TestDuration(500)));
+    let (color, timing) = sequence.evaluate(TestDuration(500));
+    assert!(colors_equal(color, BLUE));
+    assert_eq!(timing, None);
+}
+
+#[test]
+fn loop_from_rejects_index_without_looping_steps() {
+    let result = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_from(1)
+        .build();
+
+    assert!(matches!(result, Err(SequenceError::LoopStartOutOfRange)));
+}
+
+#[test]
+fn loop_from_rejects_endless_zero_duration_loop() {
+    let test_cases = [
+        (LoopCount::Infinite, Err(SequenceError::ZeroDurationLoop)),
+        (LoopCount::Finite(3), Ok(())),
+    ];
+
+    for (loop_count, expected) in test_cases {
+        let result = RgbSequence::<TestDuration, 8>::builder()
+            .step(RED, TestDuration(100), TransitionStyle::Linear)
+            .unwrap()
+            .step(BLUE, TestDuration(0), TransitionStyle::Step)
+            .unwrap()
+            .loop_from(1)
+            .loop_count(loop_count)
+            .build();
+        assert_eq!(result.map(|_| ()), expected, "{:?}", loop_count);
+    }
+}
+
+#[test]
+fn from_steps_builds_sequence_from_table() {
+    let table = [
+        (RED, TestDuration(100), TransitionStyle::Step),
+        (BLUE, TestDuration(200), TransitionStyle::Linear),
+    ];
+
+    let sequence = RgbSequence::<TestDuration, 4>::from_steps(&table).unwrap();
+    assert_eq!(sequence.step_count(), 2);
+    assert_eq!(sequence.loop_duration(), TestDuration(300));
+    assert_eq!(sequence.loop_count(), LoopCount::Finite(1));
+
+    assert_eq!(
+        RgbSequence::<TestDuration, 1>::from_steps(&table).err(),
+        Some(SequenceError::CapacityExceeded)
+    );
+    assert_eq!(
+        RgbSequence::<TestDuration, 4>::from_steps(&[]).err(),
+        Some(SequenceError::EmptySequence)
+    );
+
+    let invalid = [(RED, TestDuration(0), TransitionStyle::Linear)];
+    assert_eq!(
+        RgbSequence::<TestDuration, 4>::from_steps(&invalid).err(),
+        Some(SequenceError::ZeroDurationWithInterpolation)
+    );
+}
+
+#[test]
+fn catmull_rom_passes_through_steps_with_smooth_curve() {
+    let gray = |v: f32| Srgb::new(v, v, v);
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(gray(0.0), TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(gray(0.5), TestDuration(100), TransitionStyle::CatmullRom)
+        .unwrap()
+        .step(gray(1.0), TestDuration(100), TransitionStyle::CatmullRom)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // Waypoints are hit exactly; in between the curve bends instead of mixing pairwise
+    let test_cases = [
+        (100, 0.0),
+        (150, 0.21875),
+        (200, 0.5),
+        (250, 0.78125),
+        (300, 1.0),
+    ];
+
+    for (elapsed, expected) in test_cases {
+        let (color, _) = sequence.evaluate(TestDuration(elapsed));
+        assert!(
+            colors_equal(color, gray(expected)),
+            "at {}ms: {:?}",
+            elapsed,
+            color
+        );
+    }
+}
+
+#[test]
+fn builder_sanitizes_nan_and_out_of_range_colors() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(
+            Srgb::new(f32::NAN, 1.5, -0.5),
+            TestDuration(100),
+            TransitionStyle::Linear,
+        )
+        .unwrap()
+        .start_color(Srgb::new(f32::NAN, 0.5, 0.5))
+        .landing_color(Srgb::new(2.0, f32::NAN, 0.0))
+        .build()
+        .unwrap();
+
+    let test_cases = [
+        (
+            sequence.get_step(0).unwrap().color,
+            Srgb::new(0.0, 1.0, 0.0),
+        ),
+        (sequence.start_color().unwrap(), Srgb::new(0.0, 0.5, 0.5)),
+        (sequence.landing_color().unwrap(), Srgb::new(1.0, 0.0, 0.0)),
+    ];
+    for (color, expected) in test_cases {
+        assert!(colors_equal(color, expected), "{:?}", color);
+    }
+}
+
+#[test]
+fn function_sequence_output_is_clamped() {
+    fn overdriven(base: Srgb, _elapsed: TestDuration) -> Srgb {
+        Srgb::new(base.red * 2.0, -1.0, 0.5)
+    }
+    fn once(_elapsed: TestDuration) -> Option<TestDuration> {
+        Some(TestDuration::ZERO)
+    }
+
+    let sequence = RgbSequence::<TestDuration, 8>::from_function(RED, overdriven, once);
+    let (color, _) = sequence.evaluate(TestDuration(0));
+    assert!(colors_equal(color, Srgb::new(1.0, 0.0, 0.5)));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "color function returned NaN")]
+fn function_sequence_nan_trips_debug_assertion() {
+    fn broken(_base: Srgb, _elapsed: TestDuration) -> Srgb {
+        Srgb::new(f32::NAN, 0.0, 0.0)
+    }
+    fn once(_elapsed: TestDuration) -> Option<TestDuration> {
+        Some(TestDuration::ZERO)
+    }
+
+    let sequence = RgbSequence::<TestDuration, 8>::from_function(RED, broken, once);
+    sequence.evaluate(TestDuration(0));
+}
+
+#[test]
+fn channel_levels_report_average_and_peak() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .step(BLACK, TestDuration(200), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let levels = sequence.channel_levels().unwrap();
+    // Red: 100ms full + ~50ms fading out over 400ms; blue: ~50ms over 400ms
+    assert!(colors_equal_epsilon(
+        levels.average,
+        Srgb::new(0.375, 0.0, 0.125),
+        0.005
+    ));
+    assert!(colors_equal(levels.peak, Srgb::new(1.0, 0.0, 1.0)));
+
+    // Sequence brightness scales both
+    let dimmed = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .brightness(0.5)
+        .build()
+        .unwrap();
+    let levels = dimmed.channel_levels().unwrap();
+    assert!(colors_equal(levels.average, Srgb::new(0.5, 0.0, 0.0)));
+    assert!(colors_equal(levels.peak, Srgb::new(0.5, 0.0, 0.0)));
+
+    fn pulse(base: Srgb, _elapsed: TestDuration) -> Srgb {
+        base
+    }
+    fn forever(_elapsed: TestDuration) -> Option<TestDuration> {
+        Some(TestDuration::ZERO)
+    }
+    let function_based = RgbSequence::<TestDuration, 8>::from_function(RED, pulse, forever);
+    assert!(function_based.channel_levels().is_none());
+}
+
+#[test]
+fn steps_from_adds_step_per_color() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .steps_from(
+            rgb_sequencer::colors::wheel(6),
+            TestDuration(100),
+            TransitionStyle::Linear,
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(sequence.step_count(), 6);
+    assert_eq!(sequence.loop_duration(), TestDuration(600));
+    assert!(colors_equal(sequence.get_step(2).unwrap().color, GREEN));
+
+    let result = RgbSequence::<TestDuration, 4>::builder().steps_from(
+        rgb_sequencer::colors::wheel(6),
+        TestDuration(100),
+        TransitionStyle::Linear,
+    );
+    assert!(matches!(result, Err(SequenceError::CapacityExceeded)));
+}
+
+#[test]
+fn sequences_compare_by_content() {
+    fn pulse(base: Srgb, _elapsed: TestDuration) -> Srgb {
+        base
+    }
+
+    fn dim(_base: Srgb, _elapsed: TestDuration) -> Srgb {
+        BLACK
+    }
+
+    fn forever(_elapsed: TestDuration) -> Option<TestDuration> {
+        Some(TestDuration::ZERO)
+    }
+
+    let blink = |color, loops| {
+        RgbSequence::<TestDuration, 8>::builder()
+            .step(color, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+            .step(BLACK, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+            .loop_count(loops)
+            .build()
+            .unwrap()
+    };
+
+    assert_eq!(
+        blink(RED, LoopCount::Infinite),
+        blink(RED, LoopCount::Infinite)
+    );
+    assert_ne!(
+        blink(RED, LoopCount::Infinite),
+        blink(BLUE, LoopCount::Infinite)
+    );
+    assert_ne!(
+        blink(RED, LoopCount::Infinite),
+        blink(RED, LoopCount::Finite(3))
+    );
+
+    let function_based = RgbSequence::<TestDuration, 8>::from_function(RED, pulse, forever);
+    assert_eq!(
+        function_based,
+        RgbSequence::from_function(RED, pulse, forever)
+    );
+    assert_ne!(
+        function_based,
+        RgbSequence::from_function(BLUE, pulse, forever)
+    );
+    assert_ne!(
+        function_based,
+        RgbSequence::from_function(RED, dim, forever)
+    );
+    assert_ne!(function_based, blink(RED, LoopCount::Infinite));
+}
+
+#[test]
+fn views_erase_capacity() {
+    fn fade<const N: usize>() -> RgbSequence<TestDuration, N> {
+        RgbSequence::builder()
+            .step(RED, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+            .step(BLUE, TestDuration(200), TransitionStyle::Linear)
+            .unwrap()
+            .loop_count(LoopCount::Finite(2))
+            .build()
+            .unwrap()
+    }
+
+    // Not generic over capacity
+    fn color_at(sequence: RgbSequenceRef<'_, TestDuration>, millis: u64) -> Srgb {
+        sequence.evaluate(TestDuration(millis)).0
+    }
+
+    let small = fade::<2>();
+    let large = fade::<16>();
+    assert_eq!(small.as_view(), RgbSequenceRef::from(&large));
+
+    for millis in [0, 150, 299, 450, 700] {
+        assert!(colors_equal(
+            color_at(small.as_view(), millis),
+            large.evaluate(TestDuration(millis)).0
+        ));
+    }
+
+    let view = large.as_view();
+    assert_eq!(view.steps().len(), 2);
+    assert_eq!(view.total_duration(), Some(TestDuration(600)));
+    assert!(colors_equal(view.get_step(1).unwrap().color, BLUE));
+}
+
+#[test]
+fn then_and_hold_follow_previous_step() {
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .default_transition(TransitionStyle::Linear)
+        .then(RED, TestDuration(100))
+        .unwrap()
+        .then(BLUE, TestDuration(100))
+        .unwrap()
+        .hold(TestDuration(300))
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::EaseIn)
+        .unwrap()
+        .then(RED, TestDuration(100))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // (color, duration, transition) of every step
+    let expected = [
+        (RED, 100, TransitionStyle::Linear),
+        (BLUE, 100, TransitionStyle::Linear),
+        (BLUE, 300, TransitionStyle::Step),
+        (GREEN, 100, TransitionStyle::EaseIn),
+        (RED, 100, TransitionStyle::EaseIn),
+    ];
+
+    assert_eq!(sequence.step_count(), expected.len());
+    for (index, (color, duration, transition)) in expected.into_iter().enumerate() {
+        let step = sequence.get_step(index).unwrap();
+        assert!(colors_equal(step.color, color), "step {}", index);
+        assert_eq!(step.duration, TestDuration(duration), "step {}", index);
+        assert_eq!(step.transition, transition, "step {}", index);
+    }
+
+    // Holding on an empty builder keeps the LED off
+    let delayed = RgbSequence::<TestDuration, 8>::builder()
+        .hold(TestDuration(50))
+        .unwrap()
+        .then(RED, TestDuration(100))
+        .unwrap()
+        .build()
+        .unwrap();
+    assert!(colors_equal(delayed.get_step(0).unwrap().color, BLACK));
+    assert_eq!(
+        delayed.get_step(1).unwrap().transition,
+        TransitionStyle::Step
+    );
+}
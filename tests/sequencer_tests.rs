@@ -7,8 +7,9 @@ use palette::Srgb;
 use rgb_sequencer::sequence::RgbSequence;
 use rgb_sequencer::types::{LoopCount, TransitionStyle};
 use rgb_sequencer::{
-    DEFAULT_COLOR_EPSILON, RgbSequencer, SequencerError, SequencerState, ServiceTiming,
-    TimeDuration,
+    BrightnessCurve, DEFAULT_COLOR_EPSILON, LoadPolicy, MAX_SPEED, MIN_SPEED, OnComplete, Position,
+    RgbSequencer, SNAPSHOT_SIZE, SequencerAction, SequencerConfig, SequencerError,
+    SequencerSnapshot, SequencerState, ServiceDeadline, ServiceTiming, SnapshotError, TimeDuration,
 };
 
 #[test]
@@ -372,6 +373,60 @@ fn peek_next_timing_requires_running_state() {
     ));
 }
 
+#[test]
+fn peek_next_timing_answers_as_if_started_or_resumed_now() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .build()
+        .unwrap();
+
+    // Loaded: timing from the start of the sequence
+    sequencer.load(sequence);
+    assert_eq!(
+        sequencer.peek_next_timing(),
+        Ok(ServiceTiming::Delay(TestDuration(100)))
+    );
+
+    // Paused: timing from the frozen position, however long the pause lasts
+    sequencer.start().unwrap();
+    sequencer.service().unwrap();
+    timer.advance(TestDuration(30));
+    sequencer.pause().unwrap();
+    timer.advance(TestDuration(500));
+    assert_eq!(
+        sequencer.peek_next_timing(),
+        Ok(ServiceTiming::Delay(TestDuration(70)))
+    );
+
+    // Held: the timeline keeps running
+    sequencer.resume().unwrap();
+    sequencer.hold().unwrap();
+    timer.advance(TestDuration(50));
+    assert_eq!(
+        sequencer.peek_next_timing(),
+        Ok(ServiceTiming::Delay(TestDuration(20)))
+    );
+
+    // Complete: nothing to predict
+    sequencer.resume().unwrap();
+    timer.advance(TestDuration(200));
+    sequencer.service().unwrap();
+    assert_eq!(
+        sequencer.peek_next_timing(),
+        Err(SequencerError::InvalidState {
+            expected: "Running, Held, Paused, or Loaded",
+            actual: SequencerState::Complete
+        })
+    );
+}
+
 #[test]
 fn restart_from_running_state() {
     let led = MockLed::new();
@@ -457,6 +512,44 @@ fn restart_from_complete_state() {
     assert!(colors_equal(sequencer.current_color(), RED));
 }
 
+#[test]
+fn start_from_complete_state_plays_again() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .landing_color(BLUE)
+        .build()
+        .unwrap();
+
+    sequencer.load_and_start(sequence).unwrap();
+    timer.advance(TestDuration(300));
+    sequencer.service().unwrap();
+    assert_eq!(sequencer.state(), SequencerState::Complete);
+
+    // start() behaves like restart(): a fresh run from the current time
+    sequencer.start().unwrap();
+    assert_eq!(sequencer.state(), SequencerState::Running);
+    timer.advance(TestDuration(150));
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+
+    // Still rejected while playing
+    assert!(matches!(
+        sequencer.start(),
+        Err(SequencerError::InvalidState {
+            expected: "Loaded or Complete",
+            actual: SequencerState::Running
+        })
+    ));
+}
+
 #[test]
 fn restart_from_invalid_state_fails() {
     let led = MockLed::new();
@@ -881,6 +974,100 @@ fn current_position_returns_none_for_function_based_sequences() {
     assert_eq!(sequencer.current_position(), None);
 }
 
+#[test]
+fn periodic_function_sequence_reports_position_and_phase() {
+    fn color_fn(base: Srgb, _elapsed: TestDuration) -> Srgb {
+        base
+    }
+
+    fn timing_fn(_elapsed: TestDuration) -> Option<TestDuration> {
+        Some(TestDuration::ZERO)
+    }
+
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let sequence = RgbSequence::<TestDuration, 8>::from_function(RED, color_fn, timing_fn)
+        .with_period(TestDuration(400));
+
+    sequencer.load_and_start(sequence).unwrap();
+    timer.advance(TestDuration(900));
+    assert_eq!(
+        sequencer.current_position(),
+        Some(Position {
+            step_index: 0,
+            loop_number: 2
+        })
+    );
+    assert_eq!(sequencer.current_phase(), Some(0.25));
+
+    // Paused sequencers report the frozen phase
+    sequencer.pause().unwrap();
+    timer.advance(TestDuration(100));
+    assert_eq!(sequencer.current_phase(), Some(0.25));
+
+    sequencer.stop().unwrap();
+    assert_eq!(sequencer.current_phase(), None);
+}
+
+#[test]
+fn sync_to_phase_nudges_toward_reference() {
+    fn color_fn(base: Srgb, _elapsed: TestDuration) -> Srgb {
+        base
+    }
+
+    fn timing_fn(_elapsed: TestDuration) -> Option<TestDuration> {
+        Some(TestDuration::ZERO)
+    }
+
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let beat = TestDuration(400);
+    assert_eq!(
+        sequencer.sync_to_phase(beat, 0.5),
+        Err(SequencerError::InvalidState {
+            expected: "Running",
+            actual: SequencerState::Idle
+        })
+    );
+
+    timer.set_time(TestInstant(1000));
+    let sequence =
+        RgbSequence::<TestDuration, 8>::from_function(RED, color_fn, timing_fn).with_period(beat);
+    sequencer.load_and_start(sequence).unwrap();
+    timer.advance(TestDuration(100));
+    sequencer.set_phase_lock_gain(0.5);
+
+    // (reference phase, returned error, sequence phase afterwards)
+    let test_cases = [
+        (0.5, 0.25, 0.375),   // Lagging: half the error is corrected
+        (0.5, 0.125, 0.4375), // Converging
+        (0.25, -0.1875, 0.345),
+        (1.0, -0.345, 0.1725), // Shorter way round the period
+        (f32::NAN, 0.0, 0.1725),
+    ];
+
+    for (reference, error, phase) in test_cases {
+        assert_eq!(sequencer.sync_to_phase(beat, reference), Ok(error));
+        assert_eq!(
+            sequencer.current_phase(),
+            Some(phase),
+            "reference {}",
+            reference
+        );
+    }
+
+    // Full gain locks at once; playback is never pushed back before it started
+    sequencer.set_phase_lock_gain(2.0);
+    assert_eq!(sequencer.phase_lock_gain(), 1.0);
+    sequencer.sync_to_phase(beat, 0.5).unwrap();
+    assert_eq!(sequencer.current_phase(), Some(0.5));
+    sequencer.restart().unwrap();
+    sequencer.sync_to_phase(beat, 0.9).unwrap();
+    assert_eq!(sequencer.current_phase(), Some(0.0));
+}
+
 #[test]
 fn into_led_preserves_current_color() {
     let led = MockLed::new();
@@ -973,6 +1160,77 @@ fn color_epsilon_is_configurable() {
     assert_eq!(sequencer.color_epsilon(), new_epsilon);
 }
 
+#[test]
+fn with_config_applies_shared_settings() {
+    let timer = MockTimeSource::new();
+    let config = SequencerConfig::new()
+        .color_epsilon(0.01)
+        .brightness(1.5)
+        .linear_output(true)
+        .on_complete(OnComplete::TurnOff)
+        .frame_interval(TestDuration(20))
+        .max_catch_up(TestDuration(100));
+
+    // One config value sets up several LEDs alike
+    for _ in 0..2 {
+        let sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::with_config(
+            MockLed::new(),
+            &timer,
+            config,
+        );
+        assert_eq!(sequencer.color_epsilon(), 0.01);
+        assert_eq!(sequencer.brightness(), 1.0);
+        assert!(sequencer.linear_output());
+        assert_eq!(sequencer.on_complete(), OnComplete::TurnOff);
+        assert_eq!(sequencer.frame_interval(), Some(TestDuration(20)));
+        assert_eq!(sequencer.max_catch_up(), Some(TestDuration(100)));
+        assert_eq!(sequencer.state(), SequencerState::Idle);
+    }
+
+    // The default config matches new()
+    let sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::with_config(
+        MockLed::new(),
+        &timer,
+        SequencerConfig::default(),
+    );
+    assert_eq!(sequencer.color_epsilon(), DEFAULT_COLOR_EPSILON);
+    assert_eq!(sequencer.brightness(), 1.0);
+    assert!(!sequencer.linear_output());
+    assert_eq!(sequencer.on_complete(), OnComplete::HoldColor);
+    assert_eq!(sequencer.frame_interval(), None);
+}
+
+#[test]
+fn output_resolution_suppresses_writes_within_one_level() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    assert_eq!(sequencer.output_resolution(), None);
+    sequencer.set_output_resolution(Some(32));
+    assert_eq!(sequencer.output_resolution(), Some(16));
+    sequencer.set_output_resolution(Some(3));
+
+    let fade = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Linear)
+        .unwrap()
+        .start_color(BLACK)
+        .build()
+        .unwrap();
+    sequencer.load_and_start(fade).unwrap();
+    while sequencer.service().unwrap() != ServiceTiming::Complete {
+        timer.advance(TestDuration(1));
+    }
+
+    // Initial BLACK from construction, then one write per 3-bit level of red
+    let history = sequencer.led().color_history();
+    let levels: Vec<u32> = history
+        .iter()
+        .map(|color| (color.red * 7.0 + 0.5) as u32)
+        .collect();
+    assert_eq!(levels, [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert!(colors_equal(sequencer.current_color(), RED));
+}
+
 #[test]
 fn brightness_defaults_to_full() {
     let led = MockLed::new();
@@ -1133,3 +1391,1945 @@ fn brightness_works_with_linear_transitions() {
     let current = sequencer.current_color();
     assert!(colors_equal(current, Srgb::new(0.25, 0.25, 0.0)));
 }
+
+#[test]
+fn cached_step_lookup_follows_loop_wrap_and_restart() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    sequencer.load(sequence);
+    sequencer.start().unwrap();
+
+    let test_cases = [
+        (0, RED, "step 0"),
+        (50, RED, "same step"),
+        (50, GREEN, "advance one step"),
+        (150, BLUE, "advance to last step"),
+        (100, RED, "wrap into next loop"),
+        (150, BLUE, "skip a step"),
+    ];
+
+    for (advance, expected, desc) in test_cases {
+        timer.advance(TestDuration(advance));
+        sequencer.service().unwrap();
+        assert!(
+            colors_equal(sequencer.current_color(), expected),
+            "{}",
+            desc
+        );
+    }
+
+    // Restart jumps back to step 0
+    sequencer.restart().unwrap();
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), RED));
+}
+
+#[test]
+fn phase_offset_shifts_evaluation_time() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencers = [0, 100, 200].map(|offset| {
+        let mut sequencer =
+            RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+        sequencer.set_phase_offset(TestDuration(offset));
+        sequencer.load_and_start(sequence.clone()).unwrap();
+        sequencer
+    });
+
+    assert_eq!(sequencers[1].phase_offset(), TestDuration(100));
+
+    for sequencer in sequencers.iter_mut() {
+        sequencer.service().unwrap();
+    }
+    assert!(colors_equal(sequencers[0].current_color(), RED));
+    assert!(colors_equal(sequencers[1].current_color(), GREEN));
+    assert!(colors_equal(sequencers[2].current_color(), BLUE));
+    assert_eq!(sequencers[2].current_position().unwrap().step_index, 2);
+
+    // Offset wraps around the loop
+    timer.advance(TestDuration(100));
+    for sequencer in sequencers.iter_mut() {
+        sequencer.service().unwrap();
+    }
+    assert!(colors_equal(sequencers[0].current_color(), GREEN));
+    assert!(colors_equal(sequencers[1].current_color(), BLUE));
+    assert!(colors_equal(sequencers[2].current_color(), RED));
+}
+
+#[test]
+fn start_at_uses_given_start_time() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // Starting from Idle fails
+    assert!(matches!(
+        sequencer.start_at(TestInstant(0)),
+        Err(SequencerError::InvalidState { .. })
+    ));
+
+    timer.set_time(TestInstant(1000));
+    sequencer.load(sequence);
+    sequencer.start_at(TestInstant(850)).unwrap();
+
+    assert_eq!(sequencer.elapsed_time(), Some(TestDuration(150)));
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+}
+
+#[test]
+fn set_remaining_loops_overrides_loop_count_at_runtime() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+
+    // Idle has no sequence to override
+    assert!(matches!(
+        sequencer.set_remaining_loops(LoopCount::Finite(1)),
+        Err(SequencerError::InvalidState { .. })
+    ));
+
+    sequencer.load_and_start(sequence.clone()).unwrap();
+
+    // Infinite: finish the current (second) loop, then stop
+    timer.advance(TestDuration(250));
+    sequencer.set_remaining_loops(LoopCount::Finite(1)).unwrap();
+    assert_eq!(
+        sequencer.current_sequence().unwrap().loop_count(),
+        LoopCount::Finite(2)
+    );
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(50))
+    );
+
+    timer.advance(TestDuration(150));
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Complete);
+    assert_eq!(sequencer.state(), SequencerState::Complete);
+
+    // Finite: extend while paused, then switch back to infinite
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.set_remaining_loops(LoopCount::Finite(1)).unwrap();
+    timer.advance(TestDuration(50));
+    sequencer.pause().unwrap();
+    sequencer.set_remaining_loops(LoopCount::Finite(3)).unwrap();
+    assert_eq!(
+        sequencer.current_sequence().unwrap().loop_count(),
+        LoopCount::Finite(3)
+    );
+
+    sequencer.set_remaining_loops(LoopCount::Infinite).unwrap();
+    sequencer.resume().unwrap();
+    timer.advance(TestDuration(10_000));
+    assert!(sequencer.service().is_ok());
+    assert_eq!(sequencer.state(), SequencerState::Running);
+
+    // Zero remaining completes on the next service call
+    sequencer.set_remaining_loops(LoopCount::Finite(0)).unwrap();
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Complete);
+}
+
+#[test]
+fn skip_current_step_jumps_to_next_step() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(200), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(300), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+
+    // Requires Running state
+    assert!(matches!(
+        sequencer.skip_current_step(),
+        Err(SequencerError::InvalidState { .. })
+    ));
+
+    sequencer.load_and_start(sequence).unwrap();
+    timer.advance(TestDuration(300));
+    sequencer.service().unwrap();
+
+    // Remaining 700ms of red are dropped, green plays its full duration
+    assert_eq!(
+        sequencer.skip_current_step().unwrap(),
+        ServiceTiming::Delay(TestDuration(200))
+    );
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+    assert_eq!(sequencer.current_position().unwrap().step_index, 1);
+
+    timer.advance(TestDuration(200));
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+
+    // Skipping the last step completes the sequence
+    assert_eq!(
+        sequencer.skip_current_step().unwrap(),
+        ServiceTiming::Complete
+    );
+    assert_eq!(sequencer.state(), SequencerState::Complete);
+
+    // Restart discards skipped time
+    sequencer.restart().unwrap();
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), RED));
+}
+
+#[test]
+fn skip_current_step_rejects_function_based_sequence() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+
+    let sequence = RgbSequence::<TestDuration, 8>::from_function(
+        RED,
+        |base, _| base,
+        |_| Some(TestDuration::ZERO),
+    );
+    sequencer.load_and_start(sequence).unwrap();
+
+    assert_eq!(
+        sequencer.skip_current_step(),
+        Err(SequencerError::FunctionBasedSequence)
+    );
+}
+
+#[test]
+fn restart_current_loop_keeps_loop_number() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(3))
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load(sequence);
+
+    // Requires Running or Paused state
+    assert!(matches!(
+        sequencer.restart_current_loop(),
+        Err(SequencerError::InvalidState { .. })
+    ));
+
+    sequencer.start().unwrap();
+    timer.advance(TestDuration(350));
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+
+    sequencer.restart_current_loop().unwrap();
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), RED));
+    assert_eq!(
+        sequencer.current_position(),
+        Some(Position {
+            step_index: 0,
+            loop_number: 1
+        })
+    );
+
+    // Paused rewind takes effect on resume
+    timer.advance(TestDuration(150));
+    sequencer.pause().unwrap();
+    sequencer.restart_current_loop().unwrap();
+    timer.advance(TestDuration(1000));
+    sequencer.resume().unwrap();
+    assert_eq!(sequencer.current_position().unwrap().step_index, 0);
+
+    // Remaining loop count is unchanged: one rewound loop and one more
+    timer.advance(TestDuration(399));
+    assert!(sequencer.service().unwrap() != ServiceTiming::Complete);
+    timer.advance(TestDuration(1));
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Complete);
+}
+
+#[test]
+fn hold_freezes_color_while_timeline_continues() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+
+    sequencer.hold().unwrap();
+    assert!(sequencer.is_held());
+    assert!(matches!(
+        sequencer.hold(),
+        Err(SequencerError::InvalidState { .. })
+    ));
+
+    // LED stays red, but the sequence moves on underneath
+    timer.advance(TestDuration(250));
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(50))
+    );
+    assert_eq!(sequencer.service_if_running(), sequencer.peek_next_timing());
+    assert!(colors_equal(sequencer.current_color(), RED));
+    assert!(colors_equal(sequencer.led().get_last_color(), RED));
+    assert_eq!(sequencer.current_position().unwrap().step_index, 2);
+
+    // Resume continues from the current timeline position, not from where it was held
+    sequencer.resume().unwrap();
+    assert_eq!(sequencer.state(), SequencerState::Running);
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+
+    // Held sequences can be stopped
+    sequencer.hold().unwrap();
+    sequencer.stop().unwrap();
+    assert_eq!(sequencer.state(), SequencerState::Loaded);
+}
+
+#[test]
+fn held_finite_sequence_completes_on_time() {
+    let timer = MockTimeSource::new();
+    let sequence = RgbSequence::<TestDuration, 8>::from_steps(&[(
+        RED,
+        TestDuration(100),
+        TransitionStyle::Step,
+    )])
+    .unwrap();
+
+    let test_cases = [
+        (OnComplete::HoldColor, SequencerState::Complete, RED),
+        (OnComplete::TurnOff, SequencerState::Complete, BLACK),
+        (OnComplete::ReturnToLoaded, SequencerState::Loaded, BLACK),
+    ];
+
+    for (on_complete, state, color) in test_cases {
+        let mut sequencer =
+            RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+        sequencer.set_on_complete(on_complete);
+        sequencer.load_and_start(sequence.clone()).unwrap();
+        sequencer.service().unwrap();
+        sequencer.hold().unwrap();
+
+        timer.advance(TestDuration(100));
+        assert_eq!(
+            sequencer.service_if_running(),
+            Ok(ServiceTiming::Complete),
+            "{:?}",
+            on_complete
+        );
+        assert_eq!(sequencer.state(), state, "{:?}", on_complete);
+        assert!(
+            colors_equal(sequencer.led().get_last_color(), color),
+            "{:?}",
+            on_complete
+        );
+    }
+}
+
+#[test]
+fn override_color_replaces_output_while_sequence_plays() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+
+    // Override is written immediately and survives service calls
+    sequencer.override_color(BLUE);
+    assert_eq!(sequencer.color_override(), Some(BLUE));
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+
+    timer.advance(TestDuration(150));
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(50))
+    );
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+    assert_eq!(sequencer.current_position().unwrap().step_index, 1);
+
+    // Clearing snaps back to where the sequence is now
+    sequencer.clear_override();
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+}
+
+#[test]
+fn override_color_survives_completion_and_off_period() {
+    let timer = MockTimeSource::new();
+    let sequence = RgbSequence::<TestDuration, 8>::from_steps(&[(
+        RED,
+        TestDuration(100),
+        TransitionStyle::Step,
+    )])
+    .unwrap();
+
+    let test_cases = [
+        (OnComplete::TurnOff, None),
+        (OnComplete::ReturnToLoaded, None),
+        (OnComplete::HoldColor, Some(TestDuration(500))),
+        (OnComplete::TurnOff, Some(TestDuration(500))),
+    ];
+
+    for (on_complete, auto_restart) in test_cases {
+        let mut sequencer =
+            RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+        sequencer.set_on_complete(on_complete);
+        sequencer.set_auto_restart(auto_restart);
+        sequencer.load_and_start(sequence.clone()).unwrap();
+        sequencer.service().unwrap();
+        sequencer.override_color(BLUE);
+
+        // Completion or the off-period does not turn the override off
+        timer.advance(TestDuration(150));
+        sequencer.service_if_running().unwrap();
+        if auto_restart.is_some() {
+            timer.advance(TestDuration(500));
+            sequencer.service().unwrap();
+            timer.advance(TestDuration(150));
+            sequencer.service().unwrap();
+        }
+        assert_eq!(sequencer.color_override(), Some(BLUE), "{:?}", on_complete);
+        assert!(
+            colors_equal(sequencer.led().get_last_color(), BLUE),
+            "{:?}",
+            on_complete
+        );
+
+        // Clearing it shows that playback has turned the LED off
+        sequencer.clear_override();
+        assert!(
+            colors_equal(sequencer.led().get_last_color(), BLACK),
+            "{:?}",
+            on_complete
+        );
+        assert!(colors_equal(sequencer.current_color(), BLACK));
+    }
+}
+
+#[test]
+fn clear_override_with_fade_blends_back_to_sequence() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.override_color(BLUE);
+
+    sequencer.clear_override_with_fade(TestDuration(100));
+    assert_eq!(sequencer.color_override(), None);
+
+    timer.advance(TestDuration(50));
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Continuous);
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.5, 0.0, 0.5)
+    ));
+
+    timer.advance(TestDuration(50));
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(900))
+    );
+    assert!(colors_equal(sequencer.current_color(), RED));
+}
+
+#[test]
+fn disabled_output_blacks_out_led_without_affecting_timeline() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let led = MockLed::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+    assert!(sequencer.is_output_enabled());
+
+    sequencer.set_output_enabled(false);
+    assert!(colors_equal(sequencer.current_color(), RED));
+
+    // Timeline keeps running, LED receives no writes
+    timer.advance(TestDuration(150));
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(50))
+    );
+    assert_eq!(sequencer.state(), SequencerState::Running);
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+
+    // Re-enabling writes the color the sequence has reached
+    sequencer.set_output_enabled(true);
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+
+    let history = sequencer.into_led().color_history().to_vec();
+    let expected = [BLACK, RED, BLACK, GREEN];
+    assert_eq!(history.len(), expected.len());
+    for (actual, expected) in history.iter().zip(expected.iter()) {
+        assert!(colors_equal(*actual, *expected));
+    }
+}
+
+#[test]
+fn reenabling_output_restores_a_paused_color() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer
+        .load_and_start(RgbSequence::<TestDuration, 8>::solid(RED).unwrap())
+        .unwrap();
+    sequencer.service().unwrap();
+    sequencer.pause().unwrap();
+
+    sequencer.set_output_enabled(false);
+    assert!(colors_equal(sequencer.led().get_last_color(), BLACK));
+    sequencer.set_output_enabled(true);
+    assert!(colors_equal(sequencer.led().get_last_color(), RED));
+
+    // Stop and clear write nothing while output is disabled
+    sequencer.set_output_enabled(false);
+    let writes = sequencer.led().color_history().len();
+    sequencer.stop().unwrap();
+    sequencer.clear();
+    assert_eq!(sequencer.led().color_history().len(), writes);
+    assert!(colors_equal(sequencer.current_color(), BLACK));
+}
+
+#[test]
+fn fade_in_ramps_brightness_for_step_sequences() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.set_brightness(0.5);
+    sequencer.set_fade_in(TestDuration(200));
+    assert_eq!(sequencer.fade_in(), TestDuration(200));
+    sequencer.load_and_start(sequence).unwrap();
+
+    let test_cases = [
+        (0, 0.0, ServiceTiming::Continuous),
+        (100, 0.25, ServiceTiming::Continuous),
+        (200, 0.5, ServiceTiming::Delay(TestDuration(800))),
+    ];
+
+    for (time, red, timing) in test_cases {
+        timer.set_time(TestInstant(time));
+        assert_eq!(sequencer.service().unwrap(), timing, "at {}ms", time);
+        assert!(
+            colors_equal(sequencer.current_color(), Srgb::new(red, 0.0, 0.0)),
+            "at {}ms",
+            time
+        );
+    }
+
+    // Restart fades in again
+    sequencer.restart().unwrap();
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Continuous);
+    assert!(colors_equal(sequencer.current_color(), BLACK));
+}
+
+#[test]
+fn fade_in_runs_in_real_time_across_speed_changes_and_pauses() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.set_fade_in(TestDuration(200));
+    sequencer.load_and_start(sequence).unwrap();
+
+    // 50ms at 4x plays 200ms of sequence, but only 50ms of the fade
+    timer.set_time(TestInstant(50));
+    sequencer.set_speed(4.0);
+    timer.set_time(TestInstant(100));
+    sequencer.set_speed(1.0);
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Continuous);
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.5, 0.0, 0.0)
+    ));
+
+    // Time spent paused does not count toward the fade
+    sequencer.pause().unwrap();
+    timer.set_time(TestInstant(300));
+    sequencer.resume().unwrap();
+    timer.set_time(TestInstant(350));
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Continuous);
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.75, 0.0, 0.0)
+    ));
+}
+
+#[test]
+fn on_complete_policy_controls_final_output() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .landing_color(BLUE)
+        .build()
+        .unwrap();
+
+    let test_cases = [
+        (OnComplete::HoldColor, SequencerState::Complete, BLUE),
+        (OnComplete::TurnOff, SequencerState::Complete, BLACK),
+        (OnComplete::ReturnToLoaded, SequencerState::Loaded, BLACK),
+    ];
+
+    for (behavior, state, color) in test_cases {
+        timer.set_time(TestInstant(0));
+        let mut sequencer =
+            RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+        assert_eq!(sequencer.on_complete(), OnComplete::HoldColor);
+        sequencer.set_on_complete(behavior);
+        sequencer.load_and_start(sequence.clone()).unwrap();
+        sequencer.service().unwrap();
+
+        timer.advance(TestDuration(100));
+        assert_eq!(sequencer.service().unwrap(), ServiceTiming::Complete);
+        assert_eq!(sequencer.state(), state, "{:?}", behavior);
+        assert!(
+            colors_equal(sequencer.current_color(), color),
+            "{:?}",
+            behavior
+        );
+
+        // Landing color is never flashed before turning off
+        let history = sequencer.into_led().color_history().to_vec();
+        assert!(colors_equal(*history.last().unwrap(), color));
+        assert_eq!(history.len(), 3, "{:?}", behavior);
+    }
+}
+
+#[test]
+fn service_deadline_reports_absolute_instants() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .build()
+        .unwrap();
+
+    timer.set_time(TestInstant(1000));
+    sequencer.load_and_start(sequence).unwrap();
+
+    // (time of the service call, expected deadline)
+    let test_cases = [
+        (1000, ServiceDeadline::At(TestInstant(1100))),
+        (1030, ServiceDeadline::At(TestInstant(1100))),
+        (1150, ServiceDeadline::Continuous),
+        (1200, ServiceDeadline::Complete),
+    ];
+    for (now, expected) in test_cases {
+        timer.set_time(TestInstant(now));
+        assert_eq!(sequencer.service_deadline(), Ok(expected), "at {}ms", now);
+    }
+    assert!(sequencer.service_deadline().is_err());
+}
+
+#[test]
+fn frame_interval_replaces_continuous_timing() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .build()
+        .unwrap();
+    assert_eq!(sequencer.frame_interval(), None);
+    sequencer.set_frame_interval(Some(TestDuration(40)));
+    sequencer.load_and_start(sequence).unwrap();
+
+    // (time, expected timing): holds keep their own delay, fades are paced
+    let test_cases = [
+        (0, ServiceTiming::Delay(TestDuration(100))),
+        (100, ServiceTiming::Delay(TestDuration(40))),
+        (140, ServiceTiming::Delay(TestDuration(40))),
+        (200, ServiceTiming::Complete),
+    ];
+    for (time, expected) in test_cases {
+        timer.set_time(TestInstant(time));
+        if time == 140 {
+            assert_eq!(sequencer.peek_next_timing().unwrap(), expected);
+        }
+        assert_eq!(sequencer.service().unwrap(), expected, "at {}ms", time);
+    }
+}
+
+#[test]
+fn service_if_running_is_benign_outside_running() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .build()
+        .unwrap();
+
+    // Idle, Loaded
+    assert_eq!(sequencer.service_if_running(), Ok(ServiceTiming::Complete));
+    sequencer.load(sequence);
+    assert_eq!(sequencer.service_if_running(), Ok(ServiceTiming::Complete));
+
+    // Running services normally
+    sequencer.start().unwrap();
+    assert_eq!(
+        sequencer.service_if_running(),
+        Ok(ServiceTiming::Delay(TestDuration(100)))
+    );
+    assert!(colors_equal(sequencer.current_color(), RED));
+
+    // Paused, then Complete
+    sequencer.pause().unwrap();
+    assert_eq!(sequencer.service_if_running(), Ok(ServiceTiming::Complete));
+    sequencer.resume().unwrap();
+    timer.advance(TestDuration(100));
+    assert_eq!(sequencer.service_if_running(), Ok(ServiceTiming::Complete));
+    assert_eq!(sequencer.state(), SequencerState::Complete);
+    assert_eq!(sequencer.service_if_running(), Ok(ServiceTiming::Complete));
+    assert!(sequencer.service().is_err());
+}
+
+#[test]
+fn load_policy_controls_loading_while_running() {
+    let timer = MockTimeSource::new();
+    let solid = |color| RgbSequence::<TestDuration, 8>::solid(color).unwrap();
+
+    let test_cases = [
+        (LoadPolicy::Replace, Ok(()), SequencerState::Loaded, RED),
+        (
+            LoadPolicy::StartImmediately,
+            Ok(()),
+            SequencerState::Running,
+            BLUE,
+        ),
+        (
+            LoadPolicy::Reject,
+            Err(SequencerError::InvalidState {
+                expected: "Idle, Loaded, Paused, Held, or Complete",
+                actual: SequencerState::Running,
+            }),
+            SequencerState::Running,
+            RED,
+        ),
+    ];
+
+    for (policy, result, state, color) in test_cases {
+        let mut sequencer =
+            RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+        assert_eq!(sequencer.load_policy(), LoadPolicy::Replace);
+        sequencer.set_load_policy(policy);
+        sequencer.load_and_start(solid(RED)).unwrap();
+        sequencer.service().unwrap();
+
+        assert_eq!(sequencer.try_load(solid(BLUE)), result, "{:?}", policy);
+        assert_eq!(sequencer.state(), state, "{:?}", policy);
+        if sequencer.is_running() {
+            sequencer.service().unwrap();
+        }
+        assert!(
+            colors_equal(sequencer.current_color(), color),
+            "{:?}",
+            policy
+        );
+
+        // Outside Running the policy does not apply
+        sequencer.pause().ok();
+        sequencer.load(solid(GREEN));
+        assert_eq!(sequencer.state(), SequencerState::Loaded, "{:?}", policy);
+    }
+
+    // load() never rejects
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.set_load_policy(LoadPolicy::Reject);
+    sequencer.load_and_start(solid(RED)).unwrap();
+    sequencer.load(solid(BLUE));
+    assert_eq!(sequencer.state(), SequencerState::Loaded);
+    assert!(colors_equal(
+        sequencer
+            .current_sequence()
+            .unwrap()
+            .get_step(0)
+            .unwrap()
+            .color,
+        BLUE
+    ));
+}
+
+#[test]
+fn auto_restart_repeats_after_off_period() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .landing_color(BLUE)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.set_auto_restart(Some(TestDuration(1000)));
+    assert_eq!(sequencer.auto_restart(), Some(TestDuration(1000)));
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+
+    // Completion turns the LED off instead of showing the landing color
+    timer.set_time(TestInstant(200));
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(1000))
+    );
+    assert_eq!(sequencer.state(), SequencerState::Running);
+    assert!(colors_equal(sequencer.current_color(), BLACK));
+
+    timer.set_time(TestInstant(700));
+    assert_eq!(
+        sequencer.peek_next_timing().unwrap(),
+        ServiceTiming::Delay(TestDuration(500))
+    );
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(500))
+    );
+
+    // Sequence starts over at the end of the off-period
+    timer.set_time(TestInstant(1350));
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+
+    // Disabling auto-restart lets the next completion finish normally
+    sequencer.set_auto_restart(None);
+    timer.set_time(TestInstant(1400));
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Complete);
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+}
+
+#[test]
+fn start_paused_shows_first_frame_until_resumed() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+
+    // Requires Loaded state
+    assert!(matches!(
+        sequencer.start_paused(),
+        Err(SequencerError::InvalidState { .. })
+    ));
+
+    sequencer.load(sequence);
+    sequencer.start_paused().unwrap();
+    assert_eq!(sequencer.state(), SequencerState::Paused);
+    assert!(colors_equal(sequencer.current_color(), RED));
+
+    // Waiting for the trigger does not advance the sequence
+    timer.advance(TestDuration(5000));
+    sequencer.resume().unwrap();
+    timer.advance(TestDuration(150));
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+}
+
+#[test]
+fn max_catch_up_clamps_jump_after_stall() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .step(BLUE, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.set_max_catch_up(Some(TestDuration(50)));
+    assert_eq!(sequencer.max_catch_up(), Some(TestDuration(50)));
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+
+    // 5s stall during a continuous transition only advances 50ms
+    timer.advance(TestDuration(5000));
+    sequencer.service().unwrap();
+    assert_eq!(sequencer.current_position().unwrap().step_index, 0);
+    assert_eq!(sequencer.elapsed_time(), Some(TestDuration(50)));
+
+    // Sleeping for a hinted delay is not a stall
+    for _ in 0..2 {
+        timer.advance(TestDuration(50));
+        sequencer.service().unwrap();
+    }
+    timer.advance(TestDuration(50));
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(1000))
+    );
+    timer.advance(TestDuration(1000));
+    sequencer.service().unwrap();
+    assert_eq!(sequencer.elapsed_time(), Some(TestDuration(1200)));
+    assert_eq!(
+        sequencer.current_position().unwrap(),
+        Position {
+            step_index: 0,
+            loop_number: 1
+        }
+    );
+}
+
+#[test]
+fn restore_replaces_a_loaded_chain() {
+    let timer = MockTimeSource::new();
+    let segment = |color| {
+        RgbSequence::<TestDuration, 8>::builder()
+            .step(color, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+            .build()
+            .unwrap()
+    };
+    let segments = [segment(RED), segment(GREEN)];
+
+    // Snapshot of a plain sequence, halfway through
+    let mut source =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    source.load_and_start(segment(BLUE)).unwrap();
+    timer.set_time(TestInstant(50));
+    source.service().unwrap();
+    let snapshot = source.snapshot();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer
+        .load_chain(&segments, LoopCount::Finite(1))
+        .unwrap();
+    sequencer.start().unwrap();
+    sequencer.restore(snapshot);
+    assert_eq!(sequencer.current_segment(), None);
+
+    // Completing the restored sequence does not swap in a stale chain segment
+    timer.set_time(TestInstant(150));
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Complete);
+    assert_eq!(sequencer.state(), SequencerState::Complete);
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+}
+
+#[test]
+fn restore_with_output_disabled_writes_nothing() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer
+        .load_and_start(RgbSequence::<TestDuration, 8>::solid(RED).unwrap())
+        .unwrap();
+    sequencer.service().unwrap();
+    sequencer.set_output_enabled(false);
+    let snapshot = sequencer.snapshot();
+
+    let mut restored =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let writes = restored.led().color_history().len();
+    restored.restore(snapshot);
+
+    assert!(!restored.is_output_enabled());
+    assert_eq!(restored.led().color_history().len(), writes);
+}
+
+#[test]
+fn snapshot_restore_resumes_on_new_sequencer() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(3))
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.set_brightness(0.5);
+    sequencer.load_and_start(sequence).unwrap();
+    timer.advance(TestDuration(450));
+    sequencer.service().unwrap();
+
+    let snapshot = sequencer.snapshot();
+    assert_eq!(snapshot.state(), SequencerState::Running);
+    assert_eq!(snapshot.elapsed(), Some(TestDuration(450)));
+    let led = sequencer.into_led();
+
+    // Time source restarts from zero after wake
+    let wake_timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &wake_timer);
+    sequencer.restore(snapshot);
+
+    assert_eq!(sequencer.brightness(), 0.5);
+    assert_eq!(
+        sequencer.current_position(),
+        Some(Position {
+            step_index: 1,
+            loop_number: 1
+        })
+    );
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(50))
+    );
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.0, 0.5, 0.0)
+    ));
+
+    // Paused snapshots restore paused at the frozen position
+    sequencer.pause().unwrap();
+    wake_timer.advance(TestDuration(1000));
+    let snapshot = sequencer.snapshot();
+    assert_eq!(snapshot.elapsed(), Some(TestDuration(450)));
+
+    sequencer.clear();
+    sequencer.restore(snapshot);
+    assert_eq!(sequencer.state(), SequencerState::Paused);
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.0, 0.5, 0.0)
+    ));
+
+    sequencer.resume().unwrap();
+    wake_timer.advance(TestDuration(50));
+    sequencer.service().unwrap();
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.0, 0.0, 0.5)
+    ));
+}
+
+#[test]
+fn snapshot_bytes_round_trip_across_reboot() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.set_brightness(0.25);
+    sequencer.set_on_complete(OnComplete::TurnOff);
+    sequencer.set_auto_restart(Some(TestDuration(500)));
+    sequencer.load_and_start(sequence.clone()).unwrap();
+    timer.advance(TestDuration(1_000_150));
+    sequencer.service().unwrap();
+
+    let bytes: [u8; SNAPSHOT_SIZE] = sequencer.snapshot().to_bytes();
+
+    // Sequence is not stored in the bytes and must be supplied again
+    assert_eq!(
+        SequencerSnapshot::<TestDuration, 8>::from_bytes(&bytes, None).unwrap_err(),
+        SnapshotError::MissingSequence
+    );
+
+    let snapshot = SequencerSnapshot::from_bytes(&bytes, Some(sequence)).unwrap();
+    assert_eq!(snapshot.elapsed(), Some(TestDuration(1_000_150)));
+
+    let reboot_timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &reboot_timer);
+    sequencer.restore(snapshot);
+
+    assert_eq!(sequencer.brightness(), 0.25);
+    assert_eq!(sequencer.on_complete(), OnComplete::TurnOff);
+    assert_eq!(sequencer.auto_restart(), Some(TestDuration(500)));
+    assert_eq!(sequencer.max_catch_up(), None);
+    assert!(sequencer.is_output_enabled());
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(50))
+    );
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.0, 0.25, 0.0)
+    ));
+}
+
+#[test]
+fn snapshot_from_bytes_rejects_bad_data() {
+    let timer = MockTimeSource::new();
+    let sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let bytes = sequencer.snapshot().to_bytes();
+
+    let test_cases = [
+        (0, 99, SnapshotError::UnsupportedVersion(99)),
+        (1, 42, SnapshotError::InvalidData),
+        (3, 7, SnapshotError::InvalidData),
+    ];
+
+    for (index, value, expected) in test_cases {
+        let mut corrupt = bytes;
+        corrupt[index] = value;
+        assert_eq!(
+            SequencerSnapshot::<TestDuration, 8>::from_bytes(&corrupt, None).unwrap_err(),
+            expected
+        );
+    }
+
+    assert!(SequencerSnapshot::<TestDuration, 8>::from_bytes(&bytes, None).is_ok());
+
+    // Flags that contradict the saved state
+    let sequence = RgbSequence::<TestDuration, 8>::solid(RED).unwrap();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load_and_start(sequence.clone()).unwrap();
+    let bytes = sequencer.snapshot().to_bytes();
+    assert!(SequencerSnapshot::from_bytes(&bytes, Some(sequence.clone())).is_ok());
+
+    let test_cases = [
+        (0b0000_0001, 0b0000_0000), // Running without elapsed
+        (0b0001_0000, 0b0000_0010), // Off-period without auto-restart
+    ];
+
+    for (clear, set) in test_cases {
+        let mut corrupt = bytes;
+        corrupt[2] = (corrupt[2] & !clear) | set;
+        assert_eq!(
+            SequencerSnapshot::from_bytes(&corrupt, Some(sequence.clone())).unwrap_err(),
+            SnapshotError::InvalidData,
+            "{:#010b}",
+            corrupt[2]
+        );
+    }
+}
+
+#[test]
+fn current_step_color_reports_target_not_interpolated_color() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load(sequence);
+    assert_eq!(sequencer.current_step_color(), None);
+    assert_eq!(sequencer.current_transition(), None);
+
+    sequencer.start().unwrap();
+    timer.advance(TestDuration(150));
+    sequencer.service().unwrap();
+
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.5, 0.0, 0.5)
+    ));
+    assert!(colors_equal(sequencer.current_step_color().unwrap(), BLUE));
+    assert_eq!(
+        sequencer.current_transition(),
+        Some(TransitionStyle::Linear)
+    );
+}
+
+#[test]
+fn time_in_current_step_tracks_step_progress() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(300), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load(sequence);
+    assert_eq!(sequencer.time_in_current_step(), None);
+    sequencer.start().unwrap();
+
+    let test_cases = [(0, 0), (50, 50), (100, 0), (350, 250), (420, 20)];
+
+    for (time, expected) in test_cases {
+        timer.set_time(TestInstant(time));
+        assert_eq!(
+            sequencer.time_in_current_step(),
+            Some(TestDuration(expected)),
+            "at {}ms",
+            time
+        );
+    }
+}
+
+#[test]
+fn loops_completed_and_remaining_track_finite_sequence() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLACK, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(3))
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load(sequence);
+    assert_eq!(sequencer.loops_completed(), None);
+    assert_eq!(sequencer.loops_remaining(), None);
+    sequencer.start().unwrap();
+
+    let test_cases = [
+        (0, 0, 3),
+        (150, 0, 3),
+        (200, 1, 2),
+        (599, 2, 1),
+        (600, 3, 0),
+    ];
+
+    for (time, completed, remaining) in test_cases {
+        timer.set_time(TestInstant(time));
+        assert_eq!(
+            sequencer.loops_completed(),
+            Some(completed),
+            "at {}ms",
+            time
+        );
+        assert_eq!(
+            sequencer.loops_remaining(),
+            Some(remaining),
+            "at {}ms",
+            time
+        );
+    }
+
+    sequencer.service().unwrap();
+    assert_eq!(sequencer.state(), SequencerState::Complete);
+    assert_eq!(sequencer.loops_completed(), Some(3));
+    assert_eq!(sequencer.loops_remaining(), Some(0));
+}
+
+#[test]
+fn loops_remaining_is_none_for_infinite_sequence() {
+    let timer = MockTimeSource::new();
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.load_and_start(sequence).unwrap();
+    timer.advance(TestDuration(1050));
+
+    assert_eq!(sequencer.loops_completed(), Some(10));
+    assert_eq!(sequencer.loops_remaining(), None);
+}
+
+#[test]
+fn linear_output_converts_written_colors() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let gray = Srgb::new(0.5, 0.5, 0.5);
+    sequencer.load(RgbSequence::<TestDuration, 8>::solid(gray).unwrap());
+    sequencer.set_linear_output(true);
+    assert!(sequencer.linear_output());
+    sequencer.start().unwrap();
+    sequencer.service().unwrap();
+
+    // Change detection still works on the sRGB value
+    assert!(colors_equal(sequencer.current_color(), gray));
+
+    // Disabling rewrites the LED without the conversion
+    sequencer.set_linear_output(false);
+
+    let history = sequencer.into_led().color_history().to_vec();
+    let linear_gray = Srgb::new(0.21404, 0.21404, 0.21404);
+    assert!(colors_equal(history[history.len() - 2], linear_gray));
+    assert!(colors_equal(history[history.len() - 1], gray));
+}
+
+#[test]
+fn cie_brightness_curve_maps_perceived_lightness() {
+    let test_cases = [
+        (BrightnessCurve::Linear, 0.5, 0.5),
+        (BrightnessCurve::Cie1931, 0.0, 0.0),
+        (BrightnessCurve::Cie1931, 0.05, 0.005535),
+        (BrightnessCurve::Cie1931, 0.5, 0.184186),
+        (BrightnessCurve::Cie1931, 1.0, 1.0),
+    ];
+
+    for (curve, level, expected) in test_cases {
+        let factor = curve.apply(level);
+        assert!(
+            (factor - expected).abs() < 0.0001,
+            "{:?} at {}: {}",
+            curve,
+            level,
+            factor
+        );
+    }
+
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+    assert_eq!(sequencer.brightness_curve(), BrightnessCurve::Linear);
+
+    sequencer.load(RgbSequence::<TestDuration, 8>::solid(RED).unwrap());
+    sequencer.set_brightness(0.5);
+    sequencer.set_brightness_curve(BrightnessCurve::Cie1931);
+    sequencer.start().unwrap();
+    sequencer.service().unwrap();
+
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.184186, 0.0, 0.0)
+    ));
+}
+
+#[test]
+fn white_balance_scales_output_without_suppressing_updates() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(
+            Srgb::new(1.0, 1.0, 1.0),
+            TestDuration(100),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .step(
+            Srgb::new(1.0, 0.99, 1.0),
+            TestDuration(100),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+
+    sequencer.set_white_balance(1.0, 0.01, 2.0);
+    assert_eq!(sequencer.white_balance(), (1.0, 0.01, 1.0));
+
+    sequencer.load(sequence);
+    sequencer.start().unwrap();
+    sequencer.service().unwrap();
+    timer.advance(TestDuration(100));
+    sequencer.service().unwrap();
+
+    // Second write happens even though the scaled green change is below epsilon
+    let history = sequencer.into_led().color_history().to_vec();
+    let test_cases = [
+        (history.len() - 2, Srgb::new(1.0, 0.01, 1.0)),
+        (history.len() - 1, Srgb::new(1.0, 0.0099, 1.0)),
+    ];
+    for (index, expected) in test_cases {
+        assert!(
+            colors_equal_epsilon(history[index], expected, 0.00001),
+            "write {}: {:?}",
+            index,
+            history[index]
+        );
+    }
+}
+
+#[test]
+fn output_limits_cap_written_colors() {
+    fn halve_blue(color: Srgb) -> Srgb {
+        Srgb::new(color.red, color.green, color.blue * 0.5)
+    }
+
+    type Limiter = fn(Srgb) -> Srgb;
+
+    // (max channel sum, limiter, expected LED color for white)
+    let test_cases: [(Option<f32>, Option<Limiter>, Srgb); 4] = [
+        (None, None, Srgb::new(1.0, 1.0, 1.0)),
+        (Some(1.5), None, Srgb::new(0.5, 0.5, 0.5)),
+        (None, Some(halve_blue), Srgb::new(1.0, 1.0, 0.5)),
+        (Some(1.25), Some(halve_blue), Srgb::new(0.5, 0.5, 0.25)),
+    ];
+
+    for (max_sum, limiter, expected) in test_cases {
+        let led = MockLed::new();
+        let timer = MockTimeSource::new();
+        let mut sequencer =
+            RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+        sequencer.set_max_channel_sum(max_sum);
+        sequencer.set_output_filter(limiter);
+        assert_eq!(sequencer.max_channel_sum(), max_sum);
+
+        let white = Srgb::new(1.0, 1.0, 1.0);
+        sequencer.load(RgbSequence::<TestDuration, 8>::solid(white).unwrap());
+        sequencer.start().unwrap();
+        sequencer.service().unwrap();
+
+        // Change detection keeps the unlimited color
+        assert!(colors_equal(sequencer.current_color(), white));
+        let led = sequencer.into_led();
+        assert!(
+            colors_equal(led.get_last_color(), expected),
+            "{:?} / limiter {}: {:?}",
+            max_sum,
+            limiter.is_some(),
+            led.get_last_color()
+        );
+    }
+}
+
+#[test]
+fn output_filter_runs_after_brightness_and_before_limits() {
+    fn swap_red_blue(color: Srgb) -> Srgb {
+        Srgb::new(color.blue, color.green, color.red)
+    }
+    fn double(color: Srgb) -> Srgb {
+        Srgb::new(color.red * 2.0, color.green * 2.0, color.blue * 2.0)
+    }
+
+    type Filter = fn(Srgb) -> Srgb;
+
+    // (filter, max channel sum, expected LED color for red at half brightness)
+    let test_cases: [(Option<Filter>, Option<f32>, Srgb); 4] = [
+        (None, None, Srgb::new(0.5, 0.0, 0.0)),
+        (Some(swap_red_blue), None, Srgb::new(0.0, 0.0, 0.5)),
+        // Clamped to 0.0-1.0, and unable to lift the channel sum cap
+        (Some(double), None, Srgb::new(1.0, 0.0, 0.0)),
+        (Some(double), Some(0.6), Srgb::new(0.6, 0.0, 0.0)),
+    ];
+
+    for (filter, max_sum, expected) in test_cases {
+        let timer = MockTimeSource::new();
+        let mut sequencer =
+            RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+        sequencer.set_output_filter(filter);
+        sequencer.set_max_channel_sum(max_sum);
+        sequencer.set_brightness(0.5);
+        assert_eq!(sequencer.output_filter().is_some(), filter.is_some());
+
+        sequencer.load(RgbSequence::<TestDuration, 8>::solid(RED).unwrap());
+        sequencer.start().unwrap();
+        sequencer.service().unwrap();
+
+        assert!(colors_equal(
+            sequencer.current_color(),
+            Srgb::new(0.5, 0.0, 0.0)
+        ));
+        assert!(
+            colors_equal(sequencer.led().get_last_color(), expected),
+            "{:?}",
+            sequencer.led().get_last_color()
+        );
+    }
+
+    // Overrides pass through the filter too
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    sequencer.set_output_filter(Some(swap_red_blue));
+    sequencer.override_color(RED);
+    assert!(colors_equal(sequencer.led().get_last_color(), BLUE));
+}
+
+#[test]
+fn max_brightness_caps_every_write() {
+    fn double(color: Srgb) -> Srgb {
+        Srgb::new(color.red * 2.0, color.green * 2.0, color.blue * 2.0)
+    }
+
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    assert_eq!(sequencer.max_brightness(), 1.0);
+    sequencer.set_max_brightness(f32::NAN);
+    assert_eq!(sequencer.max_brightness(), 1.0);
+    sequencer.set_max_brightness(0.5);
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(
+            Srgb::new(1.0, 0.5, 0.0),
+            TestDuration(100),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .step(
+            Srgb::new(0.2, 0.1, 0.0),
+            TestDuration(100),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+    sequencer.load_and_start(sequence).unwrap();
+
+    // Brighter colors are scaled down keeping their hue, dimmer ones pass unchanged
+    let service_at = |sequencer: &mut RgbSequencer<_, _, _, 8>, millis| {
+        timer.set_time(TestInstant(millis));
+        sequencer.service().unwrap();
+    };
+    service_at(&mut sequencer, 0);
+    assert!(colors_equal(
+        sequencer.led().get_last_color(),
+        Srgb::new(0.5, 0.25, 0.0)
+    ));
+    service_at(&mut sequencer, 100);
+    assert!(colors_equal(
+        sequencer.led().get_last_color(),
+        Srgb::new(0.2, 0.1, 0.0)
+    ));
+
+    // Brightness, filters and overrides cannot lift the ceiling
+    sequencer
+        .handle_action(SequencerAction::SetBrightness(1.0))
+        .unwrap();
+    sequencer.set_output_filter(Some(double));
+    service_at(&mut sequencer, 200);
+    assert!(colors_equal(
+        sequencer.led().get_last_color(),
+        Srgb::new(0.5, 0.5, 0.0)
+    ));
+    sequencer.override_color(Srgb::new(1.0, 1.0, 1.0));
+    assert!(colors_equal(
+        sequencer.led().get_last_color(),
+        Srgb::new(0.5, 0.5, 0.5)
+    ));
+}
+
+#[test]
+fn speed_scales_playback_without_jumping() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer
+        .handle_action(SequencerAction::SetSpeed(2.0))
+        .unwrap();
+    assert_eq!(sequencer.speed(), 2.0);
+
+    // (wall time, speed change applied first, expected color, expected hint)
+    let test_cases = [
+        (250, None, RED, ServiceTiming::Delay(TestDuration(250))),
+        (600, None, GREEN, ServiceTiming::Delay(TestDuration(400))),
+        // Halving speed at 1200ms of sequence time keeps the position
+        (
+            600,
+            Some(0.5),
+            GREEN,
+            ServiceTiming::Delay(TestDuration(1600)),
+        ),
+        (1000, None, GREEN, ServiceTiming::Delay(TestDuration(1200))),
+        (2200, None, BLUE, ServiceTiming::Delay(TestDuration(2000))),
+    ];
+
+    for (time, speed, expected_color, expected_timing) in test_cases {
+        timer.set_time(TestInstant(time));
+        if let Some(speed) = speed {
+            sequencer.set_speed(speed);
+        }
+        let timing = sequencer.service().unwrap();
+        assert!(
+            colors_equal(sequencer.current_color(), expected_color),
+            "at {}ms",
+            time
+        );
+        assert_eq!(timing, expected_timing, "at {}ms", time);
+    }
+
+    // Changing speed while paused keeps the frozen position
+    sequencer.pause().unwrap();
+    timer.advance(TestDuration(5000));
+    sequencer.set_speed(4.0);
+    sequencer.resume().unwrap();
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(250))
+    );
+    assert!(colors_equal(sequencer.current_color(), BLUE));
+}
+
+#[test]
+fn set_speed_clamps_and_ignores_nan() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+    assert_eq!(sequencer.speed(), 1.0);
+
+    let test_cases = [
+        (0.0, MIN_SPEED),
+        (-2.0, MIN_SPEED),
+        (1000.0, MAX_SPEED),
+        (f32::NAN, MAX_SPEED),
+        (3.0, 3.0),
+    ];
+
+    for (input, expected) in test_cases {
+        sequencer.set_speed(input);
+        assert_eq!(sequencer.speed(), expected, "input {}", input);
+    }
+}
+
+#[test]
+fn load_and_start_action_starts_from_any_state() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let solid = |color| RgbSequence::<TestDuration, 8>::solid(color).unwrap();
+
+    // From Idle, and replacing a running sequence without passing through Loaded
+    for color in [RED, GREEN] {
+        sequencer
+            .handle_action(SequencerAction::LoadAndStart(solid(color)))
+            .unwrap();
+        assert_eq!(sequencer.state(), SequencerState::Running);
+
+        timer.advance(TestDuration(100));
+        sequencer.service().unwrap();
+        assert!(colors_equal(sequencer.current_color(), color));
+    }
+}
+
+#[test]
+fn seek_action_jumps_to_timeline_position() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert!(matches!(
+        sequencer.handle_action(SequencerAction::Seek(TestDuration(0))),
+        Err(SequencerError::InvalidState { .. })
+    ));
+    sequencer.load_and_start(sequence).unwrap();
+    timer.advance(TestDuration(500));
+
+    // (seek position, expected color, expected state after service)
+    let test_cases = [
+        (2500, BLUE, SequencerState::Running),
+        (1200, GREEN, SequencerState::Running),
+        (0, RED, SequencerState::Running),
+        (5000, BLUE, SequencerState::Complete),
+    ];
+
+    for (position, expected_color, expected_state) in test_cases {
+        sequencer
+            .handle_action(SequencerAction::Seek(TestDuration(position)))
+            .unwrap();
+        sequencer.service().unwrap();
+        assert!(
+            colors_equal(sequencer.current_color(), expected_color),
+            "seek {}ms",
+            position
+        );
+        assert_eq!(sequencer.state(), expected_state, "seek {}ms", position);
+    }
+}
+
+#[test]
+fn seek_while_paused_resumes_from_new_position() {
+    let led = MockLed::new();
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .step(GREEN, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .build()
+        .unwrap();
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+
+    sequencer.pause().unwrap();
+    sequencer.seek(TestDuration(1500)).unwrap();
+    timer.advance(TestDuration(3000));
+    assert_eq!(
+        sequencer.current_position().unwrap().step_index,
+        1,
+        "paused position follows the seek"
+    );
+
+    sequencer.resume().unwrap();
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(500))
+    );
+    assert!(colors_equal(sequencer.current_color(), GREEN));
+}
+
+#[test]
+fn load_ref_shares_one_sequence_between_sequencers() {
+    let timer = MockTimeSource::new();
+    let shared = RgbSequence::<TestDuration, 8>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+
+    let mut first =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    let mut second =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 8>::new(MockLed::new(), &timer);
+    first.load_ref(&shared);
+    second.load_ref(&shared);
+    assert_eq!(first.state(), SequencerState::Loaded);
+    assert!(core::ptr::eq(first.current_sequence().unwrap(), &shared));
+
+    first.start().unwrap();
+    timer.advance(TestDuration(150));
+    second.start().unwrap();
+    first.service().unwrap();
+    second.service().unwrap();
+    assert!(colors_equal(first.current_color(), BLUE));
+    assert!(colors_equal(second.current_color(), RED));
+
+    // Modifying a borrowed sequence copies it, leaving the shared one untouched
+    first.set_remaining_loops(LoopCount::Finite(1)).unwrap();
+    assert!(!core::ptr::eq(first.current_sequence().unwrap(), &shared));
+    assert_eq!(shared.loop_count(), LoopCount::Infinite);
+    assert!(core::ptr::eq(second.current_sequence().unwrap(), &shared));
+
+    let (_, sequence) = second.into_parts();
+    assert_eq!(sequence.unwrap().loop_count(), LoopCount::Infinite);
+}
+
+#[test]
+fn chained_segments_play_back_to_back() {
+    let timer = MockTimeSource::new();
+    let segment = |first, second| {
+        RgbSequence::<TestDuration, 2>::builder()
+            .step(first, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+            .step(second, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+            .build()
+            .unwrap()
+    };
+    let white = Srgb::new(1.0, 1.0, 1.0);
+    let segments = [segment(RED, GREEN), segment(BLUE, white)];
+    assert_eq!(segments[0].total_duration(), Some(TestDuration(200)));
+
+    let mut sequencer =
+        RgbSequencer::<TestInstant, MockLed, MockTimeSource, 2>::new(MockLed::new(), &timer);
+    assert_eq!(
+        sequencer.load_chain(&[], LoopCount::Finite(1)),
+        Err(SequencerError::NoSequenceLoaded)
+    );
+    sequencer
+        .load_chain(&segments, LoopCount::Finite(2))
+        .unwrap();
+    sequencer.start().unwrap();
+
+    // (time, expected color, expected segment, expected timing)
+    let test_cases = [
+        (0, RED, 0, ServiceTiming::Delay(TestDuration(100))),
+        (150, GREEN, 0, ServiceTiming::Delay(TestDuration(50))),
+        // Crossing the joint carries the overshoot into the next segment
+        (330, white, 1, ServiceTiming::Delay(TestDuration(70))),
+        (420, RED, 0, ServiceTiming::Delay(TestDuration(80))),
+        (650, BLUE, 1, ServiceTiming::Delay(TestDuration(50))),
+        (800, white, 1, ServiceTiming::Complete),
+    ];
+
+    for (time, expected_color, expected_segment, expected_timing) in test_cases {
+        timer.set_time(TestInstant(time));
+        let timing = sequencer.service().unwrap();
+        assert!(
+            colors_equal(sequencer.current_color(), expected_color),
+            "at {}ms",
+            time
+        );
+        assert_eq!(
+            sequencer.current_segment(),
+            Some(expected_segment),
+            "at {}ms",
+            time
+        );
+        assert_eq!(timing, expected_timing, "at {}ms", time);
+    }
+    assert_eq!(sequencer.state(), SequencerState::Complete);
+
+    // Restarting rewinds to the first segment
+    sequencer.restart().unwrap();
+    sequencer.service().unwrap();
+    assert_eq!(sequencer.current_segment(), Some(0));
+    assert!(colors_equal(sequencer.current_color(), RED));
+
+    // A finished but unserviced segment asks for an immediate service
+    timer.advance(TestDuration(250));
+    assert_eq!(
+        sequencer.peek_next_timing().unwrap(),
+        ServiceTiming::Delay(TestDuration(0))
+    );
+
+    // Loading a single sequence drops the chain
+    sequencer.load(segment(RED, RED));
+    assert_eq!(sequencer.current_segment(), None);
+}
@@ -0,0 +1,89 @@
+//! Integration tests for the terminal simulator (requires the `std` feature)
+
+#![cfg(feature = "std")]
+
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::TransitionStyle;
+use rgb_sequencer::{
+    BLUE, RED, RgbLed, RgbSequencer, SequencerState, SimulatorLed, Srgb, StdTimeSource, simulator,
+};
+use std::time::{Duration, Instant};
+
+#[test]
+fn simulator_led_renders_truecolor_blocks() {
+    let test_cases = [
+        (RED, "\r\x1b[48;2;255;0;0m        \x1b[0m #FF0000"),
+        (
+            Srgb::new(0.5, 0.25, 1.0),
+            "\r\x1b[48;2;128;64;255m        \x1b[0m #8040FF",
+        ),
+    ];
+
+    for (color, expected) in test_cases {
+        let mut led = SimulatorLed::with_writer(Vec::new());
+        led.set_color(color);
+        let output = String::from_utf8(led.into_writer()).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    // Timestamped output puts each update on its own line
+    let mut led = SimulatorLed::with_writer(Vec::new()).with_timestamps(true);
+    led.set_color(RED);
+    led.set_color(BLUE);
+    let output = String::from_utf8(led.into_writer()).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with('[') && lines[0].ends_with("#FF0000"));
+    assert!(lines[1].ends_with("#0000FF"));
+}
+
+#[test]
+fn run_plays_sequence_to_completion() {
+    let timer = StdTimeSource;
+    let led = SimulatorLed::with_writer(Vec::new()).with_timestamps(true);
+    let mut sequencer = RgbSequencer::<Instant, _, _, 4>::new(led, &timer);
+
+    let sequence = RgbSequence::<Duration, 4>::builder()
+        .step(RED, Duration::from_millis(20), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, Duration::from_millis(20), TransitionStyle::Linear)
+        .unwrap()
+        .build()
+        .unwrap();
+    sequencer.load(sequence);
+
+    let started = Instant::now();
+    simulator::run(&mut sequencer, Duration::from_millis(5), None).unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(40));
+    assert_eq!(sequencer.state(), SequencerState::Complete);
+
+    let output = String::from_utf8(sequencer.into_led().into_writer()).unwrap();
+    assert!(output.lines().last().unwrap().ends_with("#0000FF"));
+}
+
+#[test]
+fn sample_to_csv_writes_rows_through_completion() {
+    let sequence = RgbSequence::<Duration, 4>::builder()
+        .step(RED, Duration::from_millis(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, Duration::from_millis(150), TransitionStyle::Linear)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut csv = Vec::new();
+    simulator::sample_to_csv(&sequence, Duration::from_millis(100), &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+
+    let expected = [
+        "time_ms,r,g,b",
+        "0,1.0000,0.0000,0.0000",
+        "100,1.0000,0.0000,0.0000",
+        "200,0.3333,0.0000,0.6667",
+        "250,0.0000,0.0000,1.0000",
+    ];
+    assert_eq!(csv.lines().collect::<Vec<_>>(), expected);
+
+    let error = simulator::sample_to_csv(&sequence, Duration::ZERO, std::io::sink()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,110 @@
+//! Integration tests for the SK6812 RGBW adapter
+
+mod common;
+use common::*;
+
+use palette::Srgb;
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::TransitionStyle;
+use rgb_sequencer::{
+    ColorMapper, RgbLed, RgbSequencer, RgbwWrite, Sk6812Led, WhiteExtraction, WhiteStrategy,
+};
+
+/// Mock RGBW driver that records the last frame
+#[derive(Default)]
+struct MockDriver {
+    pixels: [[u8; 4]; 2],
+    fail: bool,
+}
+
+impl RgbwWrite for MockDriver {
+    type Error = ();
+
+    fn write(&mut self, pixels: &[[u8; 4]]) -> Result<(), ()> {
+        if self.fail {
+            return Err(());
+        }
+        self.pixels[..pixels.len()].copy_from_slice(pixels);
+        Ok(())
+    }
+}
+
+#[test]
+fn white_strategies_split_common_component() {
+    let color = Srgb::new(1.0, 0.6, 0.4);
+    let cases = [
+        (WhiteStrategy::Off, [1.0, 0.6, 0.4, 0.0]),
+        (WhiteStrategy::Full, [0.6, 0.2, 0.0, 0.4]),
+        (WhiteStrategy::Partial(0.5), [0.8, 0.4, 0.2, 0.2]),
+        (WhiteStrategy::Partial(3.0), [0.6, 0.2, 0.0, 0.4]),
+        (WhiteStrategy::Additive, [1.0, 0.6, 0.4, 0.4]),
+    ];
+
+    for (strategy, expected) in cases {
+        let channels = WhiteExtraction::new(strategy).map(color);
+        for (channel, expected) in channels.iter().zip(expected) {
+            assert!(
+                (channel - expected).abs() < 1e-6,
+                "{strategy:?}: {channels:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn white_scale_only_affects_white_channel() {
+    let extraction = WhiteExtraction::new(WhiteStrategy::Full).with_white_scale(0.5);
+    assert_eq!(
+        extraction.map(Srgb::new(1.0, 1.0, 0.5)),
+        [0.5, 0.5, 0.0, 0.25]
+    );
+
+    assert_eq!(
+        WhiteExtraction::new(WhiteStrategy::Full)
+            .with_white_scale(f32::NAN)
+            .white_scale(),
+        1.0
+    );
+}
+
+#[test]
+fn set_color_writes_same_pixel_to_every_position() {
+    let mut led = Sk6812Led::<_, 2>::new(MockDriver::default());
+    led.set_color(Srgb::new(1.0, 1.0, 0.5));
+
+    assert_eq!(led.into_driver().pixels, [[128, 128, 0, 128]; 2]);
+}
+
+#[test]
+fn sequencer_brightness_keeps_white_and_color_balanced() {
+    let timer = MockTimeSource::new();
+    let led = Sk6812Led::<_, 1>::new(MockDriver::default());
+    let mut sequencer = RgbSequencer::<TestInstant, _, MockTimeSource, 4>::new(led, &timer);
+
+    let sequence = RgbSequence::<TestDuration, 4>::builder()
+        .step(
+            Srgb::new(1.0, 0.6, 0.6),
+            TestDuration(100),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+    sequencer.set_brightness(0.5);
+    sequencer.load_and_start(sequence).unwrap();
+    sequencer.service().unwrap();
+
+    // Full brightness would be [102, 0, 0, 153]; every channel halves
+    assert_eq!(sequencer.into_led().into_driver().pixels[0], [51, 0, 0, 77]);
+}
+
+#[test]
+fn failed_writes_are_counted() {
+    let mut led = Sk6812Led::<_, 1>::new(MockDriver {
+        fail: true,
+        ..MockDriver::default()
+    });
+
+    led.set_color(Srgb::new(1.0, 0.0, 0.0));
+    assert_eq!(led.error_count(), 1);
+}
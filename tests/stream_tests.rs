@@ -0,0 +1,123 @@
+//! Integration tests for the async frame stream (requires the `async` feature)
+
+#![cfg(feature = "async")]
+
+mod common;
+use common::*;
+
+use core::future::{Future, ready};
+use core::pin::{Pin, pin};
+use core::task::{Context, Poll, Waker};
+use futures_core::Stream;
+use palette::Srgb;
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::{LoopCount, TransitionStyle};
+use rgb_sequencer::{Frames, RgbSequencer};
+
+/// Blue step, 100ms red fade, then a 50ms step to green
+fn sequence() -> RgbSequence<TestDuration, 4> {
+    RgbSequence::builder()
+        .step(
+            Srgb::new(0.0, 0.0, 1.0),
+            TestDuration(100),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .step(
+            Srgb::new(1.0, 0.0, 0.0),
+            TestDuration(100),
+            TransitionStyle::Linear,
+        )
+        .unwrap()
+        .step(
+            Srgb::new(0.0, 1.0, 0.0),
+            TestDuration(50),
+            TransitionStyle::Step,
+        )
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn stream_follows_service_timing_in_virtual_time() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, _, _, 4>::new(MockLed::new(), &timer);
+    sequencer.load(sequence());
+
+    let sleep_until = |deadline: TestInstant| {
+        timer.set_time(deadline);
+        ready(())
+    };
+    let mut frames = Frames::new(&mut sequencer, TestDuration(40), sleep_until);
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut instants = Vec::new();
+    while let Poll::Ready(Some((instant, _))) = Pin::new(&mut frames).poll_next(&mut cx) {
+        instants.push(instant.0);
+    }
+
+    // Hold until the fade at 100ms, 40ms frames through the fade (the last one lands in
+    // the final step), then the completion frame
+    assert_eq!(instants, [0, 100, 140, 180, 220, 250]);
+    assert!(colors_equal(
+        sequencer.current_color(),
+        Srgb::new(0.0, 1.0, 0.0)
+    ));
+}
+
+#[test]
+fn next_frame_yields_colors_until_completion() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, _, _, 4>::new(MockLed::new(), &timer);
+    sequencer.load(sequence());
+
+    let mut frames = Frames::new(&mut sequencer, TestDuration(40), |deadline| {
+        timer.set_time(deadline);
+        ready(())
+    });
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut next = || match pin!(frames.next_frame()).poll(&mut cx) {
+        Poll::Ready(frame) => frame,
+        Poll::Pending => panic!("virtual sleep never blocks"),
+    };
+
+    let (instant, color) = next().unwrap();
+    assert_eq!(instant, TestInstant(0));
+    assert!(colors_equal(color, Srgb::new(0.0, 0.0, 1.0)));
+
+    let mut last = None;
+    while let Some(frame) = next() {
+        last = Some(frame);
+    }
+    let (instant, color) = last.unwrap();
+    assert_eq!(instant, TestInstant(250));
+    assert!(colors_equal(color, Srgb::new(0.0, 1.0, 0.0)));
+}
+
+#[test]
+fn held_sequencer_streams_held_color_until_completion() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, _, _, 4>::new(MockLed::new(), &timer);
+    sequencer.load_and_start(sequence()).unwrap();
+    sequencer.service().unwrap();
+    sequencer.hold().unwrap();
+
+    let sleep_until = |deadline: TestInstant| {
+        timer.set_time(deadline);
+        ready(())
+    };
+    let mut frames = Frames::new(&mut sequencer, TestDuration(40), sleep_until);
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut instants = Vec::new();
+    while let Poll::Ready(Some((instant, color))) = Pin::new(&mut frames).poll_next(&mut cx) {
+        assert!(colors_equal(color, Srgb::new(0.0, 0.0, 1.0)));
+        instants.push(instant.0);
+    }
+
+    // The timeline runs on underneath the held color and still completes
+    assert_eq!(instants, [0, 100, 140, 180, 220, 250]);
+}
@@ -0,0 +1,224 @@
+//! Integration tests for StripSequencer
+
+mod common;
+use common::*;
+
+use palette::Srgb;
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::{LoopCount, TransitionStyle};
+use rgb_sequencer::{
+    Chase, FrameRenderer, RgbStrip, Scanner, SequencerAction, SequencerState, ServiceTiming,
+    StripSequencer, Wipe,
+};
+
+/// Mock strip that records the last frame and number of writes
+struct MockStrip<const PIXELS: usize> {
+    frame: [Srgb; PIXELS],
+    writes: usize,
+}
+
+impl<const PIXELS: usize> MockStrip<PIXELS> {
+    fn new() -> Self {
+        Self {
+            frame: [Srgb::new(1.0, 1.0, 1.0); PIXELS],
+            writes: 0,
+        }
+    }
+}
+
+impl<const PIXELS: usize> RgbStrip<PIXELS> for MockStrip<PIXELS> {
+    fn write_frame(&mut self, frame: &[Srgb; PIXELS]) {
+        self.frame = *frame;
+        self.writes += 1;
+    }
+}
+
+/// Lights one pixel that moves every 100ms
+struct MovingDot;
+
+impl<const PIXELS: usize> FrameRenderer<TestDuration, PIXELS> for MovingDot {
+    fn render(&mut self, color: Srgb, elapsed: TestDuration, frame: &mut [Srgb; PIXELS]) {
+        frame[(elapsed.0 / 100) as usize % PIXELS] = color;
+    }
+
+    fn is_animated(&self) -> bool {
+        true
+    }
+}
+
+fn red_hold() -> RgbSequence<TestDuration, 4> {
+    RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn new_strip_sequencer_blanks_strip() {
+    let timer = MockTimeSource::new();
+    let sequencer =
+        StripSequencer::<TestInstant, _, MockTimeSource, 4, 3>::new(MockStrip::new(), &timer);
+
+    assert_eq!(sequencer.state(), SequencerState::Idle);
+    let strip = sequencer.into_strip();
+    assert_eq!(strip.writes, 1);
+    assert!(strip.frame.iter().all(|&c| colors_equal(c, BLACK)));
+}
+
+#[test]
+fn uniform_renderer_fills_every_pixel() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        StripSequencer::<TestInstant, _, MockTimeSource, 4, 3>::new(MockStrip::new(), &timer);
+
+    sequencer.load_and_start(red_hold()).unwrap();
+    let timing = sequencer.service().unwrap();
+
+    assert_eq!(timing, ServiceTiming::Delay(TestDuration(1000)));
+    assert!(sequencer.frame().iter().all(|&c| colors_equal(c, RED)));
+
+    // Unchanged frame is not rewritten
+    timer.advance(TestDuration(100));
+    sequencer.service().unwrap();
+    assert_eq!(sequencer.into_strip().writes, 2);
+}
+
+#[test]
+fn animated_renderer_forces_continuous_timing() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = StripSequencer::<TestInstant, _, MockTimeSource, 4, 3, _>::with_renderer(
+        MockStrip::new(),
+        &timer,
+        MovingDot,
+    );
+
+    sequencer.load_and_start(red_hold()).unwrap();
+    assert_eq!(sequencer.service().unwrap(), ServiceTiming::Continuous);
+    assert!(colors_equal(sequencer.frame()[0], RED));
+    assert!(colors_equal(sequencer.frame()[1], BLACK));
+
+    timer.advance(TestDuration(100));
+    sequencer.service().unwrap();
+    assert!(colors_equal(sequencer.frame()[0], BLACK));
+    assert!(colors_equal(sequencer.frame()[1], RED));
+}
+
+#[test]
+fn animated_renderer_paces_to_frame_interval() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = StripSequencer::<TestInstant, _, MockTimeSource, 4, 3, _>::with_renderer(
+        MockStrip::new(),
+        &timer,
+        MovingDot,
+    );
+    sequencer.set_frame_interval(Some(TestDuration(50)));
+    assert_eq!(sequencer.frame_interval(), Some(TestDuration(50)));
+
+    sequencer.load_and_start(red_hold()).unwrap();
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(50))
+    );
+
+    // The step boundary wins when it comes first
+    timer.advance(TestDuration(980));
+    assert_eq!(
+        sequencer.service().unwrap(),
+        ServiceTiming::Delay(TestDuration(20))
+    );
+}
+
+#[test]
+fn stop_and_clear_blank_strip() {
+    let timer = MockTimeSource::new();
+    let mut sequencer =
+        StripSequencer::<TestInstant, _, MockTimeSource, 4, 3>::new(MockStrip::new(), &timer);
+
+    sequencer.load_and_start(red_hold()).unwrap();
+    sequencer.service().unwrap();
+
+    sequencer.handle_action(SequencerAction::Stop).unwrap();
+    assert_eq!(sequencer.state(), SequencerState::Loaded);
+    assert!(sequencer.frame().iter().all(|&c| colors_equal(c, BLACK)));
+
+    sequencer.start().unwrap();
+    sequencer.service().unwrap();
+    sequencer.clear();
+    assert_eq!(sequencer.state(), SequencerState::Idle);
+
+    let strip = sequencer.into_strip();
+    assert!(strip.frame.iter().all(|&c| colors_equal(c, BLACK)));
+}
+
+/// Renders a single frame and returns the red channel of each pixel
+fn render_red<R: FrameRenderer<TestDuration, 5>>(renderer: &mut R, elapsed: u64) -> [f32; 5] {
+    let mut frame = [BLACK; 5];
+    renderer.render(RED, TestDuration(elapsed), &mut frame);
+    frame.map(|c| c.red)
+}
+
+fn assert_pixels(actual: [f32; 5], expected: [f32; 5], desc: &str) {
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        assert!(
+            (a - e).abs() < 0.001,
+            "{}: {:?} != {:?}",
+            desc,
+            actual,
+            expected
+        );
+    }
+}
+
+#[test]
+fn chase_moves_head_with_fading_tail() {
+    let mut chase = Chase::new(TestDuration(100), 1);
+
+    let test_cases = [
+        (0, [1.0, 0.0, 0.0, 0.0, 0.5], "head at 0, tail wraps"),
+        (150, [0.5, 1.0, 0.0, 0.0, 0.0], "head at 1"),
+        (400, [0.0, 0.0, 0.0, 0.5, 1.0], "head at last pixel"),
+        (500, [1.0, 0.0, 0.0, 0.0, 0.5], "wraps to first pixel"),
+    ];
+
+    for (elapsed, expected, desc) in test_cases {
+        assert_pixels(render_red(&mut chase, elapsed), expected, desc);
+    }
+}
+
+#[test]
+fn scanner_bounces_at_strip_ends() {
+    let mut scanner = Scanner::new(TestDuration(100), 1);
+
+    let test_cases = [
+        (
+            0,
+            [1.0, 0.5, 0.0, 0.0, 0.0],
+            "start, tail from previous sweep",
+        ),
+        (200, [0.0, 0.5, 1.0, 0.0, 0.0], "moving right"),
+        (400, [0.0, 0.0, 0.0, 0.5, 1.0], "right end"),
+        (500, [0.0, 0.0, 0.0, 1.0, 0.5], "moving left, tail behind"),
+        (800, [1.0, 0.5, 0.0, 0.0, 0.0], "back at start"),
+    ];
+
+    for (elapsed, expected, desc) in test_cases {
+        assert_pixels(render_red(&mut scanner, elapsed), expected, desc);
+    }
+}
+
+#[test]
+fn wipe_fills_pixels_progressively() {
+    let mut wipe = Wipe::new(TestDuration(100));
+
+    let test_cases = [
+        (0, [1.0, 0.0, 0.0, 0.0, 0.0], "first pixel"),
+        (250, [1.0, 1.0, 1.0, 0.0, 0.0], "three pixels"),
+        (10_000, [1.0, 1.0, 1.0, 1.0, 1.0], "fully lit"),
+    ];
+
+    for (elapsed, expected, desc) in test_cases {
+        assert_pixels(render_red(&mut wipe, elapsed), expected, desc);
+    }
+}
@@ -0,0 +1,109 @@
+//! Integration tests for SyncGroup
+
+mod common;
+use common::*;
+
+use palette::Srgb;
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::{LoopCount, TransitionStyle};
+use rgb_sequencer::{
+    CollectionError, RgbSequencer, RgbStrip, SequencerError, SequencerState, ServiceTiming,
+    StripSequencer, SyncGroup, TimeSource,
+};
+
+/// Mock strip that records the last frame
+struct MockStrip<const PIXELS: usize> {
+    frame: [Srgb; PIXELS],
+}
+
+impl<const PIXELS: usize> RgbStrip<PIXELS> for MockStrip<PIXELS> {
+    fn write_frame(&mut self, frame: &[Srgb; PIXELS]) {
+        self.frame = *frame;
+    }
+}
+
+fn blink<const N: usize>() -> RgbSequence<TestDuration, N> {
+    RgbSequence::<TestDuration, N>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn group_controls_mixed_sequencers_with_one_instant() {
+    let timer = MockTimeSource::new();
+    let mut led = RgbSequencer::<TestInstant, MockLed, _, 4>::new(MockLed::new(), &timer);
+    let mut strip =
+        StripSequencer::<TestInstant, _, _, 8, 3>::new(MockStrip { frame: [BLACK; 3] }, &timer);
+    led.load(blink());
+    strip.load(blink());
+
+    let mut group = SyncGroup::<TestInstant, 2>::new();
+    assert_eq!(group.add(&mut led), Ok(0));
+    assert_eq!(group.add(&mut strip), Ok(1));
+    assert_eq!(group.len(), 2);
+
+    // Started at a shared instant in the past, both are already in the second step
+    timer.set_time(TestInstant(150));
+    group.start_at(TestInstant(0)).unwrap();
+    assert_eq!(
+        group.service_all(),
+        Ok(ServiceTiming::Delay(TestDuration(50)))
+    );
+
+    // Paused together and resumed later, both keep the same position
+    group.pause_at(timer.now()).unwrap();
+    timer.advance(TestDuration(1000));
+    group.resume_at(timer.now()).unwrap();
+    timer.advance(TestDuration(50));
+    assert_eq!(
+        group.service_all(),
+        Ok(ServiceTiming::Delay(TestDuration(100)))
+    );
+
+    drop(group);
+    assert!(colors_equal(led.current_color(), RED));
+    assert!(colors_equal(strip.frame()[0], RED));
+}
+
+#[test]
+fn group_reports_failures_per_member() {
+    let timer = MockTimeSource::new();
+    let mut loaded = RgbSequencer::<TestInstant, MockLed, _, 4>::new(MockLed::new(), &timer);
+    let mut idle = RgbSequencer::<TestInstant, MockLed, _, 4>::new(MockLed::new(), &timer);
+    let mut extra = RgbSequencer::<TestInstant, MockLed, _, 4>::new(MockLed::new(), &timer);
+    loaded.load(blink());
+
+    let mut group = SyncGroup::<TestInstant, 2>::new();
+    group.add(&mut loaded).unwrap();
+    group.add(&mut idle).unwrap();
+    assert_eq!(group.add(&mut extra), Err(CollectionError::Full));
+
+    // Every member is attempted
+    let errors = group.start_at(timer.now()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0],
+        (
+            1,
+            CollectionError::Sequencer(SequencerError::InvalidState {
+                expected: "Loaded or Complete",
+                actual: SequencerState::Idle
+            })
+        )
+    );
+    assert_eq!(
+        group.get_mut(0).map(|member| member.state()),
+        Some(SequencerState::Running)
+    );
+
+    // Members that are not running are skipped when servicing
+    assert_eq!(
+        group.service_all(),
+        Ok(ServiceTiming::Delay(TestDuration(100)))
+    );
+}
@@ -0,0 +1,188 @@
+//! Integration tests for the public test helpers (requires the `test-utils` feature)
+
+#![cfg(feature = "test-utils")]
+
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::test_utils::simulate::{SimEvent, run_for};
+use rgb_sequencer::test_utils::{
+    MockLed, MockTimeSource, TestDuration, TestInstant, colors_equal, colors_equal_epsilon,
+    golden_samples,
+};
+use rgb_sequencer::types::{LoopCount, TransitionStyle};
+use rgb_sequencer::{BLACK, BLUE, Position, RED, RgbSequencer, Srgb, TimeSource};
+
+#[test]
+fn golden_samples_record_every_frame_until_completion() {
+    let sequence = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .landing_color(BLACK)
+        .build()
+        .unwrap();
+
+    let samples = golden_samples::<4, 16>(sequence, TestDuration(50), TestDuration(1000));
+
+    let expected = [
+        (0, RED),
+        (50, RED),
+        (100, RED),
+        (150, Srgb::new(0.5, 0.0, 0.5)),
+        (200, BLACK),
+    ];
+    assert_eq!(samples.len(), expected.len());
+    for ((time, color), (expected_time, expected_color)) in samples.iter().zip(expected) {
+        assert_eq!(*time, TestDuration(expected_time));
+        assert!(
+            (color.red - expected_color.red).abs() < 0.001
+                && (color.green - expected_color.green).abs() < 0.001
+                && (color.blue - expected_color.blue).abs() < 0.001,
+            "at {}ms: {:?}",
+            expected_time,
+            color
+        );
+    }
+}
+
+#[test]
+fn golden_samples_stop_at_duration_or_capacity() {
+    let blink = || {
+        RgbSequence::<TestDuration, 4>::builder()
+            .step(RED, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+            .step(BLACK, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+            .loop_count(LoopCount::Infinite)
+            .build()
+            .unwrap()
+    };
+
+    // (frames capacity result, expected sample count)
+    let test_cases = [
+        (
+            golden_samples::<4, 64>(blink(), TestDuration(100), TestDuration(500)).len(),
+            6,
+        ),
+        (
+            golden_samples::<4, 64>(blink(), TestDuration(0), TestDuration(500)).len(),
+            1,
+        ),
+        (
+            golden_samples::<4, 3>(blink(), TestDuration(100), TestDuration(500)).len(),
+            3,
+        ),
+    ];
+    for (count, expected) in test_cases {
+        assert_eq!(count, expected);
+    }
+}
+
+#[test]
+fn mock_led_records_sequencer_output() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed<3>, _, 4>::new(MockLed::new(), &timer);
+
+    let sequence = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLACK, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .build()
+        .unwrap();
+    sequencer.load_and_start(sequence).unwrap();
+    for _ in 0..3 {
+        sequencer.service().unwrap();
+        timer.advance(TestDuration(100));
+    }
+
+    // Construction turns the LED off; history is capped at three entries while the last
+    // color keeps updating
+    let led = sequencer.into_led();
+    assert_eq!(led.color_history(), [BLACK, RED, BLUE]);
+    assert!(colors_equal(led.get_last_color(), BLACK));
+}
+
+#[test]
+fn color_comparisons_use_tolerance() {
+    let color = Srgb::new(0.5, 0.5, 0.5);
+
+    assert!(colors_equal(color, Srgb::new(0.5005, 0.5, 0.4995)));
+    assert!(!colors_equal(color, Srgb::new(0.502, 0.5, 0.5)));
+    assert!(colors_equal_epsilon(color, Srgb::new(0.55, 0.5, 0.5), 0.1));
+}
+
+#[test]
+fn run_for_follows_timing_hints_to_completion() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, _, 4>::new(MockLed::new(), &timer);
+    let sequence = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLUE, TestDuration(100), TransitionStyle::Linear)
+        .unwrap()
+        .loop_count(LoopCount::Finite(1))
+        .build()
+        .unwrap();
+    sequencer.load(sequence);
+
+    let run = run_for::<_, 4, 8, 16>(&mut sequencer, &timer, TestDuration(1000), TestDuration(30));
+
+    // The hold is skipped in one jump to the step boundary, then 30ms frames run the fade
+    let times: Vec<u64> = run.samples.iter().map(|(time, _)| time.0).collect();
+    assert_eq!(times, [0, 100, 130, 160, 190, 220]);
+    assert_eq!(
+        run.events.as_slice(),
+        [
+            SimEvent::StepChanged {
+                at: TestInstant(0),
+                position: Position {
+                    step_index: 0,
+                    loop_number: 0
+                }
+            },
+            SimEvent::StepChanged {
+                at: TestInstant(100),
+                position: Position {
+                    step_index: 1,
+                    loop_number: 0
+                }
+            },
+            SimEvent::Completed {
+                at: TestInstant(220)
+            },
+        ]
+    );
+    assert!(colors_equal(run.samples[2].1, Srgb::new(0.7, 0.0, 0.3)));
+    assert_eq!(run.next_service, None);
+    assert!(!run.truncated);
+    assert_eq!(timer.now(), TestInstant(1000));
+}
+
+#[test]
+fn run_for_windows_continue_where_the_last_one_stopped() {
+    let timer = MockTimeSource::new();
+    let mut sequencer = RgbSequencer::<TestInstant, MockLed, _, 4>::new(MockLed::new(), &timer);
+    let blink = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .step(BLACK, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Infinite)
+        .build()
+        .unwrap();
+    sequencer.load_and_start(blink).unwrap();
+
+    let first = run_for::<_, 4, 4, 2>(&mut sequencer, &timer, TestDuration(250), TestDuration(16));
+    assert_eq!(first.samples.len(), 2);
+    assert!(first.truncated);
+    assert_eq!(first.next_service, Some(TestInstant(300)));
+
+    let second = run_for::<_, 4, 4, 8>(&mut sequencer, &timer, TestDuration(150), TestDuration(16));
+    let times: Vec<u64> = second.samples.iter().map(|(time, _)| time.0).collect();
+    assert_eq!(times, [250, 300, 400]);
+    assert!(colors_equal(second.samples[2].1, RED));
+}
@@ -0,0 +1,149 @@
+//! Integration tests for the wire protocol
+
+mod common;
+use common::*;
+
+use rgb_sequencer::sequence::RgbSequence;
+use rgb_sequencer::types::{LoopCount, SequenceError, TransitionStyle};
+use rgb_sequencer::wire::{decode, encode};
+use rgb_sequencer::{
+    BROADCAST_ID, CollectionError, SequencerAction, SequencerCollection, SequencerState, WireError,
+};
+
+type TestCollection<'t> = SequencerCollection<'t, TestInstant, MockLed, MockTimeSource, 4, 3>;
+
+#[test]
+fn feed_routes_frames_by_led_id() {
+    let timer = MockTimeSource::new();
+    let mut collection = TestCollection::new(&timer);
+    for _ in 0..3 {
+        collection.add_sequencer(MockLed::new()).unwrap();
+    }
+
+    // LED 1: load and start red (100ms) then green (50ms, linear), infinite
+    let load = [1, 0x11, 0, 0xFF, 0, 0, 100, 0, 0, 0, 0xFF, 0, 50, 0, 1];
+    collection.feed(&load).unwrap();
+    let running = |collection: &TestCollection<'_>, id| {
+        collection.get_sequencer(id).unwrap().state() == SequencerState::Running
+    };
+    assert!(running(&collection, 1));
+    assert!(!running(&collection, 0));
+
+    // Brightness 0x80 and speed 2.5x (250 hundredths) on LED 1 only
+    collection.feed(&[1, 0x09, 0x80]).unwrap();
+    collection.feed(&[1, 0x0A, 250, 0]).unwrap();
+    let led = collection.get_sequencer(1).unwrap();
+    assert!((led.brightness() - 128.0 / 255.0).abs() < 1e-6);
+    assert_eq!(led.speed(), 2.5);
+
+    // Broadcast pause is attempted on every LED; the idle ones report the first failure
+    assert!(matches!(
+        collection.feed(&[BROADCAST_ID, 0x03]),
+        Err(WireError::Collection(CollectionError::Sequencer(_)))
+    ));
+    assert_eq!(
+        collection.get_sequencer(1).unwrap().state(),
+        SequencerState::Paused
+    );
+
+    // Unused slots and malformed frames are rejected before anything changes
+    let cases: [(&[u8], WireError); 6] = [
+        (
+            &[3, 0x01],
+            WireError::Collection(CollectionError::InvalidId(3)),
+        ),
+        (&[1], WireError::Truncated),
+        (&[1, 0x7F], WireError::UnknownOpcode(0x7F)),
+        (&[1, 0x04, 0], WireError::InvalidPayload),
+        (&[1, 0x08, 0, 0], WireError::InvalidPayload),
+        (
+            &[1, 0x10, 1],
+            WireError::InvalidSequence(SequenceError::EmptySequence),
+        ),
+    ];
+    for (frame, expected) in cases {
+        assert_eq!(collection.feed(frame), Err(expected), "frame {:?}", frame);
+    }
+    assert_eq!(
+        collection.get_sequencer(1).unwrap().state(),
+        SequencerState::Paused
+    );
+}
+
+#[test]
+fn encode_round_trips_through_decode() {
+    let sequence = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(250), TransitionStyle::EaseInOut)
+        .unwrap()
+        .step(BLUE, TestDuration(1000), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(3))
+        .build()
+        .unwrap();
+
+    let mut buffer = [0u8; 32];
+    let len = encode(2, &SequencerAction::Load(sequence.clone()), &mut buffer).unwrap();
+    assert_eq!(len, 3 + 2 * 6);
+
+    let (led_id, action) = decode::<TestDuration, 4>(&buffer[..len]).unwrap();
+    assert_eq!(led_id, 2);
+    assert!(matches!(action, SequencerAction::Load(decoded) if decoded == sequence));
+
+    let len = encode(
+        0,
+        &SequencerAction::<TestDuration, 4>::Seek(TestDuration(1234)),
+        &mut buffer,
+    )
+    .unwrap();
+    assert!(matches!(
+        decode::<TestDuration, 4>(&buffer[..len]),
+        Ok((0, SequencerAction::Seek(TestDuration(1234))))
+    ));
+
+    // Sequences that do not fit the format or the buffer are refused
+    let endless = RgbSequence::<TestDuration, 4>::builder()
+        .step(RED, TestDuration(100), TransitionStyle::Step)
+        .unwrap()
+        .loop_count(LoopCount::Finite(1000))
+        .build()
+        .unwrap();
+    assert_eq!(
+        encode(0, &SequencerAction::Load(endless), &mut buffer),
+        Err(WireError::LoopCountOutOfRange)
+    );
+    assert_eq!(
+        encode(0, &SequencerAction::Load(sequence), &mut buffer[..8]),
+        Err(WireError::BufferTooSmall)
+    );
+
+    // Settings beyond steps and loop count would be lost, so they are refused
+    let steps = || {
+        RgbSequence::<TestDuration, 4>::builder()
+            .step(RED, TestDuration(100), TransitionStyle::Linear)
+            .unwrap()
+            .step(BLUE, TestDuration(100), TransitionStyle::Step)
+            .unwrap()
+    };
+    let unsupported = [
+        steps().loop_from(1).loop_count(LoopCount::Infinite),
+        steps().start_color(GREEN),
+        steps().landing_color(GREEN),
+        steps().start_delay(TestDuration(50)),
+        steps().end_hold(TestDuration(50)),
+        steps().brightness(0.5),
+    ];
+    for builder in unsupported {
+        let sequence = builder.build().unwrap();
+        assert_eq!(
+            encode(0, &SequencerAction::Load(sequence), &mut buffer),
+            Err(WireError::UnsupportedSequence)
+        );
+    }
+
+    let function_based =
+        RgbSequence::<TestDuration, 4>::from_function(RED, |color, _| color, |_| None);
+    assert_eq!(
+        encode(0, &SequencerAction::Load(function_based), &mut buffer),
+        Err(WireError::UnsupportedSequence)
+    );
+}
@@ -13,8 +13,8 @@ use embassy_time::Duration as EmbassyDurationInner;
 use embassy_time::Instant as EmbassyInstantInner;
 use palette::Srgb;
 use rgb_sequencer::{
-    LoopCount, RgbLed, RgbSequence, RgbSequencer, SequenceStep, TimeDuration, TimeInstant,
-    TimeSource, TransitionStyle,
+    LoopCount, RgbLed, RgbSequence, RgbSequencer, RgbStrip, SequenceStep, SequencerCollection,
+    StripSequencer, TimeDuration, TimeInstant, TimeSource, TransitionStyle,
 };
 use std::fs::File;
 use std::io::Write;
@@ -206,6 +206,18 @@ impl RgbLed for LargeLed {
     }
 }
 
+// Mock addressable strip - driver handle only, frame buffer lives in the sequencer
+#[repr(C)]
+struct MockStrip {
+    _spi: u32,
+}
+
+impl<const PIXELS: usize> RgbStrip<PIXELS> for MockStrip {
+    fn write_frame(&mut self, _frame: &[Srgb; PIXELS]) {
+        // Mock implementation
+    }
+}
+
 // ============================================================================
 // Mock TimeSource Types
 // ============================================================================
@@ -468,6 +480,111 @@ where
     Ok(())
 }
 
+fn write_collection_table<'t, I, L, T>(
+    f: &mut File,
+    instant_name: &str,
+    led_name: &str,
+) -> std::io::Result<()>
+where
+    I: TimeInstant,
+    L: RgbLed,
+    T: TimeSource<I> + 't,
+{
+    writeln!(
+        f,
+        "### `SequencerCollection<{}, {}, N, MAX_LEDS>`",
+        instant_name, led_name
+    )?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "| MAX_LEDS | Capacity | Total Size | Per Slot | Slot Option OH | Collection OH |"
+    )?;
+    writeln!(
+        f,
+        "|----------|----------|------------|----------|----------------|---------------|"
+    )?;
+
+    macro_rules! row {
+        ($leds:literal, $n:literal) => {{
+            let total = size_of::<SequencerCollection<'t, I, L, T, $n, $leds>>();
+            let sequencer = size_of::<RgbSequencer<'t, I, L, T, $n>>();
+            let slot = size_of::<Option<RgbSequencer<'t, I, L, T, $n>>>();
+            writeln!(
+                f,
+                "| {} | {} | {} B | {} B | {} B | {} B |",
+                $leds,
+                $n,
+                total,
+                slot,
+                slot - sequencer,
+                total - slot * $leds
+            )?;
+        }};
+    }
+
+    row!(2, 4);
+    row!(2, 8);
+    row!(2, 16);
+    row!(4, 4);
+    row!(4, 8);
+    row!(4, 16);
+    row!(8, 4);
+    row!(8, 8);
+    row!(8, 16);
+    row!(16, 4);
+    row!(16, 8);
+    row!(16, 16);
+
+    writeln!(f)?;
+    Ok(())
+}
+
+fn write_strip_table<'t, I, T>(f: &mut File, instant_name: &str) -> std::io::Result<()>
+where
+    I: TimeInstant,
+    T: TimeSource<I> + 't,
+{
+    writeln!(
+        f,
+        "### `StripSequencer<{}, MockStrip, N, PIXELS>`",
+        instant_name
+    )?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "| PIXELS | Capacity | Total Size | Frame Buffer | Other |"
+    )?;
+    writeln!(
+        f,
+        "|--------|----------|------------|--------------|-------|"
+    )?;
+
+    macro_rules! row {
+        ($pixels:literal, $n:literal) => {{
+            let total = size_of::<StripSequencer<'t, I, MockStrip, T, $n, $pixels>>();
+            let frame = size_of::<[Srgb; $pixels]>();
+            writeln!(
+                f,
+                "| {} | {} | {} B | {} B | {} B |",
+                $pixels,
+                $n,
+                total,
+                frame,
+                total - frame
+            )?;
+        }};
+    }
+
+    row!(8, 8);
+    row!(30, 8);
+    row!(60, 8);
+    row!(144, 8);
+
+    writeln!(f)?;
+    Ok(())
+}
+
 fn main() -> std::io::Result<()> {
     let report_path = "report.md";
 
@@ -548,6 +665,30 @@ fn main() -> std::io::Result<()> {
         &capacities,
     )?;
 
+    // Multi-LED tables
+    writeln!(&mut file, "## Collection Memory Usage")?;
+    writeln!(&mut file)?;
+    writeln!(
+        &mut file,
+        "Every slot reserves a full `Option<RgbSequencer>` whether or not an LED is added. \"Slot Option OH\" is the per-slot cost of the `Option`; \"Collection OH\" covers group masks and the shared time source reference."
+    )?;
+    writeln!(&mut file)?;
+    write_collection_table::<Instant32, SmallLed, TimeSource32>(&mut file, "u32", "Small")?;
+    write_collection_table::<Instant32, MediumLed, TimeSource32>(&mut file, "u32", "Medium")?;
+    write_collection_table::<EmbassyInstant, MediumLed, EmbassyTimeSource>(
+        &mut file, "Embassy", "Medium",
+    )?;
+
+    writeln!(&mut file, "## Strip Memory Usage")?;
+    writeln!(&mut file)?;
+    writeln!(
+        &mut file,
+        "Strip sequencers hold one frame of `PIXELS` colors in addition to the sequencer itself."
+    )?;
+    writeln!(&mut file)?;
+    write_strip_table::<Instant32, TimeSource32>(&mut file, "u32")?;
+    write_strip_table::<EmbassyInstant, EmbassyTimeSource>(&mut file, "Embassy")?;
+
     // Key insights
     writeln!(&mut file, "## Key Insights")?;
     writeln!(&mut file)?;
@@ -563,6 +704,10 @@ fn main() -> std::io::Result<()> {
         &mut file,
         "- LED implementation size directly affects total sequencer size"
     )?;
+    writeln!(
+        &mut file,
+        "- Collections reserve every slot up front; size MAX_LEDS to the LEDs you actually drive"
+    )?;
     writeln!(&mut file)?;
     writeln!(&mut file, "## Architecture Note")?;
     writeln!(&mut file)?;